@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use helping_hand::map::interactions::interactives::*;
+use helping_hand::map::interactions::{interactives::*, level_transitions::LevelTransitionEffect};
 use helping_hand::map::*;
 use std::hint::black_box;
 use std::path::PathBuf;
@@ -12,8 +12,9 @@ fn create_interactive_collection_from_dimensions(
     for x in 1..=width {
         for y in 1..=height {
             let dimensions = PxDimensions::new(64, 64);
-            let pixel_coordinate = PxCords::new(x, y, 0);
-            let interactive_type = InteractiveType::Transition(PathBuf::from(""));
+            let pixel_coordinate = PxCords::new(x * 64, y * 64, 0);
+            let interactive_type =
+                InteractiveType::Transition(PathBuf::from(""), LevelTransitionEffect::Cut);
             let interactive_marker =
                 InteractiveMarker::new(pixel_coordinate, dimensions, interactive_type);
 
@@ -21,7 +22,7 @@ fn create_interactive_collection_from_dimensions(
         }
     }
 
-    InteractiveCollection::from_markers(interactive_markers)
+    InteractiveCollection::from_markers(interactive_markers, PxDimensions::new(64, 64))
 }
 
 fn benchmark_interactives_on_small_map(c: &mut Criterion) {
@@ -32,7 +33,7 @@ fn benchmark_interactives_on_small_map(c: &mut Criterion) {
         // This runs the function 100 times to generate performance numbers
         // averaged based on all of these cases.
         benchmarker
-            .iter(|| interactives.get_marker_from_position(black_box(&PxCords::new(5, 5, 0))))
+            .iter(|| interactives.get_marker_from_position(black_box(&PxCords::new(320, 320, 0))))
     });
 }
 
@@ -45,8 +46,9 @@ fn benchmark_interactives_on_medium_map(c: &mut Criterion) {
         |benchmarker| {
             // This runs the function 100 times to generate performance numbers
             // averaged based on all of these cases.
-            benchmarker
-                .iter(|| interactives.get_marker_from_position(black_box(&PxCords::new(20, 20, 0))))
+            benchmarker.iter(|| {
+                interactives.get_marker_from_position(black_box(&PxCords::new(81920, 81920, 0)))
+            })
         },
     );
 }
@@ -60,8 +62,9 @@ fn benchmark_interactives_on_large_map(c: &mut Criterion) {
         |benchmarker| {
             // This runs the function 100 times to generate performance numbers
             // averaged based on all of these cases.
-            benchmarker
-                .iter(|| interactives.get_marker_from_position(black_box(&PxCords::new(50, 50, 0))))
+            benchmarker.iter(|| {
+                interactives.get_marker_from_position(black_box(&PxCords::new(3200, 3200, 0)))
+            })
         },
     );
 }
@@ -76,7 +79,90 @@ fn benchmark_interactives_on_jumbo_map(c: &mut Criterion) {
             // This runs the function 100 times to generate performance numbers
             // averaged based on all of these cases.
             benchmarker.iter(|| {
-                interactives.get_marker_from_position(black_box(&PxCords::new(1280, 1280, 0)))
+                interactives.get_marker_from_position(black_box(&PxCords::new(81920, 81920, 0)))
+            })
+        },
+    );
+}
+
+/// Creates markers of random sizes and positions, which overlap each other
+/// and don't line up with the tiles of a map, on a couple of different layers.
+fn create_irregular_interactive_collection(
+    amount: usize,
+    map_size: usize,
+) -> InteractiveCollection {
+    let mut rng = fastrand::Rng::with_seed(7);
+    let mut interactive_markers = Vec::new();
+
+    for _ in 0..amount {
+        let dimensions = PxDimensions::new(rng.u32(8..256), rng.u32(8..256));
+        let pixel_coordinate = PxCords::new(
+            rng.usize(..map_size),
+            rng.usize(..map_size),
+            rng.usize(0..3),
+        );
+        let interactive_type =
+            InteractiveType::Transition(PathBuf::from(""), LevelTransitionEffect::Cut);
+        let interactive_marker =
+            InteractiveMarker::new(pixel_coordinate, dimensions, interactive_type);
+
+        interactive_markers.push(interactive_marker);
+    }
+
+    InteractiveCollection::from_markers(interactive_markers, PxDimensions::new(64, 64))
+}
+
+/// Creates markers that are all stacked on top of the same area.
+fn create_overlapping_interactive_collection(amount: usize) -> InteractiveCollection {
+    let mut interactive_markers = Vec::new();
+
+    for offset in 0..amount {
+        let dimensions = PxDimensions::new(128, 128);
+        let pixel_coordinate = PxCords::new(offset, offset, 0);
+        let interactive_type =
+            InteractiveType::Transition(PathBuf::from(""), LevelTransitionEffect::Cut);
+        let interactive_marker =
+            InteractiveMarker::new(pixel_coordinate, dimensions, interactive_type);
+
+        interactive_markers.push(interactive_marker);
+    }
+
+    InteractiveCollection::from_markers(interactive_markers, PxDimensions::new(64, 64))
+}
+
+fn benchmark_interactives_on_overlapping_markers(c: &mut Criterion) {
+    let interactives = create_overlapping_interactive_collection(100);
+
+    c.bench_function(
+        "Find all markers containing 120, 120 among 100 overlapping markers",
+        |benchmarker| {
+            benchmarker
+                .iter(|| interactives.get_markers_containing(black_box(&PxCords::new(120, 120, 0))))
+        },
+    );
+}
+
+fn benchmark_interactives_on_irregular_markers(c: &mut Criterion) {
+    let interactives = create_irregular_interactive_collection(5000, 8192);
+
+    c.bench_function(
+        "Find all markers containing 4000, 4000 among 5000 irregular markers",
+        |benchmarker| {
+            benchmarker.iter(|| {
+                interactives.get_markers_containing(black_box(&PxCords::new(4000, 4000, 1)))
+            })
+        },
+    );
+}
+
+fn benchmark_interactives_on_empty_area(c: &mut Criterion) {
+    let interactives = create_irregular_interactive_collection(5000, 8192);
+
+    c.bench_function(
+        "Look for a marker outside of 5000 irregular markers",
+        |benchmarker| {
+            benchmarker.iter(|| {
+                interactives.get_marker_from_position(black_box(&PxCords::new(9000, 9000, 0)))
             })
         },
     );
@@ -88,5 +174,8 @@ criterion_group!(
     benchmark_interactives_on_medium_map,
     benchmark_interactives_on_large_map,
     benchmark_interactives_on_jumbo_map,
+    benchmark_interactives_on_overlapping_markers,
+    benchmark_interactives_on_irregular_markers,
+    benchmark_interactives_on_empty_area,
 );
 criterion_main!(benches);
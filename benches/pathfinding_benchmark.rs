@@ -0,0 +1,125 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use helping_hand::map::{
+    movement::{collision::CollisionCollection, pathfinding::find_path},
+    GridCords3D, GridDimensions,
+};
+use std::hint::black_box;
+
+/// Creates walls down every other column of a map, leaving a gap at alternating ends
+/// so that the only path from one corner to the other snakes across the whole map.
+fn create_winding_collision_collection_from_dimensions(
+    width: usize,
+    height: usize,
+) -> CollisionCollection {
+    let mut collision_collection = CollisionCollection::new();
+
+    for x in (1..width - 1).step_by(2) {
+        let gap_y = if (x / 2) % 2 == 0 { height - 1 } else { 0 };
+
+        for y in 0..height {
+            if y == gap_y {
+                continue;
+            }
+
+            collision_collection.add(&GridCords3D::new(x, y, 0));
+        }
+    }
+
+    collision_collection
+}
+
+fn benchmark_pathfinding_across_open_map(c: &mut Criterion, size: usize, map_name: &str) {
+    let map_grid_dimensions = GridDimensions::new(size as u32, size as u32, 1);
+    let collision_collection = CollisionCollection::new();
+    let start = GridCords3D::new(0, 0, 0);
+    let goal = GridCords3D::new(size - 1, size - 1, 0);
+
+    c.bench_function(
+        &format!("Find path across open {} sized map", map_name),
+        |benchmarker| {
+            // NOTE: We're calling black_box because we don't want any optimizations to be
+            // done from Rust. Without the optimizations, that makes the run as "fair" as possible.
+            benchmarker.iter(|| {
+                find_path(
+                    black_box(&start),
+                    black_box(&goal),
+                    &map_grid_dimensions,
+                    &collision_collection,
+                    &[],
+                )
+            })
+        },
+    );
+}
+
+fn benchmark_pathfinding_across_winding_map(c: &mut Criterion, size: usize, map_name: &str) {
+    let map_grid_dimensions = GridDimensions::new(size as u32, size as u32, 1);
+    let collision_collection = create_winding_collision_collection_from_dimensions(size, size);
+    let start = GridCords3D::new(0, 0, 0);
+    let goal = GridCords3D::new(size - 1, size - 1, 0);
+
+    c.bench_function(
+        &format!("Find path across winding {} sized map", map_name),
+        |benchmarker| {
+            benchmarker.iter(|| {
+                find_path(
+                    black_box(&start),
+                    black_box(&goal),
+                    &map_grid_dimensions,
+                    &collision_collection,
+                    &[],
+                )
+            })
+        },
+    );
+}
+
+fn benchmark_pathfinding_on_medium_maps(c: &mut Criterion) {
+    benchmark_pathfinding_across_open_map(c, 20, "Medium");
+    benchmark_pathfinding_across_winding_map(c, 20, "Medium");
+}
+
+fn benchmark_pathfinding_on_large_maps(c: &mut Criterion) {
+    benchmark_pathfinding_across_open_map(c, 50, "Large");
+    benchmark_pathfinding_across_winding_map(c, 50, "Large");
+}
+
+fn benchmark_pathfinding_on_huge_maps(c: &mut Criterion) {
+    benchmark_pathfinding_across_open_map(c, 200, "Huge");
+    benchmark_pathfinding_across_winding_map(c, 200, "Huge");
+}
+
+fn benchmark_pathfinding_with_occupied_tiles(c: &mut Criterion) {
+    let map_grid_dimensions = GridDimensions::new(50, 50, 1);
+    let collision_collection = CollisionCollection::new();
+    let start = GridCords3D::new(0, 0, 0);
+    let goal = GridCords3D::new(49, 49, 0);
+
+    // A diagonal line of characters standing in the way.
+    let occupied_tiles: Vec<GridCords3D> =
+        (1..49).map(|i| GridCords3D::new(i, 49 - i, 0)).collect();
+
+    c.bench_function(
+        "Find path past 48 occupied tiles in Large sized map",
+        |benchmarker| {
+            benchmarker.iter(|| {
+                find_path(
+                    black_box(&start),
+                    black_box(&goal),
+                    &map_grid_dimensions,
+                    &collision_collection,
+                    &occupied_tiles,
+                )
+            })
+        },
+    );
+}
+
+criterion_group!(
+    benches,
+    benchmark_pathfinding_on_medium_maps,
+    benchmark_pathfinding_on_large_maps,
+    benchmark_pathfinding_on_huge_maps,
+    benchmark_pathfinding_with_occupied_tiles,
+);
+criterion_main!(benches);
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl, AudioInstance, AudioTween};
+
+use crate::{
+    audio::volume_to_decibels,
+    map::ambient_sounds::{AmbientMix, AmbientSound},
+};
+
+#[derive(Default, Component, Resource)]
+pub struct AmbientChannel;
+
+/// The looping sound an ambient sound emitter is playing.
+#[derive(Component)]
+pub struct AmbientInstance {
+    handle: Handle<AudioInstance>,
+}
+
+/// Starts looping the sound of each ambient sound emitter that was just placed.
+pub fn play_ambient_sounds(
+    ambient_sounds: Query<(Entity, &AmbientSound, &AmbientMix), Without<AmbientInstance>>,
+    asset_server: Res<AssetServer>,
+    ambience: Res<AudioChannel<AmbientChannel>>,
+    mut commands: Commands,
+) {
+    for (entity, ambient_sound, ambient_mix) in ambient_sounds.iter() {
+        let handle = ambience
+            .play(asset_server.load(ambient_sound.get_file().clone()))
+            .looped()
+            .with_volume(volume_to_decibels(ambient_mix.get_volume()))
+            .with_panning(ambient_mix.get_panning())
+            .handle();
+
+        commands.entity(entity).insert(AmbientInstance { handle });
+    }
+}
+
+/// Turns each ambient sound up or down, and pans it, as the player walks around.
+pub fn follow_ambient_mix(
+    ambient_sounds: Query<(&AmbientMix, &AmbientInstance), Changed<AmbientMix>>,
+    mut audio_instances: ResMut<Assets<AudioInstance>>,
+) {
+    for (ambient_mix, ambient_instance) in ambient_sounds.iter() {
+        let found_audio_instance = audio_instances.get_mut(&ambient_instance.handle);
+        if found_audio_instance.is_none() {
+            continue;
+        }
+        let audio_instance = found_audio_instance.unwrap();
+
+        audio_instance.set_volume(
+            volume_to_decibels(ambient_mix.get_volume()),
+            AudioTween::default(),
+        );
+        audio_instance.set_panning(ambient_mix.get_panning(), AudioTween::default());
+    }
+}
+
+/// Stops the sounds of ambient sound emitters that were despawned along with their level.
+pub fn stop_removed_ambient_sounds(
+    mut removed_instances: RemovedComponents<AmbientInstance>,
+    ambient_sounds: Query<(), With<AmbientInstance>>,
+    ambience: Res<AudioChannel<AmbientChannel>>,
+) {
+    // Emitters are only ever despawned a whole level at a time, so the channel is
+    // stopped once every one of them is gone.
+    let has_removed_instances = removed_instances.read().count() > 0;
+    if has_removed_instances && ambient_sounds.is_empty() {
+        ambience.stop();
+    }
+}
+
+/// Holds the ambient sounds where they are while the game is paused.
+pub fn pause_ambience(ambience: Res<AudioChannel<AmbientChannel>>) {
+    ambience.pause();
+}
+
+/// Picks the ambient sounds back up from where they were paused.
+pub fn resume_ambience(ambience: Res<AudioChannel<AmbientChannel>>) {
+    ambience.resume();
+}
+
+pub fn stop_ambience(ambience: Res<AudioChannel<AmbientChannel>>) {
+    ambience.stop();
+}
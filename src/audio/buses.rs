@@ -0,0 +1,171 @@
+use std::{collections::HashMap, marker::PhantomData};
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioApp, AudioChannel, AudioControl};
+use serde::{Deserialize, Serialize};
+
+use crate::audio::volume_to_decibels;
+
+/// A group of audio channels whose volume is turned up, down or muted together.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+    /// Every other bus plays through this one.
+    Master,
+    Music,
+    Sfx,
+    Ambient,
+    Voice,
+}
+
+impl AudioBus {
+    /// Every bus, in the order they're shown in the settings menu.
+    pub const ALL: [AudioBus; 5] = [
+        AudioBus::Master,
+        AudioBus::Music,
+        AudioBus::Sfx,
+        AudioBus::Ambient,
+        AudioBus::Voice,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            AudioBus::Master => "Master",
+            AudioBus::Music => "Music",
+            AudioBus::Sfx => "SFX",
+            AudioBus::Ambient => "Ambient",
+            AudioBus::Voice => "Voice",
+        }
+    }
+}
+
+/// How loud a single bus is set to be.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct BusVolume {
+    /// From 0 to 1.
+    volume: f32,
+    #[serde(default)]
+    muted: bool,
+}
+
+impl Default for BusVolume {
+    fn default() -> Self {
+        Self {
+            volume: 1.0,
+            muted: false,
+        }
+    }
+}
+
+/// The volume of every bus, where buses that were never set are at full volume.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AudioBuses {
+    #[serde(default)]
+    buses: HashMap<AudioBus, BusVolume>,
+    /// How far each bus is turned down for the moment, such as music under a cutscene.
+    /// It isn't a setting of the player's, so it's never saved.
+    #[serde(skip)]
+    ducking: HashMap<AudioBus, f32>,
+}
+
+impl AudioBuses {
+    fn get_bus(&self, audio_bus: AudioBus) -> BusVolume {
+        self.buses.get(&audio_bus).copied().unwrap_or_default()
+    }
+
+    /// Returns the volume a bus is set to, from 0 to 1, leaving out the master volume and mutes.
+    pub fn get_volume(&self, audio_bus: AudioBus) -> f32 {
+        self.get_bus(audio_bus).volume
+    }
+
+    pub fn set_volume(&mut self, audio_bus: AudioBus, volume: f32) {
+        self.buses.entry(audio_bus).or_default().volume = volume.clamp(0.0, 1.0);
+    }
+
+    pub fn is_muted(&self, audio_bus: AudioBus) -> bool {
+        self.get_bus(audio_bus).muted
+    }
+
+    pub fn set_muted(&mut self, audio_bus: AudioBus, muted: bool) {
+        self.buses.entry(audio_bus).or_default().muted = muted;
+    }
+
+    pub fn toggle_muted(&mut self, audio_bus: AudioBus) {
+        let muted = self.is_muted(audio_bus);
+        self.set_muted(audio_bus, !muted);
+    }
+
+    /// Returns what a bus is scaled by while ducked, which is 1 when it isn't ducked.
+    pub fn get_ducking(&self, audio_bus: AudioBus) -> f32 {
+        self.ducking.get(&audio_bus).copied().unwrap_or(1.0)
+    }
+
+    pub fn set_ducking(&mut self, audio_bus: AudioBus, volume: f32) {
+        self.ducking.insert(audio_bus, volume.clamp(0.0, 1.0));
+    }
+
+    /// Returns how loud a bus actually plays, which is its own volume scaled by the master
+    /// volume and by how far it's ducked, or silent when either of them is muted.
+    pub fn get_effective_volume(&self, audio_bus: AudioBus) -> f32 {
+        if self.is_muted(AudioBus::Master) || self.is_muted(audio_bus) {
+            return 0.0;
+        }
+
+        if audio_bus == AudioBus::Master {
+            return self.get_volume(AudioBus::Master) * self.get_ducking(AudioBus::Master);
+        }
+
+        self.get_volume(AudioBus::Master) * self.get_volume(audio_bus) * self.get_ducking(audio_bus)
+    }
+}
+
+/// Which bus the audio channel C plays through.
+#[derive(Resource)]
+pub struct ChannelBus<C> {
+    audio_bus: AudioBus,
+    channel: PhantomData<C>,
+}
+
+impl<C> ChannelBus<C> {
+    pub fn new(audio_bus: AudioBus) -> Self {
+        Self {
+            audio_bus,
+            channel: PhantomData,
+        }
+    }
+
+    pub fn get_bus(&self) -> AudioBus {
+        self.audio_bus
+    }
+}
+
+/// Adds audio channels that play through some bus.
+pub trait AudioBusApp {
+    /// Adds the audio channel C, keeping its volume at the effective volume of a bus.
+    fn add_audio_channel_on_bus<C: Resource>(&mut self, audio_bus: AudioBus) -> &mut Self;
+}
+
+impl AudioBusApp for App {
+    fn add_audio_channel_on_bus<C: Resource>(&mut self, audio_bus: AudioBus) -> &mut Self {
+        if !self.world().contains_resource::<AudioBuses>() {
+            self.init_resource::<AudioBuses>();
+        }
+
+        self.add_audio_channel::<C>()
+            .insert_resource(ChannelBus::<C>::new(audio_bus))
+            .add_systems(Update, apply_bus_volume::<C>)
+    }
+}
+
+/// Sets the volume of the audio channel C whenever the volume of its bus changes.
+pub fn apply_bus_volume<C: Resource>(
+    audio_buses: Res<AudioBuses>,
+    channel_bus: Res<ChannelBus<C>>,
+    channel: Res<AudioChannel<C>>,
+) {
+    if !audio_buses.is_changed() && !channel_bus.is_added() {
+        return;
+    }
+
+    let volume = audio_buses.get_effective_volume(channel_bus.get_bus());
+    channel.set_volume(volume_to_decibels(volume));
+}
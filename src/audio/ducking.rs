@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        buses::{AudioBus, AudioBuses},
+        voice::VoicePlayback,
+    },
+    narrative::acts::{Act, SceneType},
+    ui::text_box::TextBox,
+};
+
+/// How far the music is turned down while something else should be heard over it,
+/// such as an image cutscene, some dialogue or a voice line.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DuckingSettings {
+    /// How much of the music's volume is taken away while ducked, from 0 to 1.
+    amount: f32,
+    /// How long the music takes to go all the way down, or all the way back up.
+    ramp_duration: Duration,
+    /// The types of scenes the music is ducked under.
+    scene_types: Vec<SceneType>,
+}
+
+impl Default for DuckingSettings {
+    fn default() -> Self {
+        Self::new(0.6, Duration::from_secs_f32(0.5))
+    }
+}
+
+impl DuckingSettings {
+    /// Returns settings ducking the music under image cutscenes and dialogue.
+    pub fn new(amount: f32, ramp_duration: Duration) -> Self {
+        Self {
+            amount: amount.clamp(0.0, 1.0),
+            ramp_duration,
+            scene_types: vec![SceneType::ImageCutscene],
+        }
+    }
+
+    /// Returns the settings, ducking the music under the given types of scenes instead.
+    pub fn with_scene_types(mut self, scene_types: Vec<SceneType>) -> Self {
+        self.scene_types = scene_types;
+        self
+    }
+
+    pub fn get_amount(&self) -> f32 {
+        self.amount
+    }
+
+    pub fn get_ramp_duration(&self) -> Duration {
+        self.ramp_duration
+    }
+
+    pub fn ducks_under(&self, scene_type: &SceneType) -> bool {
+        self.scene_types.contains(scene_type)
+    }
+}
+
+/// How ducked the music is, from 0 when it isn't to 1 when it's all the way down.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct MusicDucking {
+    current: f32,
+    target: f32,
+}
+
+impl MusicDucking {
+    pub fn get_current(&self) -> f32 {
+        self.current
+    }
+
+    pub fn is_ducking(&self) -> bool {
+        self.target > 0.0
+    }
+}
+
+/// Ducks the music while the current scene is of a type that asks for it, while
+/// some dialogue is being shown, or while a voice line is playing.
+pub fn choose_music_ducking(
+    acts: Query<&Act>,
+    text_boxes: Query<(), With<TextBox>>,
+    voice_playback: Res<VoicePlayback>,
+    ducking_settings: Res<DuckingSettings>,
+    mut music_ducking: ResMut<MusicDucking>,
+) {
+    let in_ducked_scene = acts.iter().next().is_some_and(|act| {
+        let scene_type = act.get_current_scene().get_scene_type();
+        ducking_settings.ducks_under(scene_type)
+    });
+    let in_dialogue = !text_boxes.is_empty();
+    let in_voice_line = voice_playback.is_playing();
+
+    let target = if in_ducked_scene || in_dialogue || in_voice_line {
+        1.0
+    } else {
+        0.0
+    };
+
+    if music_ducking.target != target {
+        music_ducking.target = target;
+    }
+}
+
+/// Eases the music down, or back up, towards how ducked it should be.
+pub fn ramp_music_ducking(
+    time: Res<Time>,
+    ducking_settings: Res<DuckingSettings>,
+    mut music_ducking: ResMut<MusicDucking>,
+    mut audio_buses: ResMut<AudioBuses>,
+) {
+    if music_ducking.current == music_ducking.target {
+        return;
+    }
+
+    let ramp_duration = ducking_settings.get_ramp_duration();
+    let step = if ramp_duration.is_zero() {
+        1.0
+    } else {
+        time.delta_secs() / ramp_duration.as_secs_f32()
+    };
+
+    let remaining = music_ducking.target - music_ducking.current;
+    music_ducking.current += remaining.clamp(-step, step);
+
+    let music_volume = 1.0 - ducking_settings.get_amount() * music_ducking.current;
+    audio_buses.set_ducking(AudioBus::Music, music_volume);
+}
+
+/// Brings the music all the way back up, such as when leaving the scene.
+pub fn reset_music_ducking(
+    mut music_ducking: ResMut<MusicDucking>,
+    mut audio_buses: ResMut<AudioBuses>,
+) {
+    *music_ducking = MusicDucking::default();
+    audio_buses.set_ducking(AudioBus::Music, 1.0);
+}
@@ -0,0 +1,117 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::PathBuf};
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    audio::sfx::SfxChannel,
+    map::{
+        materials::MaterialCollection, movement::grid_based_movement::ReachedTarget, player::Player,
+    },
+};
+
+/// How far the pitch of a footstep can stray from the sound itself, up or down.
+const PITCH_VARIATION: f64 = 0.08;
+
+/// Which footstep sound plays on each material, as paths rooted at the assets folder.
+///
+/// The game's own table only has the default footstep for now, until sounds are made for
+/// each material.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FootstepSounds {
+    /// Played on tiles without a material, or with a material missing from the table.
+    default: PathBuf,
+    #[serde(default)]
+    materials: HashMap<String, PathBuf>,
+}
+
+impl Default for FootstepSounds {
+    fn default() -> Self {
+        Self {
+            default: PathBuf::from("audio/sfx/player_walk.wav"),
+            materials: Default::default(),
+        }
+    }
+}
+
+impl FootstepSounds {
+    /// Returns the footstep sounds written in a JSON footstep file.
+    pub fn load_from(footstep_file: PathBuf) -> Self {
+        let file = File::open(footstep_file).expect("load_from: Unable to open file");
+        let reader = BufReader::new(file);
+
+        serde_json::from_reader(reader).expect("load_from: Unable to parse the footstep file.")
+    }
+
+    /// Returns the sound of stepping onto some material.
+    pub fn get_sound_for(&self, material: Option<&String>) -> &PathBuf {
+        material
+            .and_then(|material| self.materials.get(material))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Asks for a single footstep to be played.
+#[derive(Message, Debug, Clone)]
+pub struct PlayFootstep {
+    sound: PathBuf,
+    playback_rate: f64,
+}
+
+impl PlayFootstep {
+    pub fn new(sound: PathBuf, playback_rate: f64) -> Self {
+        Self {
+            sound,
+            playback_rate,
+        }
+    }
+
+    pub fn get_sound(&self) -> &PathBuf {
+        &self.sound
+    }
+
+    /// Returns how fast the footstep is played, where higher is also higher pitched.
+    pub fn get_playback_rate(&self) -> f64 {
+        self.playback_rate
+    }
+}
+
+/// Picks a footstep sound each time the player finishes a step, from the material
+/// of the tile they stepped onto.
+pub fn choose_footstep_sounds(
+    mut reached_targets: MessageReader<ReachedTarget>,
+    players: Query<(), With<Player>>,
+    material_collection: Query<&MaterialCollection>,
+    footstep_sounds: Res<FootstepSounds>,
+    mut play_footstep_requests: MessageWriter<PlayFootstep>,
+) {
+    for reached_target in reached_targets.read() {
+        if !players.contains(reached_target.get_entity()) {
+            continue;
+        }
+
+        let material = material_collection
+            .iter()
+            .next()
+            .and_then(|materials| materials.get(reached_target.get_to()));
+        let sound = footstep_sounds.get_sound_for(material);
+
+        // Every step is pitched a little differently, so walking doesn't sound mechanical.
+        let playback_rate = 1.0 + (fastrand::f64() * 2.0 - 1.0) * PITCH_VARIATION;
+
+        play_footstep_requests.write(PlayFootstep::new(sound.clone(), playback_rate));
+    }
+}
+
+pub fn play_footstep_sounds(
+    mut play_footstep_requests: MessageReader<PlayFootstep>,
+    asset_server: Res<AssetServer>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+) {
+    for footstep in play_footstep_requests.read() {
+        sfx_channel
+            .play(asset_server.load(footstep.get_sound().clone()))
+            .with_playback_rate(footstep.get_playback_rate());
+    }
+}
@@ -1,2 +1,19 @@
+pub mod ambience;
+pub mod buses;
+pub mod ducking;
+pub mod footsteps;
 pub mod music;
 pub mod sfx;
+pub mod voice;
+
+/// How quiet a sound can get before it's as good as silent, in decibels.
+const SILENT_DECIBELS: f32 = -60.0;
+
+/// Returns a volume from 0 to 1 as decibels, which is what the audio channels mix with.
+pub fn volume_to_decibels(volume: f32) -> f32 {
+    if volume <= 0.0 {
+        return SILENT_DECIBELS;
+    }
+
+    (20.0 * volume.log10()).max(SILENT_DECIBELS)
+}
@@ -1,30 +1,253 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
 use bevy::prelude::*;
-use bevy_kira_audio::{AudioChannel, AudioControl};
+use bevy_kira_audio::{AudioChannel, AudioControl, AudioTween};
+use tiled::Map;
+
+use crate::{
+    map::{
+        get_string_property,
+        interactions::map_changing::{CurrentLevel, LoadLevel},
+    },
+    narrative::acts::Act,
+};
 
-use crate::map::interactions::map_changing::LoadLevel;
+/// Where music tracks are found, relative to the assets folder.
+const MUSIC_FOLDER: &str = "audio/music";
 
 #[derive(Default, Component, Resource)]
 pub struct MusicChannel;
 
-pub fn play_level_music(
+/// How long one track takes to fade out while the next fades in.
+#[derive(Resource, Clone, Copy)]
+pub struct MusicFadeDuration {
+    duration: Duration,
+}
+
+impl MusicFadeDuration {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl Default for MusicFadeDuration {
+    fn default() -> Self {
+        Self::new(Duration::from_secs_f32(1.5))
+    }
+}
+
+/// The track that's playing, as a path rooted at the assets folder.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct PlayingMusic {
+    track: Option<PathBuf>,
+}
+
+impl PlayingMusic {
+    pub fn get_track(&self) -> Option<&PathBuf> {
+        self.track.as_ref()
+    }
+}
+
+/// The track the current act scene plays over whatever level is loaded, if it has one.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SceneMusic {
+    track: Option<PathBuf>,
+}
+
+impl SceneMusic {
+    pub fn get_track(&self) -> Option<&PathBuf> {
+        self.track.as_ref()
+    }
+}
+
+/// Asks for some track to be played, or for the music to fade out when there's no track.
+#[derive(Message, Debug, Clone)]
+pub struct PlayMusic {
+    track: Option<PathBuf>,
+}
+
+impl PlayMusic {
+    pub fn new(track: PathBuf) -> Self {
+        Self { track: Some(track) }
+    }
+
+    pub fn silence() -> Self {
+        Self { track: None }
+    }
+
+    pub fn get_track(&self) -> Option<&PathBuf> {
+        self.track.as_ref()
+    }
+}
+
+/// Sent whenever the music moves on to a different track, or fades out.
+#[derive(Message, Debug, Clone)]
+pub struct MusicChanged {
+    track: Option<PathBuf>,
+}
+
+impl MusicChanged {
+    pub fn new(track: Option<PathBuf>) -> Self {
+        Self { track }
+    }
+
+    pub fn get_track(&self) -> Option<&PathBuf> {
+        self.track.as_ref()
+    }
+}
+
+/// Returns the path of a track in the music folder from its name, where tracks without
+/// a file extension are taken to be .wav files | ex. village -> audio/music/village.wav
+pub fn get_music_path(track_name: &str) -> PathBuf {
+    let mut music_path = PathBuf::from(MUSIC_FOLDER).join(track_name.trim());
+
+    if music_path.extension().is_none() {
+        music_path.set_extension("wav");
+    }
+
+    music_path
+}
+
+/// Returns the track a map plays, set by its Music property, or else named after its file.
+pub fn get_level_music(map: &Map, level_path: &Path) -> PathBuf {
+    if let Some(track_name) = get_string_property(&map.properties, "Music") {
+        return get_music_path(&track_name);
+    }
+
+    let level_name = LoadLevel::from_path(level_path.to_path_buf()).get_level_name();
+    get_music_path(&level_name)
+}
+
+/// Asks for the music of each level loaded, unless an act scene is playing its own.
+pub fn choose_level_music(
+    current_level: Option<Res<CurrentLevel>>,
+    scene_music: Res<SceneMusic>,
+    mut play_music_requests: MessageWriter<PlayMusic>,
+) {
+    let Some(current_level) = current_level else {
+        return;
+    };
+    if !current_level.is_changed() || scene_music.get_track().is_some() {
+        return;
+    }
+
+    if let Some(level_music) = current_level.get_music() {
+        play_music_requests.write(PlayMusic::new(level_music.clone()));
+    }
+}
+
+/// Plays the music of each act scene that has some, over the music of the level.
+/// Once a scene without music follows one with, the level's music comes back.
+pub fn choose_scene_music(
+    current_act: Single<Ref<Act>>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut scene_music: ResMut<SceneMusic>,
+    mut play_music_requests: MessageWriter<PlayMusic>,
+) {
+    if !current_act.is_changed() {
+        return;
+    }
+
+    let current_scene = current_act.get_current_scene();
+    let had_scene_music = scene_music.get_track().is_some();
+    scene_music.track = current_scene.get_music().cloned();
+
+    if let Some(track) = scene_music.get_track() {
+        play_music_requests.write(PlayMusic::new(track.clone()));
+        return;
+    }
+
+    if !had_scene_music {
+        return;
+    }
+
+    let level_music = current_level.and_then(|current_level| current_level.get_music().cloned());
+    match level_music {
+        Some(level_music) => play_music_requests.write(PlayMusic::new(level_music)),
+        None => play_music_requests.write(PlayMusic::silence()),
+    };
+}
+
+/// Moves the music on to the last track asked for, unless it's already playing.
+pub fn switch_music(
+    mut play_music_requests: MessageReader<PlayMusic>,
+    mut playing_music: ResMut<PlayingMusic>,
+    mut music_changed_broadcaster: MessageWriter<MusicChanged>,
+) {
+    let found_request = play_music_requests.read().last();
+    if found_request.is_none() {
+        return;
+    }
+
+    let mut track = found_request.unwrap().get_track().cloned();
+
+    // Bevy will not report the folder of the asset server, so this looks in the
+    // `assets` folder at the root of the project, like image cutscenes do.
+    if let Some(track_path) = &track {
+        if !Path::new("assets").join(track_path).exists() {
+            warn!(
+                "switch_music: No music found at assets/{}, fading out instead.",
+                track_path.display()
+            );
+            track = None;
+        }
+    }
+
+    if playing_music.track == track {
+        return;
+    }
+
+    playing_music.track = track.clone();
+    music_changed_broadcaster.write(MusicChanged::new(track));
+}
+
+/// Fades the track that was playing out while the next one fades in.
+pub fn crossfade_music(
+    mut music_changes: MessageReader<MusicChanged>,
+    music_fade_duration: Res<MusicFadeDuration>,
     asset_server: Res<AssetServer>,
-    mut level_change_requests: MessageReader<LoadLevel>,
     background_music: Res<AudioChannel<MusicChannel>>,
 ) {
-    let level_has_changed = !level_change_requests.is_empty();
-    if !level_has_changed {
+    let found_music_change = music_changes.read().last();
+    if found_music_change.is_none() {
         return;
     }
 
-    let level_identifier = level_change_requests
-        .read()
-        .next()
-        .unwrap()
-        .get_level_name();
-    let audio_file_path = format!("audio/music/{}.wav", level_identifier);
+    let fade = AudioTween::linear(music_fade_duration.get_duration());
+
+    background_music.stop().fade_out(fade.clone());
+
+    if let Some(track) = found_music_change.unwrap().get_track() {
+        background_music
+            .play(asset_server.load(track.clone()))
+            .looped()
+            .fade_in(fade);
+    }
+}
+
+/// Forgets the music that was playing, so it starts over once back in the scene.
+pub fn reset_music(mut playing_music: ResMut<PlayingMusic>, mut scene_music: ResMut<SceneMusic>) {
+    *playing_music = PlayingMusic::default();
+    *scene_music = SceneMusic::default();
+}
+
+/// Holds the music where it is while the game is paused.
+pub fn pause_music(background_music: Res<AudioChannel<MusicChannel>>) {
+    background_music.pause();
+}
+
+/// Picks the music back up from where it was paused.
+pub fn resume_music(background_music: Res<AudioChannel<MusicChannel>>) {
+    background_music.resume();
+}
 
+pub fn stop_music(background_music: Res<AudioChannel<MusicChannel>>) {
     background_music.stop();
-    background_music
-        .play(asset_server.load(audio_file_path))
-        .looped();
 }
@@ -1,66 +1,247 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::PathBuf, time::Duration};
+
 use bevy::prelude::*;
 use bevy_kira_audio::{AudioChannel, AudioControl};
+use serde::{Deserialize, Serialize};
 
-use crate::map::player::*;
+use crate::{
+    audio::volume_to_decibels,
+    map::{
+        interactions::map_changing::ChangeLevel,
+        player::{PlayerInteraction, PlayerMovementActions},
+    },
+};
 
-pub fn load_player_movement_sound(
-    asset_server: Res<AssetServer>,
-    player_movement_sound: Res<AudioChannel<PlayerWalkChannel>>,
-) {
-    let audio_file_path = "audio/sfx/player_walk.wav";
-    player_movement_sound
-        .play(asset_server.load(audio_file_path))
-        .looped();
-    player_movement_sound.pause();
+/// The shared channel every sound effect is played on.
+#[derive(Default, Component, Resource)]
+pub struct SfxChannel;
+
+/// The name of something happening in the game that a sound effect can be set to play on,
+/// as written in the SFX manifest.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct SfxCue {
+    name: String,
 }
 
-pub fn play_player_movement_sound(
-    mut player_movement_receiver: MessageReader<PlayerMovementActions>,
-    player_movement_sound: Res<AudioChannel<PlayerWalkChannel>>,
-) {
-    if player_movement_receiver.is_empty() {
-        player_movement_sound.pause();
-        return;
+impl SfxCue {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+}
+
+/// A game message that can set off a sound effect.
+pub trait SfxSource: Message {
+    /// Returns the cue this message sets off, if it sets one off at all.
+    fn get_sfx_cue(&self) -> Option<SfxCue>;
+}
+
+impl SfxSource for PlayerMovementActions {
+    fn get_sfx_cue(&self) -> Option<SfxCue> {
+        match self {
+            // The player walked into something they can't walk through.
+            PlayerMovementActions::Bumping => Some(SfxCue::new("Bump")),
+            // Footsteps are chosen by what's being walked on, so they're played on their own.
+            PlayerMovementActions::Walking => None,
+        }
     }
+}
 
-    let has_walked = player_movement_receiver
-        .read()
-        .all(|&movement_action| movement_action == PlayerMovementActions::Walking);
+impl SfxSource for PlayerInteraction {
+    fn get_sfx_cue(&self) -> Option<SfxCue> {
+        Some(SfxCue::new("Interact"))
+    }
+}
 
-    if !has_walked {
-        player_movement_sound.pause();
-    } else {
-        player_movement_sound.resume();
+impl SfxSource for ChangeLevel {
+    fn get_sfx_cue(&self) -> Option<SfxCue> {
+        Some(SfxCue::new("ChangeLevel"))
     }
 }
 
-pub fn load_player_bump_sound(
-    asset_server: Res<AssetServer>,
-    player_bump_sound: Res<AudioChannel<PlayerBumpChannel>>,
+/// A sound effect, along with how it's played.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SfxDefinition {
+    /// The sound to play, rooted at the assets folder.
+    file: PathBuf,
+    /// How loud the sound is played, from 0 to 1.
+    #[serde(default = "default_volume")]
+    volume: f32,
+    /// Seconds to wait before the sound can play again, so held down actions don't spam it.
+    #[serde(default)]
+    cooldown: f32,
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+impl SfxDefinition {
+    pub fn new(file: PathBuf, volume: f32, cooldown: f32) -> Self {
+        Self {
+            file,
+            volume,
+            cooldown,
+        }
+    }
+
+    pub fn get_file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn get_cooldown(&self) -> Duration {
+        Duration::from_secs_f32(self.cooldown.max(0.0))
+    }
+}
+
+/// Which sound effect plays on each cue, keyed by the cue names written in a JSON manifest.
+///
+/// Cues that aren't in the manifest are silent.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SfxRegistry {
+    #[serde(default)]
+    sounds: HashMap<SfxCue, SfxDefinition>,
+}
+
+impl SfxRegistry {
+    /// Returns the sound effects written in a JSON manifest.
+    pub fn load_from(manifest_file: PathBuf) -> Self {
+        let file = File::open(manifest_file).expect("load_from: Unable to open file");
+        let reader = BufReader::new(file);
+
+        serde_json::from_reader(reader).expect("load_from: Unable to parse the SFX manifest.")
+    }
+
+    pub fn get(&self, sfx_cue: &SfxCue) -> Option<&SfxDefinition> {
+        self.sounds.get(sfx_cue)
+    }
+}
+
+/// When each cue last played its sound effect, as time since the game started.
+#[derive(Resource, Debug, Default)]
+pub struct SfxCooldowns {
+    last_played: HashMap<SfxCue, Duration>,
+}
+
+impl SfxCooldowns {
+    /// Returns whether a cue's sound effect is ready to play again, remembering it as
+    /// played now if it is.
+    pub fn try_play(&mut self, sfx_cue: &SfxCue, cooldown: Duration, now: Duration) -> bool {
+        if let Some(last_played) = self.last_played.get(sfx_cue) {
+            if now.saturating_sub(*last_played) < cooldown {
+                return false;
+            }
+        }
+
+        self.last_played.insert(sfx_cue.clone(), now);
+        true
+    }
+}
+
+/// Asks for the sound effect of some cue to be played.
+#[derive(Message, Debug, Clone)]
+pub struct CueSfx {
+    sfx_cue: SfxCue,
+}
+
+impl CueSfx {
+    pub fn new(sfx_cue: SfxCue) -> Self {
+        Self { sfx_cue }
+    }
+
+    pub fn get_sfx_cue(&self) -> &SfxCue {
+        &self.sfx_cue
+    }
+}
+
+/// Asks for a single sound effect to be played.
+#[derive(Message, Debug, Clone)]
+pub struct PlaySfx {
+    sound: PathBuf,
+    volume: f32,
+}
+
+impl PlaySfx {
+    pub fn new(sound: PathBuf, volume: f32) -> Self {
+        Self { sound, volume }
+    }
+
+    pub fn get_sound(&self) -> &PathBuf {
+        &self.sound
+    }
+
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+}
+
+/// Cues a sound effect for each message of some kind that sets one off.
+pub fn cue_sfx_from<M: SfxSource>(
+    mut sfx_sources: MessageReader<M>,
+    mut sfx_cues: MessageWriter<CueSfx>,
 ) {
-    let audio_file_path = "audio/sfx/player_bump.wav";
-    player_bump_sound
-        .play(asset_server.load(audio_file_path))
-        .looped();
-    player_bump_sound.pause();
+    for sfx_source in sfx_sources.read() {
+        if let Some(sfx_cue) = sfx_source.get_sfx_cue() {
+            sfx_cues.write(CueSfx::new(sfx_cue));
+        }
+    }
 }
 
-pub fn play_player_bump_sound(
-    mut player_movement_receiver: MessageReader<PlayerMovementActions>,
-    player_bump_sound: Res<AudioChannel<PlayerBumpChannel>>,
+/// Cues a sound effect for each button that was just clicked.
+pub fn cue_button_click_sfx(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut sfx_cues: MessageWriter<CueSfx>,
 ) {
-    if player_movement_receiver.is_empty() {
-        player_bump_sound.pause();
-        return;
+    for interaction in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            sfx_cues.write(CueSfx::new(SfxCue::new("ButtonClick")));
+        }
     }
+}
 
-    let has_bumped = player_movement_receiver
-        .read()
-        .all(|&movement_action| movement_action == PlayerMovementActions::Bumping);
+/// Picks the sound effect of each cue from the registry, unless it's still cooling down.
+pub fn choose_sfx(
+    mut sfx_cues: MessageReader<CueSfx>,
+    sfx_registry: Res<SfxRegistry>,
+    mut sfx_cooldowns: ResMut<SfxCooldowns>,
+    time: Res<Time>,
+    mut play_sfx_requests: MessageWriter<PlaySfx>,
+) {
+    for cue in sfx_cues.read() {
+        let sfx_cue = cue.get_sfx_cue();
+
+        let found_sfx = sfx_registry.get(sfx_cue);
+        if found_sfx.is_none() {
+            continue;
+        }
+        let sfx = found_sfx.unwrap();
+
+        if !sfx_cooldowns.try_play(sfx_cue, sfx.get_cooldown(), time.elapsed()) {
+            continue;
+        }
+
+        play_sfx_requests.write(PlaySfx::new(sfx.get_file().clone(), sfx.get_volume()));
+    }
+}
 
-    if !has_bumped {
-        player_bump_sound.pause();
-    } else {
-        player_bump_sound.resume();
+pub fn play_sfx(
+    mut play_sfx_requests: MessageReader<PlaySfx>,
+    asset_server: Res<AssetServer>,
+    sfx_channel: Res<AudioChannel<SfxChannel>>,
+) {
+    for sfx in play_sfx_requests.read() {
+        sfx_channel
+            .play(asset_server.load(sfx.get_sound().clone()))
+            .with_volume(volume_to_decibels(sfx.get_volume()));
     }
 }
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+use bevy_kira_audio::AudioChannel;
+
+/// The channel every voice line is played on.
+#[derive(Default, Component, Resource)]
+pub struct VoiceChannel;
+
+/// Whether some voice line is being played, which the music is ducked under.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct VoicePlayback {
+    playing: bool,
+}
+
+impl VoicePlayback {
+    pub fn new(playing: bool) -> Self {
+        Self { playing }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+}
+
+/// Keeps track of whether the voice channel is playing anything.
+pub fn track_voice_playback(
+    voice_channel: Res<AudioChannel<VoiceChannel>>,
+    mut voice_playback: ResMut<VoicePlayback>,
+) {
+    let playing = voice_channel.is_playing_sound();
+
+    if voice_playback.playing != playing {
+        voice_playback.playing = playing;
+    }
+}
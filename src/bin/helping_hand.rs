@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use bevy::prelude::*;
 use bevy_kira_audio::AudioPlugin;
 
@@ -10,8 +12,18 @@ fn main() {
         .add_plugins(AudioPlugin)
         .add_plugins(plugins::acts::ActsPlugin)
         .add_plugins(plugins::levels::LevelsPlugin)
+        .add_plugins(plugins::music::MusicPlugin)
+        .add_plugins(plugins::ambience::AmbiencePlugin)
+        .add_plugins(plugins::sfx::SfxPlugin)
+        .add_plugins(plugins::loading::LoadingPlugin)
+        .add_plugins(plugins::inventory::InventoryPlugin)
+        .add_plugins(plugins::pause_menu::PauseMenuPlugin)
+        .add_plugins(plugins::quests::QuestsPlugin::new(PathBuf::from(
+            "assets/quests/quests.json",
+        )))
         .add_plugins(plugins::playable_character::PlayableCharacterPlugin)
         .add_plugins(plugins::main_menu::MainMenuPlugin)
+        .add_plugins(plugins::display::DisplayPlugin)
         .add_plugins(plugins::settings_menu::SettingsMenuPlugin)
         .run();
 }
@@ -0,0 +1,332 @@
+use bevy::{
+    prelude::*,
+    window::{
+        MonitorSelection, PresentMode, PrimaryMonitor, PrimaryWindow, VideoModeSelection,
+        WindowMode,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// How many pixels wide and tall each tile of the map's art is.
+const TILE_ART_SIZE: u32 = 64;
+/// How many tiles across and down the view is laid out to show at the least,
+/// which is 1280 x 704 pixels of the tile art.
+const VIEW_SIZE_IN_TILES: UVec2 = UVec2::new(20, 11);
+
+/// How the game's window takes up the screen.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    /// Covers the whole screen without changing the monitor's resolution.
+    Borderless,
+    /// Takes over the monitor, switching it to the chosen resolution.
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    /// Every window mode, in the order they're shown in the settings menu.
+    pub const ALL: [WindowModeSetting; 3] = [
+        WindowModeSetting::Windowed,
+        WindowModeSetting::Borderless,
+        WindowModeSetting::Fullscreen,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            WindowModeSetting::Windowed => "Windowed",
+            WindowModeSetting::Borderless => "Borderless",
+            WindowModeSetting::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+/// A size of the window, in physical pixels.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolution {
+    width: u32,
+    height: u32,
+}
+
+impl Resolution {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    pub fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn get_name(&self) -> String {
+        format!("{} x {}", self.width, self.height)
+    }
+}
+
+/// How the game is shown on the screen.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DisplaySettings {
+    #[serde(default)]
+    window_mode: WindowModeSetting,
+    /// The window is left at whatever size it is until a resolution is chosen.
+    #[serde(default)]
+    resolution: Option<Resolution>,
+    #[serde(default = "get_default_vsync")]
+    vsync: bool,
+    /// How much larger menus and other UI are drawn, where 1 is their normal size.
+    #[serde(default = "get_default_ui_scale")]
+    ui_scale: f32,
+    /// Whether the 64px tile art is only ever scaled up by whole numbers, so that every pixel
+    /// of it covers the same number of screen pixels.
+    #[serde(default)]
+    pixel_perfect: bool,
+    /// How many times larger the map is drawn, in whole steps so the tile art stays crisp.
+    #[serde(default = "get_default_camera_zoom")]
+    camera_zoom: u32,
+}
+
+fn get_default_vsync() -> bool {
+    true
+}
+
+fn get_default_ui_scale() -> f32 {
+    1.0
+}
+
+fn get_default_camera_zoom() -> u32 {
+    1
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            window_mode: WindowModeSetting::default(),
+            resolution: None,
+            vsync: get_default_vsync(),
+            ui_scale: get_default_ui_scale(),
+            pixel_perfect: false,
+            camera_zoom: get_default_camera_zoom(),
+        }
+    }
+}
+
+impl DisplaySettings {
+    /// The smallest and largest the UI can be scaled to.
+    pub const UI_SCALE_RANGE: (f32, f32) = (0.5, 2.0);
+    /// The furthest out and furthest in the camera can be zoomed.
+    pub const CAMERA_ZOOM_RANGE: (u32, u32) = (1, 4);
+
+    pub fn get_window_mode(&self) -> WindowModeSetting {
+        self.window_mode
+    }
+
+    pub fn set_window_mode(&mut self, window_mode: WindowModeSetting) {
+        self.window_mode = window_mode;
+    }
+
+    pub fn get_resolution(&self) -> Option<Resolution> {
+        self.resolution
+    }
+
+    pub fn set_resolution(&mut self, resolution: Resolution) {
+        self.resolution = Some(resolution);
+    }
+
+    pub fn has_vsync(&self) -> bool {
+        self.vsync
+    }
+
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.vsync = vsync;
+    }
+
+    pub fn get_ui_scale(&self) -> f32 {
+        self.ui_scale
+    }
+
+    pub fn set_ui_scale(&mut self, ui_scale: f32) {
+        let (min_ui_scale, max_ui_scale) = Self::UI_SCALE_RANGE;
+        self.ui_scale = ui_scale.clamp(min_ui_scale, max_ui_scale);
+    }
+
+    pub fn is_pixel_perfect(&self) -> bool {
+        self.pixel_perfect
+    }
+
+    pub fn set_pixel_perfect(&mut self, pixel_perfect: bool) {
+        self.pixel_perfect = pixel_perfect;
+    }
+
+    pub fn get_camera_zoom(&self) -> u32 {
+        self.camera_zoom
+    }
+
+    pub fn set_camera_zoom(&mut self, camera_zoom: u32) {
+        let (min_camera_zoom, max_camera_zoom) = Self::CAMERA_ZOOM_RANGE;
+        self.camera_zoom = camera_zoom.clamp(min_camera_zoom, max_camera_zoom);
+    }
+}
+
+/// Returns every resolution some monitor can be switched to, largest first, along with
+/// the resolution the window is at right now.
+pub fn get_resolution_options(monitor: Option<&Monitor>, current: Resolution) -> Vec<Resolution> {
+    let mut resolutions: Vec<Resolution> = monitor
+        .map(|monitor| {
+            monitor
+                .video_modes
+                .iter()
+                .map(|video_mode| {
+                    Resolution::new(video_mode.physical_size.x, video_mode.physical_size.y)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !resolutions.contains(&current) {
+        resolutions.push(current);
+    }
+
+    resolutions.sort_by_key(|resolution| std::cmp::Reverse((resolution.width, resolution.height)));
+    resolutions.dedup();
+
+    resolutions
+}
+
+/// Returns the resolution some window is at right now.
+pub fn get_window_resolution(window: &Window) -> Resolution {
+    Resolution::new(
+        window.resolution.physical_width(),
+        window.resolution.physical_height(),
+    )
+}
+
+/// Changes the window to match the display settings whenever they change.
+pub fn apply_display_settings(
+    display_settings: Res<DisplaySettings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    if !display_settings.is_changed() {
+        return;
+    }
+
+    ui_scale.0 = display_settings.get_ui_scale();
+
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+
+    let resolution = display_settings.get_resolution();
+
+    window.mode = match display_settings.get_window_mode() {
+        WindowModeSetting::Windowed => WindowMode::Windowed,
+        WindowModeSetting::Borderless => {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        }
+        WindowModeSetting::Fullscreen => {
+            // The monitor is switched to the chosen resolution at its highest refresh rate,
+            // or left as it is when it can't show that resolution.
+            let video_mode = resolution.and_then(|resolution| {
+                monitors.iter().next().and_then(|monitor| {
+                    monitor
+                        .video_modes
+                        .iter()
+                        .filter(|video_mode| {
+                            video_mode.physical_size
+                                == UVec2::new(resolution.width, resolution.height)
+                        })
+                        .max_by_key(|video_mode| video_mode.refresh_rate_millihertz)
+                        .copied()
+                })
+            });
+
+            let video_mode_selection = match video_mode {
+                Some(video_mode) => VideoModeSelection::Specific(video_mode),
+                None => VideoModeSelection::Current,
+            };
+
+            WindowMode::Fullscreen(MonitorSelection::Current, video_mode_selection)
+        }
+    };
+
+    if let Some(resolution) = resolution {
+        if get_window_resolution(&window) != resolution {
+            window
+                .resolution
+                .set_physical_resolution(resolution.width, resolution.height);
+        }
+    }
+
+    window.present_mode = if display_settings.has_vsync() {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+}
+
+/// Returns how many screen pixels each pixel of the tile art covers, when it's kept pixel-perfect.
+/// This is the largest whole number the view can be scaled up by while still fitting the window.
+pub fn get_pixel_perfect_scale(window: &Window) -> f32 {
+    let view_size = VIEW_SIZE_IN_TILES * TILE_ART_SIZE;
+
+    let fitting_scale = (window.resolution.physical_width() / view_size.x)
+        .min(window.resolution.physical_height() / view_size.y);
+
+    fitting_scale.max(1) as f32
+}
+
+/// Scales every 2D camera by the camera zoom. When pixel-perfect scaling is on, each pixel
+/// of the tile art also covers a whole number of screen pixels however the screen itself is scaled.
+pub fn apply_camera_scaling(
+    display_settings: Res<DisplaySettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<&mut Projection, With<Camera2d>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    let screen_scale = if display_settings.is_pixel_perfect() {
+        window.scale_factor() / get_pixel_perfect_scale(window)
+    } else {
+        1.0
+    };
+    let camera_scale = screen_scale / display_settings.get_camera_zoom().max(1) as f32;
+
+    for mut projection in &mut cameras {
+        let Projection::Orthographic(orthographic) = &*projection else {
+            continue;
+        };
+
+        if orthographic.scale == camera_scale {
+            continue;
+        }
+
+        if let Projection::Orthographic(orthographic) = &mut *projection {
+            orthographic.scale = camera_scale;
+        }
+    }
+}
+
+/// Zooms the camera in a step with the equals key, or out a step with the minus key.
+/// It's meant for debugging, since the zoom is normally set from the settings menu,
+/// so it's only found in debug builds.
+#[cfg(debug_assertions)]
+pub fn zoom_camera_on_key_press(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut display_settings: ResMut<DisplaySettings>,
+) {
+    let camera_zoom = display_settings.get_camera_zoom();
+
+    if keyboard_input.just_pressed(KeyCode::Equal) {
+        display_settings.set_camera_zoom(camera_zoom + 1);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Minus) {
+        display_settings.set_camera_zoom(camera_zoom.saturating_sub(1));
+    }
+}
@@ -1,9 +1,13 @@
 use bevy::prelude::*;
 
 pub mod audio;
+pub mod display;
+pub mod loading;
 pub mod map;
 pub mod narrative;
 pub mod plugins;
+pub mod saving;
+pub mod settings;
 pub mod ui;
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
@@ -11,5 +15,32 @@ pub enum AppState {
     #[default]
     MainMenu,
     SettingsMenu,
+    /// Everything the next level or act needs is being loaded behind a loading screen.
+    Loading,
     InScene,
 }
+
+/// Leaving the scene for the title screen.
+///
+/// The scene is also left for the loading screen whenever a level or act is loaded, so
+/// whatever should only go away once the game is quit is cleaned up on this transition instead
+/// of when exiting the scene.
+pub const QUIT_TO_TITLE: OnTransition<AppState> = OnTransition {
+    exited: AppState::InScene,
+    entered: AppState::MainMenu,
+};
+
+/// Whether the game is being played or held still while the player looks through a menu.
+/// It only exists while in a scene.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, SubStates)]
+#[source(AppState = AppState::InScene)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Inventory,
+    QuestLog,
+    /// The pause menu is open and time stands still.
+    Paused,
+    /// The settings menu was opened from the pause menu, so time still stands still.
+    Settings,
+}
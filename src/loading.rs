@@ -0,0 +1,368 @@
+use std::path::{Path, PathBuf};
+
+use bevy::{asset::LoadState, prelude::*};
+use bevy_kira_audio::AudioSource;
+
+use crate::{
+    audio::music::get_level_music,
+    map::{
+        interactions::map_changing::{load_tiled_map, LoadLevel},
+        render::to_bevy_path,
+    },
+    narrative::{
+        act_loading::LoadAct,
+        acts::{Act, ActLoader, SceneContents},
+        image_layouts::ImageCutsceneLayout,
+    },
+    plugins::acts::MapsFolderPath,
+    AppState,
+};
+
+/// The act the game starts with, loaded when the loading screen is entered from the title screen.
+#[derive(Resource, Clone)]
+pub struct StartingAct {
+    act_path: PathBuf,
+}
+
+impl StartingAct {
+    pub fn new(act_path: PathBuf) -> Self {
+        Self { act_path }
+    }
+
+    pub fn get_path(&self) -> &PathBuf {
+        &self.act_path
+    }
+}
+
+/// A level or act that's only built once its assets are loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingLoad {
+    Level(PathBuf),
+    Act(PathBuf),
+}
+
+/// The levels and acts waiting on their assets behind the loading screen, along with those
+/// whose assets have loaded and are ready to be built.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PendingLoads {
+    waiting: Vec<PendingLoad>,
+    loaded: Vec<PendingLoad>,
+}
+
+impl PendingLoads {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a level or act has to wait for its assets first, holding onto it if so.
+    /// Once its assets have loaded, it's let through the next time it's asked for.
+    pub fn hold_back(&mut self, pending_load: PendingLoad) -> bool {
+        let found_loaded = self
+            .loaded
+            .iter()
+            .position(|loaded| *loaded == pending_load);
+
+        if let Some(loaded_index) = found_loaded {
+            self.loaded.remove(loaded_index);
+            return false;
+        }
+
+        if !self.waiting.contains(&pending_load) {
+            self.waiting.push(pending_load);
+        }
+
+        true
+    }
+
+    pub fn get_waiting(&self) -> &Vec<PendingLoad> {
+        &self.waiting
+    }
+
+    pub fn is_waiting(&self) -> bool {
+        !self.waiting.is_empty()
+    }
+
+    /// Returns everything that was waiting, letting each through the next time it's asked for.
+    pub fn finish_waiting(&mut self) -> Vec<PendingLoad> {
+        let finished = std::mem::take(&mut self.waiting);
+        self.loaded.extend(finished.clone());
+
+        finished
+    }
+}
+
+/// Returns whether a level or act is held back until its assets are loaded behind the
+/// loading screen, which is entered if so. Without a loading screen, everything is built
+/// right away.
+pub fn hold_back_until_loaded(
+    pending_loads: Option<&mut PendingLoads>,
+    next_state: &mut NextState<AppState>,
+    pending_load: PendingLoad,
+) -> bool {
+    let Some(pending_loads) = pending_loads else {
+        return false;
+    };
+
+    if !pending_loads.hold_back(pending_load) {
+        return false;
+    }
+
+    next_state.set(AppState::Loading);
+    true
+}
+
+/// Every asset an act needs, such as cutscene images, tilesets, character sheets and music,
+/// as paths rooted at the assets folder.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct NeededAssets {
+    image_paths: Vec<PathBuf>,
+    audio_paths: Vec<PathBuf>,
+}
+
+impl NeededAssets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the assets needed by every scene of an act.
+    pub fn from_act(act: &Act) -> Self {
+        let mut needed_assets = Self::new();
+        needed_assets.add_act(act);
+
+        needed_assets
+    }
+
+    /// Adds the assets needed by every scene of an act.
+    pub fn add_act(&mut self, act: &Act) {
+        for scene in act.get_scenes() {
+            match scene.get_scene_contents() {
+                SceneContents::ImageCutscene(image_path) => {
+                    self.add_image_cutscene(image_path, scene.get_image_layout())
+                }
+                SceneContents::MapCutscene(map_path, _) => self.add_map(map_path),
+            }
+
+            if let Some(music) = scene.get_music() {
+                self.add_audio(music.clone());
+            }
+        }
+    }
+
+    /// Adds an image cutscene's image, along with any images layered over it.
+    pub fn add_image_cutscene(&mut self, image_path: &Path, layout: Option<&ImageCutsceneLayout>) {
+        let images_folder = PathBuf::from("acts/images");
+
+        self.add_image(images_folder.join(image_path));
+
+        if let Some(layout) = layout {
+            for layer in layout.get_layers() {
+                self.add_image(images_folder.join(layer.get_image()));
+            }
+        }
+    }
+
+    /// Adds the tilesets and character sheets of a Tiled map, along with the music
+    /// played while it's loaded.
+    pub fn add_map(&mut self, map_path: &Path) {
+        let map = load_tiled_map(map_path.to_path_buf());
+
+        for tileset in map.tilesets() {
+            if let Some(tileset_image) = &tileset.image {
+                self.add_image(to_bevy_path(&tileset_image.source));
+            }
+        }
+
+        self.add_audio(get_level_music(&map, map_path));
+    }
+
+    pub fn add_image(&mut self, image_path: PathBuf) {
+        if !self.image_paths.contains(&image_path) {
+            self.image_paths.push(image_path);
+        }
+    }
+
+    pub fn add_audio(&mut self, audio_path: PathBuf) {
+        if !self.audio_paths.contains(&audio_path) {
+            self.audio_paths.push(audio_path);
+        }
+    }
+
+    pub fn get_image_paths(&self) -> &Vec<PathBuf> {
+        &self.image_paths
+    }
+
+    pub fn get_audio_paths(&self) -> &Vec<PathBuf> {
+        &self.audio_paths
+    }
+}
+
+/// Handles to every asset being loaded behind the loading screen.
+///
+/// They're held onto after loading so the assets aren't dropped before the scene uses them.
+/// Those of the load before are kept too, as the old scene stays up until the new one is built.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LoadingAssets {
+    handles: Vec<UntypedHandle>,
+    loaded_handles: Vec<UntypedHandle>,
+}
+
+impl LoadingAssets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, handle: UntypedHandle) {
+        self.handles.push(handle);
+    }
+
+    /// Starts loading over, keeping the assets of the last load around and dropping any
+    /// from before it.
+    pub fn start_over(&mut self) {
+        self.loaded_handles = std::mem::take(&mut self.handles);
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns how many assets have either loaded or failed to load.
+    pub fn get_num_finished(&self, asset_server: &AssetServer) -> usize {
+        self.handles
+            .iter()
+            .filter(|handle| {
+                matches!(
+                    asset_server.get_load_state(handle.id()),
+                    Some(LoadState::Loaded) | Some(LoadState::Failed(_))
+                )
+            })
+            .count()
+    }
+
+    /// Returns how many assets failed to load.
+    pub fn get_num_failed(&self, asset_server: &AssetServer) -> usize {
+        self.handles
+            .iter()
+            .filter(|handle| {
+                matches!(
+                    asset_server.get_load_state(handle.id()),
+                    Some(LoadState::Failed(_))
+                )
+            })
+            .count()
+    }
+
+    /// Returns how far along loading is, from 0 to 1.
+    pub fn get_progress(&self, asset_server: &AssetServer) -> f32 {
+        if self.is_empty() {
+            return 1.0;
+        }
+
+        self.get_num_finished(asset_server) as f32 / self.len() as f32
+    }
+
+    pub fn is_finished(&self, asset_server: &AssetServer) -> bool {
+        self.get_num_finished(asset_server) == self.len()
+    }
+}
+
+/// Holds back the starting act when the loading screen is entered with nothing else waiting,
+/// which only happens when starting the game from the title screen.
+pub fn queue_starting_act(starting_act: Res<StartingAct>, mut pending_loads: ResMut<PendingLoads>) {
+    if pending_loads.is_waiting() {
+        return;
+    }
+
+    pending_loads.hold_back(PendingLoad::Act(starting_act.get_path().clone()));
+}
+
+/// Finds every asset the levels and acts waiting on the loading screen need, and starts
+/// loading their images.
+pub fn queue_needed_images(
+    pending_loads: Res<PendingLoads>,
+    maps_folder_path: Res<MapsFolderPath>,
+    asset_server: Res<AssetServer>,
+    loading_assets: Option<ResMut<LoadingAssets>>,
+    mut commands: Commands,
+) {
+    let mut needed_assets = NeededAssets::new();
+
+    for pending_load in pending_loads.get_waiting() {
+        match pending_load {
+            PendingLoad::Level(level_path) => needed_assets.add_map(level_path),
+            PendingLoad::Act(act_path) => {
+                let act_loader = ActLoader::new(act_path.clone(), maps_folder_path.get_path());
+                needed_assets.add_act(&act_loader.read_act_from());
+            }
+        }
+    }
+
+    let mut loading_assets = loading_assets
+        .map(|loading_assets| loading_assets.clone())
+        .unwrap_or_default();
+    loading_assets.start_over();
+
+    for image_path in needed_assets.get_image_paths() {
+        let image: Handle<Image> = asset_server.load(image_path.clone());
+        loading_assets.add(image.untyped());
+    }
+
+    commands.insert_resource(needed_assets);
+    commands.insert_resource(loading_assets);
+}
+
+/// Starts loading the music and sounds the levels and acts waiting on the loading screen need.
+pub fn queue_needed_audio(
+    needed_assets: Res<NeededAssets>,
+    mut loading_assets: ResMut<LoadingAssets>,
+    asset_server: Res<AssetServer>,
+) {
+    for audio_path in needed_assets.get_audio_paths() {
+        let audio: Handle<AudioSource> = asset_server.load(audio_path.clone());
+        loading_assets.add(audio.untyped());
+    }
+}
+
+/// Enters the scene once every asset has either loaded or failed to load.
+pub fn finish_loading(
+    loading_assets: Res<LoadingAssets>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !loading_assets.is_finished(&asset_server) {
+        return;
+    }
+
+    let num_failed = loading_assets.get_num_failed(&asset_server);
+    if num_failed > 0 {
+        warn!(
+            "finish_loading: {} of {} assets failed to load.",
+            num_failed,
+            loading_assets.len()
+        );
+    }
+
+    next_state.set(AppState::InScene);
+}
+
+/// Asks for the levels and acts that waited on the loading screen again, now that their
+/// assets have loaded.
+pub fn release_pending_loads(
+    mut pending_loads: ResMut<PendingLoads>,
+    mut load_level_requests: MessageWriter<LoadLevel>,
+    mut load_act_requests: MessageWriter<LoadAct>,
+) {
+    for pending_load in pending_loads.finish_waiting() {
+        match pending_load {
+            PendingLoad::Level(level_path) => {
+                load_level_requests.write(LoadLevel::from_path(level_path));
+            }
+            PendingLoad::Act(act_path) => {
+                load_act_requests.write(LoadAct::new(act_path.to_str().unwrap()));
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use bevy::prelude::*;
+use tiled::{Map, PropertyValue};
+
+use crate::map::{get_string_property, player::Player, GridCords3D};
+
+/// A sound looping somewhere on the map, such as a stream or a crackling fire, which
+/// gets louder the closer the player walks up to it.
+///
+/// Emitters are AmbientSound objects placed on any object layer, with a File property
+/// rooted at the assets folder, a Radius in tiles and a Volume from 0 to 1.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct AmbientSound {
+    file: PathBuf,
+    radius: f32,
+    volume: f32,
+    grid_cords: GridCords3D,
+}
+
+impl AmbientSound {
+    pub fn new(file: PathBuf, radius: f32, volume: f32, grid_cords: GridCords3D) -> Self {
+        Self {
+            file,
+            radius,
+            volume,
+            grid_cords,
+        }
+    }
+
+    pub fn get_file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    /// Returns how many tiles away the sound can still be heard.
+    pub fn get_radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Returns how loud the sound is when standing right on top of it.
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn get_grid_coordinates(&self) -> &GridCords3D {
+        &self.grid_cords
+    }
+
+    /// Returns how loud the sound is and where it pans to, as heard from some tile.
+    pub fn get_mix_at(&self, listener: &GridCords3D) -> AmbientMix {
+        let x_offset = self.grid_cords.get_x() as f32 - listener.get_x() as f32;
+        let y_offset = self.grid_cords.get_y() as f32 - listener.get_y() as f32;
+        let distance = Vec2::new(x_offset, y_offset).length();
+
+        let falloff = (1.0 - distance / self.radius).clamp(0.0, 1.0);
+        let panning = (x_offset / self.radius).clamp(-1.0, 1.0);
+
+        AmbientMix::new(self.volume * falloff, panning)
+    }
+}
+
+/// How an ambient sound is heard from where the player stands.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct AmbientMix {
+    volume: f32,
+    panning: f32,
+}
+
+impl AmbientMix {
+    pub fn new(volume: f32, panning: f32) -> Self {
+        Self { volume, panning }
+    }
+
+    /// Returns the volume from 0 to 1, which is silent once out of the sound's radius.
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    /// Returns where the sound is panned, from -1 on the left to 1 on the right.
+    pub fn get_panning(&self) -> f32 {
+        self.panning
+    }
+}
+
+/// Returns every ambient sound placed on the map.
+pub fn get_ambient_sounds_from(tiled_map: &Map) -> Vec<AmbientSound> {
+    let tile_width = tiled_map.tile_width as f32;
+    let tile_height = tiled_map.tile_height as f32;
+
+    let mut ambient_sounds = Vec::new();
+
+    for layer in tiled_map.layers() {
+        let found_object_layer = layer.as_object_layer();
+        if found_object_layer.is_none() {
+            continue;
+        }
+
+        for object in found_object_layer.unwrap().objects() {
+            if object.user_type != "AmbientSound" {
+                continue;
+            }
+
+            let properties = &object.properties;
+
+            let found_file = get_string_property(properties, "File");
+            if found_file.is_none() {
+                warn!(
+                    "get_ambient_sounds_from: Ambient sound {} has no File, so it's left out.",
+                    object.name
+                );
+                continue;
+            }
+
+            let radius = get_number_property(properties, "Radius").unwrap_or(0.0);
+            if radius <= 0.0 {
+                warn!(
+                    "get_ambient_sounds_from: Ambient sound {} needs a Radius above 0, so it's left out.",
+                    object.name
+                );
+                continue;
+            }
+
+            let volume = get_number_property(properties, "Volume")
+                .unwrap_or(1.0)
+                .clamp(0.0, 1.0);
+
+            // Emitters are usually points, which sit anywhere inside of the tile they're on.
+            let grid_x = (object.x / tile_width).floor().max(0.0) as usize;
+            let grid_y = (object.y / tile_height).floor().max(0.0) as usize;
+            let grid_cords = GridCords3D::new(grid_x, grid_y, 0);
+
+            ambient_sounds.push(AmbientSound::new(
+                PathBuf::from(found_file.unwrap()),
+                radius,
+                volume,
+                grid_cords,
+            ));
+        }
+    }
+
+    ambient_sounds
+}
+
+/// Returns the value of a number property of some Tiled object, whether it was typed
+/// as a whole number or not.
+fn get_number_property(properties: &HashMap<String, PropertyValue>, name: &str) -> Option<f32> {
+    match properties.get(name) {
+        Some(PropertyValue::FloatValue(value)) => Some(*value),
+        Some(PropertyValue::IntValue(value)) => Some(*value as f32),
+        _ => None,
+    }
+}
+
+/// Keeps each ambient sound mixed for where the player currently stands.
+pub fn mix_ambient_sounds(
+    players: Query<&GridCords3D, With<Player>>,
+    mut ambient_sounds: Query<(&AmbientSound, &mut AmbientMix)>,
+) {
+    let found_player = players.iter().next();
+    if found_player.is_none() {
+        return;
+    }
+    let player_grid_cords = found_player.unwrap();
+
+    for (ambient_sound, mut ambient_mix) in ambient_sounds.iter_mut() {
+        let mix = ambient_sound.get_mix_at(player_grid_cords);
+
+        // Only mixes that actually changed are passed along to the playing sound.
+        if *ambient_mix != mix {
+            *ambient_mix = mix;
+        }
+    }
+}
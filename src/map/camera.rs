@@ -0,0 +1,507 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    map::{npc::Npc, player::Player, GridCords2D, GridDimensions, PxDimensions},
+    narrative::acts::{Character, MapLocation},
+    ui::get_centered_player_position,
+};
+
+/// The name cutscenes use to pan over to the player rather than to a character on the map.
+pub const PLAYER_CHARACTER_NAME: &str = "Player";
+
+/// How close in pixels a smoothed camera has to get to where it's headed before it settles there.
+const SETTLE_DISTANCE: f32 = 0.5;
+
+/// Moves the camera of a level, either following the player or panning over to somewhere
+/// a cutscene asked it to look, and shakes it when asked to.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct CameraController {
+    /// How quickly the camera catches up with the player, where higher is quicker.
+    /// Without any, the camera snaps straight onto them.
+    smoothing: Option<f32>,
+    /// The size of the area around the camera's center the player can move around in
+    /// without the camera following them.
+    dead_zone: Vec2,
+    mode: CameraMode,
+    /// Where the camera is looking before it's shaken, once it has looked anywhere.
+    focus: Option<Vec2>,
+    shake: Option<CameraShake>,
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CameraController {
+    pub fn new() -> Self {
+        Self {
+            smoothing: None,
+            dead_zone: Vec2::ZERO,
+            mode: CameraMode::Follow,
+            focus: None,
+            shake: None,
+        }
+    }
+
+    /// Returns the controller, catching up with the player at the given speed rather than
+    /// snapping onto them.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+
+    /// Returns the controller, only following the player once they leave an area of the
+    /// given size around the camera's center.
+    pub fn with_dead_zone(mut self, dead_zone: Vec2) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    pub fn get_smoothing(&self) -> Option<f32> {
+        self.smoothing
+    }
+
+    pub fn get_dead_zone(&self) -> Vec2 {
+        self.dead_zone
+    }
+
+    pub fn is_following(&self) -> bool {
+        self.mode == CameraMode::Follow
+    }
+
+    pub fn is_shaking(&self) -> bool {
+        self.shake.is_some()
+    }
+}
+
+/// How the camera of each level loaded follows the player.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct CameraFollow {
+    /// How quickly the camera catches up with the player, where higher is quicker.
+    /// Without any, the camera snaps straight onto them.
+    smoothing: Option<f32>,
+    /// The size of the area around the camera's center the player can move around in
+    /// without the camera following them.
+    dead_zone: Vec2,
+}
+
+impl CameraFollow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the settings, catching up with the player at the given speed rather than
+    /// snapping onto them.
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+
+    /// Returns the settings, only following the player once they leave an area of the
+    /// given size around the camera's center.
+    pub fn with_dead_zone(mut self, dead_zone: Vec2) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    pub fn get_smoothing(&self) -> Option<f32> {
+        self.smoothing
+    }
+
+    pub fn get_dead_zone(&self) -> Vec2 {
+        self.dead_zone
+    }
+
+    /// Returns a camera controller following the player the way these settings describe.
+    pub fn create_controller(&self) -> CameraController {
+        let camera_controller = CameraController::new().with_dead_zone(self.dead_zone);
+
+        match self.smoothing {
+            Some(smoothing) => camera_controller.with_smoothing(smoothing),
+            None => camera_controller,
+        }
+    }
+}
+
+/// Whether the camera is following the player or in the middle of a pan.
+#[derive(Debug, Clone, PartialEq)]
+enum CameraMode {
+    Follow,
+    Pan(CameraPan),
+}
+
+/// A pan the camera is in the middle of.
+#[derive(Debug, Clone, PartialEq)]
+struct CameraPan {
+    from: Vec2,
+    target: PanTarget,
+    travel_timer: Timer,
+    hold_timer: Timer,
+}
+
+/// A shake the camera is in the middle of, easing off as it runs out.
+#[derive(Debug, Clone, PartialEq)]
+struct CameraShake {
+    intensity: f32,
+    timer: Timer,
+}
+
+/// Somewhere the camera can pan over to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanTarget {
+    /// The center of the tile at some grid coordinates.
+    Tile(GridCords2D),
+    /// Some character on the map by name, following them if they move.
+    /// The player is found by the name "Player".
+    Character(String),
+}
+
+/// Asks the camera to pan over to something, hold there for a while, then go back to
+/// following the player.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct PanCamera {
+    target: PanTarget,
+    duration: Duration,
+    hold: Duration,
+}
+
+impl PanCamera {
+    pub fn new(target: PanTarget, duration: Duration) -> Self {
+        Self {
+            target,
+            duration,
+            hold: Duration::ZERO,
+        }
+    }
+
+    /// Returns a pan over to some location of a map cutscene.
+    pub fn to_location(location: &MapLocation, duration: Duration) -> Self {
+        Self::new(PanTarget::Tile(location.get_cords().clone()), duration)
+    }
+
+    /// Returns a pan over to some character of a map cutscene.
+    pub fn to_character(character: &Character, duration: Duration) -> Self {
+        Self::new(PanTarget::Character(character.get_name().clone()), duration)
+    }
+
+    /// Returns the pan, staying on its target for the given amount of time before it ends.
+    pub fn with_hold(mut self, hold: Duration) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    pub fn get_target(&self) -> &PanTarget {
+        &self.target
+    }
+
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn get_hold(&self) -> Duration {
+        self.hold
+    }
+}
+
+/// Sent once a pan has ended and the camera has gone back to following the player.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct CameraPanFinished {
+    target: PanTarget,
+}
+
+impl CameraPanFinished {
+    pub fn new(target: PanTarget) -> Self {
+        Self { target }
+    }
+
+    pub fn get_target(&self) -> &PanTarget {
+        &self.target
+    }
+}
+
+/// Asks the camera to shake by up to some number of pixels, easing off over some duration.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ShakeCamera {
+    intensity: f32,
+    duration: Duration,
+}
+
+impl ShakeCamera {
+    pub fn new(intensity: f32, duration: Duration) -> Self {
+        Self {
+            intensity,
+            duration,
+        }
+    }
+
+    pub fn get_intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Starts a pan of each camera whenever one is requested, taking over from any pan it's
+/// already in the middle of.
+pub fn start_camera_pans(
+    mut pan_requests: MessageReader<PanCamera>,
+    mut cameras: Query<(&mut CameraController, &Transform)>,
+) {
+    for pan_request in pan_requests.read() {
+        for (mut camera_controller, camera_transform) in &mut cameras {
+            // A camera that hasn't looked anywhere yet starts its pan wherever it is.
+            let from = camera_controller
+                .focus
+                .unwrap_or(camera_transform.translation.truncate());
+
+            camera_controller.mode = CameraMode::Pan(CameraPan {
+                from,
+                target: pan_request.target.clone(),
+                travel_timer: Timer::new(pan_request.duration, TimerMode::Once),
+                hold_timer: Timer::new(pan_request.hold, TimerMode::Once),
+            });
+        }
+    }
+}
+
+/// Starts shaking each camera whenever a shake is requested, keeping whichever shake is stronger.
+pub fn start_camera_shakes(
+    mut shake_requests: MessageReader<ShakeCamera>,
+    mut cameras: Query<&mut CameraController>,
+) {
+    for shake_request in shake_requests.read() {
+        for mut camera_controller in &mut cameras {
+            let is_stronger = camera_controller
+                .shake
+                .as_ref()
+                .is_none_or(|shake| shake.intensity <= shake_request.intensity);
+
+            if is_stronger {
+                camera_controller.shake = Some(CameraShake {
+                    intensity: shake_request.intensity,
+                    timer: Timer::new(shake_request.duration, TimerMode::Once),
+                });
+            }
+        }
+    }
+}
+
+/// Moves each camera towards where it's looking, whether that's the player or the target of
+/// some pan, without showing past the edges of the level, then shakes it if it's shaking.
+pub fn move_camera(
+    time: Res<Time>,
+    level_query: Query<(&PxDimensions, &GridDimensions)>,
+    player_query: Query<(&Transform, &PxDimensions), With<Player>>,
+    npc_query: Query<(&Npc, &Transform, &PxDimensions)>,
+    mut camera_query: Query<
+        (&mut CameraController, &mut Transform, &Projection),
+        (With<Camera2d>, Without<Player>, Without<Npc>),
+    >,
+    mut pans_finished: MessageWriter<CameraPanFinished>,
+) {
+    //Check for empties
+    if player_query.is_empty() {
+        return;
+    }
+    if level_query.is_empty() {
+        return;
+    }
+
+    //Start unpacking
+    let (player_transform, player_tile_dimensions) = player_query
+        .single()
+        .expect("move_camera: could not find player");
+    let (level_dimensions, level_grid) = level_query.single().unwrap();
+
+    let player_center = get_centered_player_position(player_transform, player_tile_dimensions)
+        .translation
+        .truncate();
+
+    for (mut camera_controller, mut camera_transform, camera_bounds) in &mut camera_query {
+        let area = if let Projection::Orthographic(orthographic) = camera_bounds {
+            orthographic.area
+        } else {
+            panic!("Camera bounds is not an orthographic projection")
+        };
+
+        let camera_controller = &mut *camera_controller;
+        let focus = camera_controller.focus.unwrap_or(player_center);
+
+        let new_focus = match &mut camera_controller.mode {
+            CameraMode::Follow => {
+                let followed_position =
+                    follow_within_dead_zone(focus, player_center, camera_controller.dead_zone);
+                let target = clamp_to_level(followed_position, area, level_dimensions, level_grid);
+
+                match (camera_controller.focus, camera_controller.smoothing) {
+                    (Some(focus), Some(smoothing)) => {
+                        smooth_towards(focus, target, smoothing, time.delta_secs())
+                    }
+                    _ => target,
+                }
+            }
+            CameraMode::Pan(camera_pan) => {
+                let found_target_position = match &camera_pan.target {
+                    PanTarget::Tile(grid_cords) => {
+                        Some(get_tile_center(grid_cords, level_dimensions, level_grid))
+                    }
+                    PanTarget::Character(name) if name == PLAYER_CHARACTER_NAME => {
+                        Some(player_center)
+                    }
+                    PanTarget::Character(name) => npc_query
+                        .iter()
+                        .find(|(npc, _, _)| npc.get_name() == name)
+                        .map(|(_, npc_transform, npc_tile_dimensions)| {
+                            get_centered_player_position(npc_transform, npc_tile_dimensions)
+                                .translation
+                                .truncate()
+                        }),
+                };
+
+                let Some(target_position) = found_target_position else {
+                    warn!(
+                        "move_camera: The camera can't pan to {:?}, as it isn't on the map.",
+                        camera_pan.target
+                    );
+                    pans_finished.write(CameraPanFinished::new(camera_pan.target.clone()));
+                    camera_controller.mode = CameraMode::Follow;
+                    continue;
+                };
+                let target = clamp_to_level(target_position, area, level_dimensions, level_grid);
+
+                if camera_pan.travel_timer.is_finished() {
+                    camera_pan.hold_timer.tick(time.delta());
+                } else {
+                    camera_pan.travel_timer.tick(time.delta());
+                }
+
+                let traveled = camera_pan.travel_timer.fraction();
+                // Eases in and out, so the pan doesn't lurch into or out of its movement.
+                let eased_traveled = traveled * traveled * (3.0 - 2.0 * traveled);
+                let new_focus = camera_pan.from.lerp(target, eased_traveled);
+
+                if camera_pan.travel_timer.is_finished() && camera_pan.hold_timer.is_finished() {
+                    pans_finished.write(CameraPanFinished::new(camera_pan.target.clone()));
+                    camera_controller.mode = CameraMode::Follow;
+                }
+
+                new_focus
+            }
+        };
+
+        camera_controller.focus = Some(new_focus);
+
+        let shake_offset = match &mut camera_controller.shake {
+            Some(shake) => {
+                shake.timer.tick(time.delta());
+
+                let strength = shake.intensity * shake.timer.fraction_remaining();
+                Vec2::new(fastrand::f32() * 2.0 - 1.0, fastrand::f32() * 2.0 - 1.0) * strength
+            }
+            None => Vec2::ZERO,
+        };
+
+        if camera_controller
+            .shake
+            .as_ref()
+            .is_some_and(|shake| shake.timer.is_finished())
+        {
+            camera_controller.shake = None;
+        }
+
+        let new_translation = new_focus + shake_offset;
+        if camera_transform.translation.truncate() != new_translation {
+            camera_transform.translation.x = new_translation.x;
+            camera_transform.translation.y = new_translation.y;
+        }
+    }
+}
+
+/// Returns where the camera should look to keep some position within the dead zone around its focus.
+fn follow_within_dead_zone(focus: Vec2, position: Vec2, dead_zone: Vec2) -> Vec2 {
+    let half_dead_zone = dead_zone / 2.0;
+
+    focus.clamp(position - half_dead_zone, position + half_dead_zone)
+}
+
+/// Returns a position some of the way towards a target, settling on it once close enough.
+fn smooth_towards(position: Vec2, target: Vec2, smoothing: f32, delta_secs: f32) -> Vec2 {
+    // The same share of the distance is covered each second however fast the game runs.
+    let smoothed_position = position.lerp(target, 1.0 - (-smoothing * delta_secs).exp());
+
+    if smoothed_position.distance(target) < SETTLE_DISTANCE {
+        return target;
+    }
+
+    smoothed_position
+}
+
+/// Returns the pixel coordinates the camera looks at to center some tile.
+pub fn get_tile_center(
+    grid_cords: &GridCords2D,
+    level_dimensions: &PxDimensions,
+    level_grid: &GridDimensions,
+) -> Vec2 {
+    let tile_width = level_dimensions.get_width() as f32 / level_grid.get_columns() as f32;
+    let tile_height = level_dimensions.get_height() as f32 / level_grid.get_rows() as f32;
+
+    // Y-axis flip, because Bevy is Y-Up while the grid is Y-Down
+    let flipped_row = level_grid.get_rows() as f32 - 1.0 - grid_cords.get_y() as f32;
+
+    // Tiles are drawn centered on their grid position, so no offset is needed.
+    Vec2::new(
+        grid_cords.get_x() as f32 * tile_width,
+        flipped_row * tile_height,
+    )
+}
+
+/// Returns the closest position to some position a camera seeing the given area can look at
+/// without showing past the edges of the level, or the center of the level along whichever
+/// direction the camera sees all of it.
+pub fn clamp_to_level(
+    position: Vec2,
+    area: Rect,
+    level_dimensions: &PxDimensions,
+    level_grid: &GridDimensions,
+) -> Vec2 {
+    let camera_width = area.width();
+    let camera_height = area.height();
+
+    let level_height = level_dimensions.get_height() as f32;
+    let level_width = level_dimensions.get_width() as f32;
+    if level_height == 0.0 || level_width == 0.0 {
+        return position;
+    }
+    let level_center_x = level_width / 2.0;
+    let level_center_y = level_height / 2.0;
+
+    let tile_x_offset = (level_width / level_grid.get_columns() as f32) / 2.0;
+    let tile_y_offset = (level_height / level_grid.get_rows() as f32) / 2.0;
+
+    //Bounding limits
+    let camera_min_x = (camera_width / 2.0) - tile_x_offset;
+    let camera_max_x = (level_width - (camera_width / 2.0)) - tile_x_offset;
+    let camera_min_y = (camera_height / 2.0) - tile_y_offset;
+    let camera_max_y = (level_height - (camera_height / 2.0)) - tile_y_offset;
+
+    //Logic
+    let x = if camera_width > level_width {
+        level_center_x - tile_x_offset
+    } else {
+        position.x.clamp(camera_min_x, camera_max_x)
+    };
+
+    let y = if camera_height > level_height {
+        level_center_y - tile_y_offset
+    } else {
+        position.y.clamp(camera_min_y, camera_max_y)
+    };
+
+    Vec2::new(x, y)
+}
@@ -3,30 +3,82 @@ use std::{collections::HashMap, path::PathBuf};
 use bevy::prelude::*;
 use tiled::{Map, ObjectShape, PropertyValue};
 
-use crate::map::{
-    flip_y_axis, is_object_layer, player::PlayerInteraction, GridDimensions, PxCords, PxDimensions,
+use crate::{
+    map::{
+        flip_y_axis, is_object_layer,
+        movement::grid_based_movement::{set_physical_destination, MovementDirection},
+        player::{Player, PlayerInteraction},
+        GridCords3D, GridDimensions, PxCords, PxDimensions,
+    },
+    narrative::story_flags::{FlagConditions, SetStoryFlag, StoryFlags},
+    ui::text_box::{ShowTextBox, TextBox},
 };
 
+use super::{
+    level_transitions::LevelTransitionEffect,
+    map_changing::transform_to_xyzcord,
+    triggers::{create_trigger, Trigger},
+};
+
+/// All interactive markers of a map, indexed by a uniform grid of cells the size of the
+/// map's tiles, so that every marker covering a point can be found without looking
+/// through all of them.
 #[derive(Component, Debug, Clone, Default)]
 pub struct InteractiveCollection {
     interactive_markers: Vec<InteractiveMarker>,
+    cell_dimensions: PxDimensions,
+    marker_cells: HashMap<(usize, usize), Vec<usize>>,
 }
 
 impl InteractiveCollection {
     pub fn new() -> Self {
-        let interactive_markers = Vec::new();
-
-        Self {
-            interactive_markers,
-        }
+        Self::default()
     }
 
-    pub fn from_markers(mut interactive_markers: Vec<InteractiveMarker>) -> Self {
-        interactive_markers.sort();
-
-        Self {
+    /// Returns a collection of some markers, indexed by cells of the given size,
+    /// such as the size of the tiles of their map.
+    pub fn from_markers(
+        interactive_markers: Vec<InteractiveMarker>,
+        cell_dimensions: PxDimensions,
+    ) -> Self {
+        let mut interactive_collection = Self {
             interactive_markers,
+            cell_dimensions,
+            marker_cells: HashMap::new(),
+        };
+
+        for (index, marker) in interactive_collection
+            .interactive_markers
+            .iter()
+            .enumerate()
+        {
+            let position = marker.get_position();
+            let dimensions = marker.get_dimensions();
+
+            // Markers without an area can't contain anything.
+            if dimensions.get_width() == 0 || dimensions.get_height() == 0 {
+                continue;
+            }
+
+            let (first_column, first_row) = interactive_collection.get_cell(&position);
+            let (last_column, last_row) = interactive_collection.get_cell(&PxCords::new(
+                position.get_x() + dimensions.get_width() - 1,
+                position.get_y() + dimensions.get_height() - 1,
+                position.get_z(),
+            ));
+
+            for column in first_column..=last_column {
+                for row in first_row..=last_row {
+                    interactive_collection
+                        .marker_cells
+                        .entry((column, row))
+                        .or_default()
+                        .push(index);
+                }
+            }
         }
+
+        interactive_collection
     }
 
     pub fn len(&self) -> usize {
@@ -37,39 +89,47 @@ impl InteractiveCollection {
         &self.interactive_markers[index]
     }
 
-    pub fn get_marker_from_position(&self, position: &PxCords) -> Option<&InteractiveMarker> {
-        if self.len() == 0 {
-            return None;
-        }
-
-        let mut left = 0;
-        let mut right = self.len() - 1;
+    /// Returns the column and row of the cell some position falls into.
+    fn get_cell(&self, position: &PxCords) -> (usize, usize) {
+        let cell_width = self.cell_dimensions.get_width().max(1);
+        let cell_height = self.cell_dimensions.get_height().max(1);
 
-        while left <= right {
-            let mid = (left + right) / 2;
-            let marker = self.get_marker_at_index(mid);
-            let comparison = marker.containing(position);
+        (
+            position.get_x() / cell_width,
+            position.get_y() / cell_height,
+        )
+    }
 
-            if comparison == Proximity::Higher {
-                left = mid + 1;
-            } else if comparison == Proximity::Lower {
-                if mid == 0 {
-                    break;
-                }
-                right = mid - 1;
-            } else {
-                return Some(marker);
-            }
+    /// Returns every marker containing some position on the same layer as it,
+    /// in the order they were found on the map.
+    pub fn get_markers_containing(&self, position: &PxCords) -> Vec<&InteractiveMarker> {
+        let found_marker_indices = self.marker_cells.get(&self.get_cell(position));
+        if found_marker_indices.is_none() {
+            return Vec::new();
         }
-        None
+
+        found_marker_indices
+            .unwrap()
+            .iter()
+            .map(|index| self.get_marker_at_index(*index))
+            .filter(|marker| marker.get_position().get_z() == position.get_z())
+            .filter(|marker| marker.contains(position))
+            .collect()
+    }
+
+    /// Returns a marker containing some position on the same layer as it,
+    /// or None if no marker contains it.
+    pub fn get_marker_from_position(&self, position: &PxCords) -> Option<&InteractiveMarker> {
+        self.get_markers_containing(position).into_iter().next()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct InteractiveMarker {
     position: PxCords,
     dimensions: PxDimensions,
     interaction_type: InteractiveType,
+    flag_conditions: FlagConditions,
 }
 
 impl InteractiveMarker {
@@ -82,33 +142,24 @@ impl InteractiveMarker {
             position,
             dimensions,
             interaction_type,
+            flag_conditions: FlagConditions::default(),
         }
     }
 
-    pub fn containing(&self, position: &PxCords) -> Proximity {
-        let marker_min_x = self.position.get_x();
-        let marker_max_x = marker_min_x + (self.dimensions.get_width());
-        let marker_x_range = marker_min_x..marker_max_x;
-        let position_x = position.get_x();
-
-        let marker_min_y = self.position.get_y();
-        let marker_max_y = marker_min_y + (self.dimensions.get_height());
-        let marker_y_range = marker_min_y..marker_max_y;
-        let position_y = position.get_y();
+    /// Returns the marker, only usable when the story meets its flag conditions.
+    pub fn with_flag_conditions(mut self, flag_conditions: FlagConditions) -> Self {
+        self.flag_conditions = flag_conditions;
+        self
+    }
 
-        if marker_x_range.contains(&position_x) && marker_y_range.contains(&position_y) {
-            return Proximity::Match;
-        }
+    /// Returns whether some position lies within the marker, ignoring layers.
+    pub fn contains(&self, position: &PxCords) -> bool {
+        let marker_x_range =
+            self.position.get_x()..self.position.get_x() + self.dimensions.get_width();
+        let marker_y_range =
+            self.position.get_y()..self.position.get_y() + self.dimensions.get_height();
 
-        if position_x < marker_min_x {
-            Proximity::Lower
-        } else if position_x >= marker_max_x {
-            Proximity::Higher
-        } else if position_y < marker_min_y {
-            Proximity::Lower
-        } else {
-            Proximity::Higher
-        }
+        marker_x_range.contains(&position.get_x()) && marker_y_range.contains(&position.get_y())
     }
 
     pub fn get_position(&self) -> PxCords {
@@ -123,37 +174,55 @@ impl InteractiveMarker {
         self.interaction_type.clone()
     }
 
+    pub fn get_flag_conditions(&self) -> &FlagConditions {
+        &self.flag_conditions
+    }
+
     pub fn get_type_name(&self) -> String {
         self.interaction_type.type_name()
     }
 
-    pub fn get_path(&self) -> PathBuf {
-        self.interaction_type.type_value()
+    /// Returns the destination of a Transition marker, or None for any other type of marker.
+    pub fn get_path(&self) -> Option<PathBuf> {
+        match &self.interaction_type {
+            InteractiveType::Transition(path, _) => Some(path.clone()),
+            _ => None,
+        }
     }
-}
 
-#[derive(Component, Clone, Copy, Debug, PartialEq)]
-pub enum Proximity {
-    Lower,
-    Higher,
-    Match,
+    /// Returns the text of an Inspect marker, or None for any other type of marker.
+    pub fn get_text(&self) -> Option<String> {
+        match &self.interaction_type {
+            InteractiveType::Inspect(text) => Some(text.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the trigger of a Trigger marker, or None for any other type of marker.
+    pub fn get_trigger(&self) -> Option<&Trigger> {
+        match &self.interaction_type {
+            InteractiveType::Trigger(trigger) => Some(trigger),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InteractiveType {
-    Transition(PathBuf),
+    /// Changes the level to the map at the given path behind the given transition effect.
+    Transition(PathBuf, LevelTransitionEffect),
+    /// Shows the given text, such as what's written on a sign.
+    Inspect(String),
+    /// Fires as soon as the player steps into or out of the marker.
+    Trigger(Trigger),
 }
 
 impl InteractiveType {
     fn type_name(&self) -> String {
         match self {
-            InteractiveType::Transition(_) => "Transition".to_string(),
-        }
-    }
-
-    fn type_value(&self) -> PathBuf {
-        match self {
-            InteractiveType::Transition(value) => value.clone(),
+            InteractiveType::Transition(..) => "Transition".to_string(),
+            InteractiveType::Inspect(_) => "Inspect".to_string(),
+            InteractiveType::Trigger(_) => "Trigger".to_string(),
         }
     }
 }
@@ -175,24 +244,27 @@ pub fn get_interactives_from(tiled_map: &Map) -> Vec<InteractiveMarker> {
         let object_layer = tiled_map.get_layer(z).unwrap().as_object_layer().unwrap();
 
         for object in object_layer.objects() {
-            if object.user_type == "Transition" {
-                let position = PxCords::new(object.x as usize, object.y as usize, z);
+            let position = PxCords::new(object.x as usize, object.y as usize, z);
 
-                // Get properties and create interactive type from it
-                let properties = &object.properties;
-                let interactive_type = create_interactive_type(properties);
+            // Get properties and create interactive type from it
+            let properties = &object.properties;
+            let found_interactive_type = create_interactive_type(&object.user_type, properties);
+            if found_interactive_type.is_none() {
+                continue;
+            }
+            let interactive_type = found_interactive_type.unwrap();
 
-                // Get shape, check it's a Rect, get width and height
-                if let ObjectShape::Rect { width, height } = object.shape {
-                    let object_width = width as u32;
-                    let object_height = height as u32;
+            // Get shape, check it's a Rect, get width and height
+            if let ObjectShape::Rect { width, height } = object.shape {
+                let object_width = width as u32;
+                let object_height = height as u32;
 
-                    let dimensions = PxDimensions::new(object_width, object_height);
+                let dimensions = PxDimensions::new(object_width, object_height);
 
-                    let interactive_marker =
-                        InteractiveMarker::new(position, dimensions, interactive_type);
-                    interactive_markers.push(interactive_marker);
-                }
+                let interactive_marker =
+                    InteractiveMarker::new(position, dimensions, interactive_type)
+                        .with_flag_conditions(FlagConditions::from_properties(properties));
+                interactive_markers.push(interactive_marker);
             }
         }
     }
@@ -200,13 +272,38 @@ pub fn get_interactives_from(tiled_map: &Map) -> Vec<InteractiveMarker> {
     interactive_markers
 }
 
-pub fn create_interactive_type(properties: &HashMap<String, PropertyValue>) -> InteractiveType {
-    let property_value = properties.get("Destination");
-    // We assume that there is only one destination property on a marker
-    if let Some(PropertyValue::StringValue(property_string)) = property_value {
-        InteractiveType::Transition(PathBuf::from(property_string))
-    } else {
-        panic!("create_interactive_type: Marker Type is invalid")
+/// Returns the interactive type of a Tiled object with the given type and properties,
+/// or None if the object isn't an interactive marker or is missing the properties it needs.
+pub fn create_interactive_type(
+    object_type: &str,
+    properties: &HashMap<String, PropertyValue>,
+) -> Option<InteractiveType> {
+    match object_type {
+        "Transition" => {
+            // We assume that there is only one destination property on a marker
+            if let Some(PropertyValue::StringValue(destination)) = properties.get("Destination") {
+                return Some(InteractiveType::Transition(
+                    PathBuf::from(destination),
+                    LevelTransitionEffect::from_properties(properties),
+                ));
+            }
+
+            warn!("create_interactive_type: Transition marker has no Destination property.");
+            None
+        }
+        "Inspect" | "Sign" => {
+            if let Some(PropertyValue::StringValue(text)) = properties.get("Text") {
+                return Some(InteractiveType::Inspect(text.clone()));
+            }
+
+            warn!(
+                "create_interactive_type: {} marker has no Text property.",
+                object_type
+            );
+            None
+        }
+        "Trigger" => create_trigger(properties).map(InteractiveType::Trigger),
+        _ => None,
     }
 }
 
@@ -232,7 +329,8 @@ pub fn flip_interactives_on_y_axis(
             flipped_ycord,
             marker.get_dimensions(),
             marker.get_interactive_type(),
-        );
+        )
+        .with_flag_conditions(marker.get_flag_conditions().clone());
 
         y_flipped_markers.push(flipped_marker);
     }
@@ -240,13 +338,110 @@ pub fn flip_interactives_on_y_axis(
     y_flipped_markers
 }
 
+/// Returns the marker covering the tile at some grid coordinate, if there is one.
+pub fn get_marker_at_tile<'a>(
+    marker_collection: &'a InteractiveCollection,
+    grid_cords: &GridCords3D,
+    map_dimensions_in_px: &PxDimensions,
+    map_grid_dimensions: &GridDimensions,
+) -> Option<&'a InteractiveMarker> {
+    let tile_width = map_dimensions_in_px.get_width() / map_grid_dimensions.get_columns() as usize;
+    let tile_height = map_dimensions_in_px.get_height() / map_grid_dimensions.get_rows() as usize;
+    // Y-axis flip, since markers use physical coordinates while the grid is Y-Down
+    let flipped_row = map_grid_dimensions.get_rows() as usize - 1 - grid_cords.get_y();
+
+    let tile_position = PxCords::new(
+        grid_cords.get_x() * tile_width,
+        flipped_row * tile_height,
+        grid_cords.get_z(),
+    );
+
+    marker_collection.get_marker_from_position(&tile_position)
+}
+
+/// Returns the marker found on the tile in front of some position, if there is one.
+pub fn find_marker_ahead<'a>(
+    marker_collection: &'a InteractiveCollection,
+    current_position: &Transform,
+    tile_dimensions: &PxDimensions,
+    map_dimensions_in_px: &PxDimensions,
+    direction: &MovementDirection,
+) -> Option<&'a InteractiveMarker> {
+    let inspected_point = set_physical_destination(
+        current_position,
+        tile_dimensions,
+        map_dimensions_in_px,
+        direction,
+    )?;
+    let inspected_cords = transform_to_xyzcord(inspected_point);
+
+    marker_collection.get_marker_from_position(&inspected_cords)
+}
+
+/// Shows the text of an Inspect marker if there's one present in front of the player.
+pub fn inspect_marker(
+    mut requests_to_interact: MessageReader<PlayerInteraction>,
+    player: Query<(&Transform, &PxDimensions, &MovementDirection), With<Player>>,
+    map_markers: Query<(&InteractiveCollection, &PxDimensions)>,
+    story_flags: Res<StoryFlags>,
+    mut text_box_requests: MessageWriter<ShowTextBox>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+) {
+    if player.is_empty() {
+        return;
+    }
+
+    if requests_to_interact.is_empty() {
+        return;
+    }
+
+    if map_markers.is_empty() {
+        return;
+    }
+
+    let (current_player_position, player_dimensions, player_direction) = player.single().unwrap();
+    let (marker_collection, map_dimensions_in_px) = map_markers.single().unwrap();
+
+    for _ in requests_to_interact.read() {
+        let found_marker = find_marker_ahead(
+            marker_collection,
+            current_player_position,
+            player_dimensions,
+            map_dimensions_in_px,
+            player_direction,
+        );
+
+        if found_marker.is_none() {
+            continue;
+        }
+
+        let marker = found_marker.unwrap();
+        if !marker.get_flag_conditions().is_met_by(&story_flags) {
+            continue;
+        }
+
+        if let InteractiveType::Inspect(text) = marker.get_interactive_type() {
+            text_box_requests.write(ShowTextBox::new(&text));
+            marker
+                .get_flag_conditions()
+                .set_flag_with(&mut set_story_flag_requests);
+        }
+    }
+}
+
 pub fn interact_entity(
     input: Res<ButtonInput<KeyCode>>,
+    text_boxes: Query<(), With<TextBox>>,
     mut interactive_event_writer: MessageWriter<PlayerInteraction>,
 ) {
     if !input.just_pressed(KeyCode::KeyE) {
         return;
     }
 
+    // The same key closes text boxes, which shouldn't also interact with what's ahead.
+    if !text_boxes.is_empty() {
+        return;
+    }
+
     interactive_event_writer.write(PlayerInteraction);
 }
@@ -0,0 +1,288 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use bevy::prelude::*;
+use tiled::PropertyValue;
+
+use crate::map::get_string_property;
+
+const TRANSITION_COLOR: Color = Color::srgb(0.0, 0.0, 0.0);
+
+/// How the screen changes over from one level to the next.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LevelTransitionEffect {
+    /// The next level shows up right away.
+    #[default]
+    Cut,
+    /// The screen fades to black, then fades back in on the next level.
+    Fade,
+    /// The screen fades to black, then cuts to the next level.
+    FadeToBlack,
+    /// The screen cuts to black, then fades in on the next level.
+    FadeFromBlack,
+    /// The screen is wiped over in black towards some direction, then wiped clean the same way.
+    Wipe(WipeDirection),
+}
+
+/// The direction a wipe travels across the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl LevelTransitionEffect {
+    /// Returns an effect from its name in Tiled, such as "Fade" or "WipeLeft".
+    pub fn parse_from(effect_name: &str) -> Option<Self> {
+        match effect_name.trim() {
+            "Cut" => Some(LevelTransitionEffect::Cut),
+            "Fade" => Some(LevelTransitionEffect::Fade),
+            "FadeToBlack" => Some(LevelTransitionEffect::FadeToBlack),
+            "FadeFromBlack" => Some(LevelTransitionEffect::FadeFromBlack),
+            "WipeLeft" => Some(LevelTransitionEffect::Wipe(WipeDirection::Left)),
+            "WipeRight" => Some(LevelTransitionEffect::Wipe(WipeDirection::Right)),
+            "WipeUp" => Some(LevelTransitionEffect::Wipe(WipeDirection::Up)),
+            "WipeDown" => Some(LevelTransitionEffect::Wipe(WipeDirection::Down)),
+            _ => None,
+        }
+    }
+
+    /// Returns the effect set by the Transition property of a Tiled object, or a cut without one.
+    pub fn from_properties(properties: &HashMap<String, PropertyValue>) -> Self {
+        let found_effect_name = get_string_property(properties, "Transition");
+        if found_effect_name.is_none() {
+            return LevelTransitionEffect::Cut;
+        }
+
+        let effect_name = found_effect_name.unwrap();
+        LevelTransitionEffect::parse_from(&effect_name).unwrap_or_else(|| {
+            warn!(
+                "from_properties: Unknown level transition '{}', cutting instead.",
+                effect_name
+            );
+            LevelTransitionEffect::Cut
+        })
+    }
+
+    /// Returns whether the screen is covered over time, rather than all at once.
+    fn covers_gradually(&self) -> bool {
+        !matches!(
+            self,
+            LevelTransitionEffect::Cut | LevelTransitionEffect::FadeFromBlack
+        )
+    }
+
+    /// Returns whether the next level is revealed over time, rather than all at once.
+    fn uncovers_gradually(&self) -> bool {
+        !matches!(
+            self,
+            LevelTransitionEffect::Cut | LevelTransitionEffect::FadeToBlack
+        )
+    }
+}
+
+/// How long each half of a level transition takes, covering the screen and uncovering it.
+#[derive(Resource, Clone, Copy)]
+pub struct LevelTransitionDuration {
+    duration: Duration,
+}
+
+impl LevelTransitionDuration {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl Default for LevelTransitionDuration {
+    fn default() -> Self {
+        Self::new(Duration::from_secs_f32(0.5))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionPhase {
+    /// The screen is being covered up before the level is swapped out.
+    Covering,
+    /// The next level is being revealed.
+    Uncovering,
+}
+
+/// The overlay covering the screen while the level changes.
+#[derive(Component, Debug, Clone)]
+pub struct LevelTransition {
+    effect: LevelTransitionEffect,
+    phase: TransitionPhase,
+    timer: Timer,
+    phase_duration: Duration,
+    level_path: PathBuf,
+}
+
+impl LevelTransition {
+    pub fn new(
+        effect: LevelTransitionEffect,
+        level_path: PathBuf,
+        transition_duration: &LevelTransitionDuration,
+    ) -> Self {
+        let phase_duration = transition_duration.get_duration();
+        let covering_duration = if effect.covers_gradually() {
+            phase_duration
+        } else {
+            Duration::ZERO
+        };
+
+        Self {
+            effect,
+            phase: TransitionPhase::Covering,
+            timer: Timer::new(covering_duration, TimerMode::Once),
+            phase_duration,
+            level_path,
+        }
+    }
+
+    pub fn get_effect(&self) -> LevelTransitionEffect {
+        self.effect
+    }
+
+    pub fn get_phase(&self) -> TransitionPhase {
+        self.phase
+    }
+
+    pub fn get_level_path(&self) -> &PathBuf {
+        &self.level_path
+    }
+
+    /// Returns how much of the screen is covered, from 0 to 1.
+    pub fn get_coverage(&self) -> f32 {
+        let phase_progress = if self.timer.duration().is_zero() {
+            1.0
+        } else {
+            self.timer.fraction()
+        };
+
+        match self.phase {
+            TransitionPhase::Covering => phase_progress,
+            TransitionPhase::Uncovering => 1.0 - phase_progress,
+        }
+    }
+
+    /// Moves on to revealing the next level once the screen is covered.
+    fn start_uncovering(&mut self) {
+        let uncovering_duration = if self.effect.uncovers_gradually() {
+            self.phase_duration
+        } else {
+            Duration::ZERO
+        };
+
+        self.phase = TransitionPhase::Uncovering;
+        self.timer = Timer::new(uncovering_duration, TimerMode::Once);
+    }
+
+    /// Returns the overlay as it should look at this point of the transition.
+    fn get_overlay(&self) -> (Node, BackgroundColor) {
+        let mut overlay = Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        };
+        let mut color = TRANSITION_COLOR;
+
+        match self.effect {
+            LevelTransitionEffect::Wipe(wipe_direction) => {
+                // Wipes come in from one side and leave through the other, so the overlay
+                // is offset behind the direction it travels while covering, then ahead of it.
+                let offset = match self.phase {
+                    TransitionPhase::Covering => self.get_coverage() - 1.0,
+                    TransitionPhase::Uncovering => 1.0 - self.get_coverage(),
+                } * 100.0;
+
+                match wipe_direction {
+                    WipeDirection::Right => overlay.left = Val::Percent(offset),
+                    WipeDirection::Left => overlay.left = Val::Percent(-offset),
+                    WipeDirection::Down => overlay.top = Val::Percent(offset),
+                    WipeDirection::Up => overlay.top = Val::Percent(-offset),
+                }
+            }
+            _ => color.set_alpha(self.get_coverage()),
+        }
+
+        (overlay, BackgroundColor(color))
+    }
+}
+
+/// Sent once a level transition has covered the screen, so the level can be swapped unseen.
+#[derive(Message, Debug, Clone)]
+pub struct ScreenCovered {
+    level_path: PathBuf,
+}
+
+impl ScreenCovered {
+    pub fn new(level_path: PathBuf) -> Self {
+        Self { level_path }
+    }
+
+    pub fn get_level_path(&self) -> &PathBuf {
+        &self.level_path
+    }
+}
+
+/// Starts covering the screen before changing to the level at some path.
+pub fn spawn_level_transition(commands: &mut Commands, level_transition: LevelTransition) {
+    let (overlay, color) = level_transition.get_overlay();
+
+    commands.spawn((overlay, color, ZIndex(30), level_transition));
+}
+
+/// Plays running level transitions, letting the level be swapped once the screen is covered
+/// and removing the overlay once the next level is revealed.
+pub fn advance_level_transitions(
+    mut level_transitions: Query<(
+        Entity,
+        &mut LevelTransition,
+        &mut Node,
+        &mut BackgroundColor,
+    )>,
+    time: Res<Time>,
+    mut screen_covered_broadcaster: MessageWriter<ScreenCovered>,
+    mut commands: Commands,
+) {
+    for (entity, mut level_transition, mut overlay, mut color) in &mut level_transitions {
+        level_transition.timer.tick(time.delta());
+
+        (*overlay, *color) = level_transition.get_overlay();
+
+        if !level_transition.timer.is_finished() {
+            continue;
+        }
+
+        match level_transition.get_phase() {
+            TransitionPhase::Covering => {
+                screen_covered_broadcaster.write(ScreenCovered::new(
+                    level_transition.get_level_path().clone(),
+                ));
+                level_transition.start_uncovering();
+            }
+            TransitionPhase::Uncovering => commands.entity(entity).despawn(),
+        }
+    }
+}
+
+/// Returns whether no level transition is running, for systems that should hold still while one is.
+pub fn no_level_transition_running(level_transitions: Query<(), With<LevelTransition>>) -> bool {
+    level_transitions.is_empty()
+}
+
+/// Removes any level transition still running, such as when leaving the scene.
+pub fn remove_level_transitions(
+    mut commands: Commands,
+    level_transitions: Query<Entity, With<LevelTransition>>,
+) {
+    for entity in &level_transitions {
+        commands.entity(entity).despawn();
+    }
+}
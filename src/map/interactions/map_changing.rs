@@ -4,17 +4,37 @@ use bevy::prelude::*;
 use tiled::{Loader, Map};
 
 use crate::map::{
+    ambient_sounds::{get_ambient_sounds_from, AmbientMix, AmbientSound},
+    camera::CameraFollow,
+    items::{get_items_from, Inventory, ItemPickup, PickedUpItems},
+    materials::get_materials_from,
     movement::{
         collision::{create_collision_collection_from, CollisionCollection},
-        grid_based_movement::{set_physical_destination, MovementDirection},
+        grid_based_movement::MovementDirection,
+        npc_movement::{IdleTime, IdleTimer, NpcBehavior},
     },
+    npc::get_npcs_from,
     player::*,
     render::RenderedMap,
     GridCords3D, GridDimensions, PxCords, PxDimensions, TileType, Tilemap,
 };
 
-use super::interactives::{
-    flip_interactives_on_y_axis, get_interactives_from, InteractiveCollection,
+use crate::narrative::story_flags::{SetStoryFlag, StoryFlags};
+use crate::{
+    audio::music::get_level_music,
+    loading::{hold_back_until_loaded, PendingLoad, PendingLoads},
+    AppState,
+};
+
+use super::{
+    interactives::{
+        find_marker_ahead, flip_interactives_on_y_axis, get_interactives_from,
+        InteractiveCollection, InteractiveType,
+    },
+    level_transitions::{
+        spawn_level_transition, LevelTransition, LevelTransitionDuration, LevelTransitionEffect,
+        ScreenCovered,
+    },
 };
 
 #[derive(Message)]
@@ -35,6 +55,10 @@ impl LoadLevel {
         }
     }
 
+    pub fn from_path(level_path: PathBuf) -> Self {
+        Self { level_path }
+    }
+
     pub fn get_level_path(&self) -> &PathBuf {
         &self.level_path
     }
@@ -54,18 +78,30 @@ impl LoadLevel {
 #[derive(Message)]
 pub struct ChangeLevel {
     level_path: PathBuf,
+    transition_effect: LevelTransitionEffect,
 }
 
 impl ChangeLevel {
     pub fn new(desired_level_name: &str) -> Self {
         Self {
             level_path: PathBuf::from(desired_level_name),
+            transition_effect: LevelTransitionEffect::Cut,
         }
     }
 
+    /// Returns the request, changing the level behind some transition effect instead of a cut.
+    pub fn with_transition_effect(mut self, transition_effect: LevelTransitionEffect) -> Self {
+        self.transition_effect = transition_effect;
+        self
+    }
+
     pub fn get_level_path(&self) -> &PathBuf {
         &self.level_path
     }
+
+    pub fn get_transition_effect(&self) -> LevelTransitionEffect {
+        self.transition_effect
+    }
 }
 
 /// Loads some predetermined map when clicking the "Play" button.
@@ -75,28 +111,172 @@ pub fn load_starting_map(mut change_level_requester: MessageWriter<LoadLevel>) {
     change_level_requester.write(LoadLevel::new(&map_path));
 }
 
+/// The path of the level that is currently loaded, along with the music it plays.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct CurrentLevel {
+    level_path: PathBuf,
+    music: Option<PathBuf>,
+}
+
+impl CurrentLevel {
+    pub fn new(level_path: PathBuf) -> Self {
+        Self {
+            level_path,
+            music: None,
+        }
+    }
+
+    /// Returns the level, playing the given track while it's loaded.
+    pub fn with_music(mut self, music: PathBuf) -> Self {
+        self.music = Some(music);
+        self
+    }
+
+    pub fn get_level_path(&self) -> &PathBuf {
+        &self.level_path
+    }
+
+    pub fn get_music(&self) -> Option<&PathBuf> {
+        self.music.as_ref()
+    }
+}
+
+/// Sent once a level has been built, including the level the game starts in.
+#[derive(Message, Debug, Clone)]
+pub struct LevelLoaded {
+    level_path: PathBuf,
+}
+
+impl LevelLoaded {
+    pub fn new(level_path: PathBuf) -> Self {
+        Self { level_path }
+    }
+
+    pub fn get_level_path(&self) -> &PathBuf {
+        &self.level_path
+    }
+}
+
 /// Loads the Tiled test map with a Camera into the game at the center of the map.
+///
+/// With a loading screen, the map waits behind it until its assets have loaded.
 pub fn load_map(
     mut change_level_requests: MessageReader<LoadLevel>,
     mut commands: Commands,
     asset_spawner: Res<AssetServer>,
     mut texture_atlas_assets: ResMut<Assets<TextureAtlasLayout>>,
+    idle_time: Res<IdleTime>,
+    picked_up_items: Res<PickedUpItems>,
+    carried_inventory: Option<Res<Inventory>>,
+    story_flags: Res<StoryFlags>,
+    mut pending_loads: Option<ResMut<PendingLoads>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    camera_follow: Res<CameraFollow>,
+    mut loaded_levels: MessageWriter<LevelLoaded>,
 ) {
     if change_level_requests.is_empty() {
         return;
     }
 
     let change_level_request = change_level_requests.read().next().unwrap();
-    let tiled_map = load_tiled_map(PathBuf::from(change_level_request.get_level_path()));
+    let level_path = PathBuf::from(change_level_request.get_level_path());
+
+    let is_held_back = hold_back_until_loaded(
+        pending_loads.as_deref_mut(),
+        &mut next_state,
+        PendingLoad::Level(level_path.clone()),
+    );
+    if is_held_back {
+        return;
+    }
+
+    let tiled_map = load_tiled_map(level_path.clone());
     let map = Tilemap::from_tiled(&tiled_map);
     let bevy_map = RenderedMap::new(&map, &asset_spawner, &mut texture_atlas_assets);
+    let mut npc_markers = get_npcs_from(&tiled_map);
+    let mut item_markers = get_items_from(&tiled_map);
+
+    // The inventory is carried over from the previous level, if there was one.
+    let inventory = carried_inventory
+        .map(|carried_inventory| (*carried_inventory).clone())
+        .unwrap_or_default();
+    commands.remove_resource::<Inventory>();
 
     let rendered_tiles = bevy_map.get_bevy_tiles();
 
     for render_tile in rendered_tiles {
         let render_tile = render_tile.clone();
         if render_tile.get_tile_type() == &TileType::Player {
-            commands.spawn((render_tile, Player, MovementDirection::Left));
+            commands.spawn((
+                render_tile,
+                Player,
+                MovementDirection::Left,
+                inventory.clone(),
+            ));
+            continue;
+        }
+
+        if render_tile.get_tile_type() == &TileType::Item {
+            let item_grid_cords = render_tile.get_grid_coordinates();
+            let found_item_marker = take_marker_at(&mut item_markers, item_grid_cords, |marker| {
+                marker.get_grid_coordinates()
+            });
+            let Some(item_marker) = found_item_marker else {
+                warn!(
+                    "load_map: Could not find the properties of the item at {:?}.",
+                    item_grid_cords
+                );
+                continue;
+            };
+
+            if picked_up_items.has_picked_up(&level_path, item_marker.get_object_id()) {
+                continue;
+            }
+
+            // Items that need a story flag stay hidden until it's set.
+            let flag_conditions = item_marker.get_flag_conditions().clone();
+            if !flag_conditions.is_met_by(&story_flags) {
+                continue;
+            }
+
+            let item_pickup =
+                ItemPickup::new(item_marker.get_object_id(), item_marker.get_item().clone());
+            commands.spawn((render_tile, item_pickup, flag_conditions));
+            continue;
+        }
+
+        if render_tile.get_tile_type() == &TileType::Npc {
+            let npc_grid_cords = render_tile.get_grid_coordinates();
+            let found_npc_marker = take_marker_at(&mut npc_markers, npc_grid_cords, |marker| {
+                marker.get_grid_coordinates()
+            });
+            let Some(npc_marker) = found_npc_marker else {
+                warn!(
+                    "load_map: Could not find the properties of the character at {:?}.",
+                    npc_grid_cords
+                );
+                continue;
+            };
+
+            // Characters that need a story flag are left out, which lets several characters
+            // share a spot, each showing up at a different point in the story.
+            let flag_conditions = npc_marker.get_flag_conditions().clone();
+            if !flag_conditions.is_met_by(&story_flags) {
+                continue;
+            }
+
+            let npc = npc_marker.get_npc().clone();
+            let facing = *npc_marker.get_facing();
+            let behavior = NpcBehavior::new(*npc_marker.get_grid_coordinates());
+            let idle_timer = IdleTimer::new(Timer::new(*idle_time.get_duration(), TimerMode::Once));
+            commands.spawn((
+                render_tile,
+                npc,
+                facing,
+                behavior,
+                idle_timer,
+                flag_conditions,
+            ));
             continue;
         }
 
@@ -104,7 +284,7 @@ pub fn load_map(
     }
 
     let camera_centered_to_map = create_centered_camera(&map);
-    commands.spawn(camera_centered_to_map);
+    commands.spawn((camera_centered_to_map, camera_follow.create_controller()));
 
     // This section represents the Physical properties of the map.
     let map_size_in_px = *bevy_map.get_px_dimensions();
@@ -112,19 +292,47 @@ pub fn load_map(
     let mut interactives = get_interactives_from(&tiled_map);
     // We have to flip the y-axis of all tiles, since they're physical coordinates.
     interactives = flip_interactives_on_y_axis(interactives, map_size_in_px, map_grid_dimenions);
-    let interactive_collection = InteractiveCollection::from_markers(interactives);
+    let tile_dimensions = PxDimensions::new(tiled_map.tile_width, tiled_map.tile_height);
+    let interactive_collection = InteractiveCollection::from_markers(interactives, tile_dimensions);
     let physical_properties = (map_size_in_px, interactive_collection);
 
     // This section represents all of the Logical properties of the map.
     let collision_collection = create_collision_collection_from(&bevy_map);
+    let material_collection = get_materials_from(&tiled_map);
     let map_size_in_tiles = *bevy_map.get_grid_dimensions();
-    let logical_properties = (collision_collection, map_size_in_tiles);
+    let logical_properties = (collision_collection, material_collection, map_size_in_tiles);
 
     commands.spawn((physical_properties, logical_properties));
+
+    for ambient_sound in get_ambient_sounds_from(&tiled_map) {
+        commands.spawn((ambient_sound, AmbientMix::default()));
+    }
+
+    let level_music = get_level_music(&tiled_map, &level_path);
+    commands.insert_resource(CurrentLevel::new(level_path.clone()).with_music(level_music));
+
+    loaded_levels.write(LevelLoaded::new(level_path));
+}
+
+/// Takes the first of some markers found at a grid coordinate out of them, so that several
+/// characters or items sharing a spot each get their own marker, in the order they were placed.
+fn take_marker_at<T>(
+    markers: &mut Vec<T>,
+    grid_cords: &GridCords3D,
+    get_grid_cords: impl Fn(&T) -> &GridCords3D,
+) -> Option<T> {
+    let found_index = markers
+        .iter()
+        .position(|marker| get_grid_cords(marker) == grid_cords)?;
+
+    Some(markers.remove(found_index))
 }
 
 pub fn change_to_new_level(
     mut change_level_requests: MessageReader<ChangeLevel>,
+    mut covered_screens: MessageReader<ScreenCovered>,
+    level_transitions: Query<(), With<LevelTransition>>,
+    transition_duration: Res<LevelTransitionDuration>,
     mut load_level_broadcaster: MessageWriter<LoadLevel>,
     loaded_level_tiles: Query<(Entity, &GridCords3D, &TileType, &PxDimensions)>,
     map_properties: Query<
@@ -137,12 +345,41 @@ pub fn change_to_new_level(
         ),
     >,
     camera: Query<Entity, With<Camera2d>>,
+    ambient_sounds: Query<Entity, With<AmbientSound>>,
+    player_inventory: Query<&Inventory, With<Player>>,
     mut commands: Commands,
 ) {
-    if change_level_requests.is_empty() {
+    // Levels are swapped right away when cutting to them, otherwise only once their
+    // transition has covered the screen.
+    let mut found_level_path = covered_screens
+        .read()
+        .next()
+        .map(|covered_screen| covered_screen.get_level_path().clone());
+
+    if let Some(change_level_request) = change_level_requests.read().next() {
+        let level_path = change_level_request.get_level_path().clone();
+
+        match change_level_request.get_transition_effect() {
+            LevelTransitionEffect::Cut => found_level_path = Some(level_path),
+            // Only one transition runs at a time, so a level can't be left twice.
+            transition_effect if level_transitions.is_empty() => spawn_level_transition(
+                &mut commands,
+                LevelTransition::new(transition_effect, level_path, &transition_duration),
+            ),
+            _ => {}
+        }
+    }
+
+    if found_level_path.is_none() {
         return;
     }
 
+    // The Player is despawned along with the rest of the level, so whatever they're
+    // carrying is held onto until the Player of the next level is spawned.
+    if let Ok(inventory) = player_inventory.single() {
+        commands.insert_resource(inventory.clone());
+    }
+
     for loaded_tile in &loaded_level_tiles {
         let loaded_tile_entity = loaded_tile.0;
         commands.entity(loaded_tile_entity).despawn();
@@ -156,12 +393,42 @@ pub fn change_to_new_level(
         commands.entity(map_properties_entity).despawn();
     }
 
-    let change_level_request = change_level_requests.read().next().unwrap();
-    let load_level_request = LoadLevel::from(change_level_request);
+    for ambient_sound_entity in &ambient_sounds {
+        commands.entity(ambient_sound_entity).despawn();
+    }
+
+    let load_level_request = LoadLevel::from_path(found_level_path.unwrap());
 
     load_level_broadcaster.write(load_level_request);
 }
 
+/// Removes everything the loaded level is made of, such as when quitting to the title screen.
+pub fn unload_level(
+    loaded_level_tiles: Query<Entity, With<TileType>>,
+    map_properties: Query<Entity, (With<InteractiveCollection>, With<GridDimensions>)>,
+    camera: Query<Entity, With<Camera2d>>,
+    ambient_sounds: Query<Entity, With<AmbientSound>>,
+    mut commands: Commands,
+) {
+    for loaded_tile_entity in &loaded_level_tiles {
+        commands.entity(loaded_tile_entity).despawn();
+    }
+
+    for camera_entity in &camera {
+        commands.entity(camera_entity).despawn();
+    }
+
+    for map_properties_entity in &map_properties {
+        commands.entity(map_properties_entity).despawn();
+    }
+
+    for ambient_sound_entity in &ambient_sounds {
+        commands.entity(ambient_sound_entity).despawn();
+    }
+
+    commands.remove_resource::<CurrentLevel>();
+}
+
 /// Returns a loaded Tiled map.
 pub fn load_tiled_map(map_location: PathBuf) -> Map {
     let mut loader = Loader::new();
@@ -201,7 +468,9 @@ pub fn change_level_from_marker(
     mut requests_to_interact: MessageReader<PlayerInteraction>,
     player: Query<(&Transform, &PxDimensions, &MovementDirection), With<Player>>,
     map_markers: Query<(&InteractiveCollection, &PxDimensions)>,
+    story_flags: Res<StoryFlags>,
     mut change_level_requests: MessageWriter<ChangeLevel>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
 ) {
     if player.is_empty() {
         return;
@@ -222,32 +491,35 @@ pub fn change_level_from_marker(
     for _ in requests_to_interact.read() {
         let (marker_collection, map_dimensions_in_px) = map_markers.single().unwrap();
 
-        let found_inspected_point = set_physical_destination(
+        let found_marker = find_marker_ahead(
+            marker_collection,
             current_player_position,
             player_dimensions,
             map_dimensions_in_px,
             player_direction,
         );
 
-        if found_inspected_point.is_none() {
-            continue;
-        }
-
-        let inspected_point = found_inspected_point.unwrap();
-        let inspected_cords = transform_to_xyzcord(inspected_point);
-        let found_marker = marker_collection.get_marker_from_position(&inspected_cords);
-
         if found_marker.is_none() {
             return;
         }
 
+        // Transitions that need a story flag stay locked until it's set.
         let marker = found_marker.unwrap();
-        if marker.get_type_name() != "Transition".to_string() {
+        if !marker.get_flag_conditions().is_met_by(&story_flags) {
             return;
         }
 
-        let level_name = ChangeLevel::new(&marker.get_path().to_str().unwrap());
-        change_level_requests.write(level_name);
+        match marker.get_interactive_type() {
+            InteractiveType::Transition(level_path, transition_effect) => {
+                let level_name = ChangeLevel::new(level_path.to_str().unwrap())
+                    .with_transition_effect(transition_effect);
+                change_level_requests.write(level_name);
+                marker
+                    .get_flag_conditions()
+                    .set_flag_with(&mut set_story_flag_requests);
+            }
+            InteractiveType::Inspect(_) | InteractiveType::Trigger(_) => return,
+        }
     }
 }
 
@@ -1,2 +1,4 @@
 pub mod interactives;
+pub mod level_transitions;
 pub mod map_changing;
+pub mod triggers;
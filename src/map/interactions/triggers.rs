@@ -0,0 +1,321 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use tiled::PropertyValue;
+
+use crate::{
+    map::{
+        get_string_property, movement::grid_based_movement::ReachedTarget, player::Player,
+        GridDimensions, PxCords, PxDimensions,
+    },
+    narrative::story_flags::{SetStoryFlag, StoryFlag, StoryFlags},
+};
+
+use super::{
+    interactives::{get_marker_at_tile, InteractiveCollection, InteractiveMarker, InteractiveType},
+    map_changing::{ChangeLevel, CurrentLevel},
+};
+
+/// A marker that fires as soon as the player steps into or out of it.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+pub struct Trigger {
+    action: TriggerAction,
+    activation: TriggerActivation,
+    is_once: bool,
+    cooldown: Duration,
+}
+
+impl Trigger {
+    pub fn new(
+        action: TriggerAction,
+        activation: TriggerActivation,
+        is_once: bool,
+        cooldown: Duration,
+    ) -> Self {
+        Self {
+            action,
+            activation,
+            is_once,
+            cooldown,
+        }
+    }
+
+    pub fn get_action(&self) -> &TriggerAction {
+        &self.action
+    }
+
+    pub fn get_activation(&self) -> &TriggerActivation {
+        &self.activation
+    }
+
+    pub fn is_once(&self) -> bool {
+        self.is_once
+    }
+
+    pub fn get_cooldown(&self) -> &Duration {
+        &self.cooldown
+    }
+}
+
+/// What happens when a trigger fires.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+pub enum TriggerAction {
+    /// Loads the act at the given path.
+    StartAct(String),
+    /// Changes the level to the map at the given path.
+    ChangeLevel(PathBuf),
+    /// Sets the story flag with the given name.
+    SetFlag(String),
+}
+
+/// Whether a trigger fires when the player steps into it or out of it.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
+pub enum TriggerActivation {
+    OnEnter,
+    OnExit,
+}
+
+/// Sent whenever the player sets off a trigger.
+#[derive(Message, Debug, Clone)]
+pub struct TriggerFired {
+    action: TriggerAction,
+}
+
+impl TriggerFired {
+    pub fn new(action: TriggerAction) -> Self {
+        Self { action }
+    }
+
+    pub fn get_action(&self) -> &TriggerAction {
+        &self.action
+    }
+}
+
+/// When each trigger last fired, kept for every level so that triggers which only fire
+/// once don't fire again when coming back to a level.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "Vec<FiredTrigger>", into = "Vec<FiredTrigger>")]
+pub struct TriggerHistory {
+    last_fired: HashMap<(PathBuf, PxCords), Duration>,
+}
+
+/// When a trigger last fired, as it's written to a save file, since save files can only
+/// be keyed by text.
+#[derive(Serialize, Deserialize)]
+struct FiredTrigger {
+    level_path: PathBuf,
+    position: PxCords,
+    last_fired: Duration,
+}
+
+impl From<Vec<FiredTrigger>> for TriggerHistory {
+    fn from(fired_triggers: Vec<FiredTrigger>) -> Self {
+        let last_fired = fired_triggers
+            .into_iter()
+            .map(|fired| ((fired.level_path, fired.position), fired.last_fired))
+            .collect();
+
+        Self { last_fired }
+    }
+}
+
+impl From<TriggerHistory> for Vec<FiredTrigger> {
+    fn from(trigger_history: TriggerHistory) -> Self {
+        trigger_history
+            .last_fired
+            .into_iter()
+            .map(|((level_path, position), last_fired)| FiredTrigger {
+                level_path,
+                position,
+                last_fired,
+            })
+            .collect()
+    }
+}
+
+impl TriggerHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether a trigger is allowed to fire at the given time.
+    pub fn can_fire(
+        &self,
+        level_path: &PathBuf,
+        trigger_marker: &InteractiveMarker,
+        trigger: &Trigger,
+        now: Duration,
+    ) -> bool {
+        let key = (level_path.clone(), trigger_marker.get_position());
+        let found_last_fired = self.last_fired.get(&key);
+
+        if found_last_fired.is_none() {
+            return true;
+        }
+
+        if trigger.is_once() {
+            return false;
+        }
+
+        let last_fired = *found_last_fired.unwrap();
+        now.saturating_sub(last_fired) >= *trigger.get_cooldown()
+    }
+
+    pub fn record(
+        &mut self,
+        level_path: &PathBuf,
+        trigger_marker: &InteractiveMarker,
+        now: Duration,
+    ) {
+        let key = (level_path.clone(), trigger_marker.get_position());
+        self.last_fired.insert(key, now);
+    }
+}
+
+/// Returns a trigger from the properties of a Tiled object, or None if its action is missing.
+pub fn create_trigger(properties: &HashMap<String, PropertyValue>) -> Option<Trigger> {
+    let found_action = match properties.get("Action") {
+        Some(PropertyValue::StringValue(action)) => Some(action.as_str()),
+        _ => None,
+    };
+
+    let action = match found_action {
+        Some("StartAct") => get_string_property(properties, "Act").map(TriggerAction::StartAct),
+        Some("ChangeLevel") => get_string_property(properties, "Destination")
+            .map(|destination| TriggerAction::ChangeLevel(PathBuf::from(destination))),
+        Some("SetFlag") => get_string_property(properties, "Flag").map(TriggerAction::SetFlag),
+        _ => None,
+    };
+
+    if action.is_none() {
+        warn!("create_trigger: Trigger marker has a missing or invalid Action.");
+        return None;
+    }
+
+    let activation = match get_string_property(properties, "Activation").as_deref() {
+        Some("OnExit") => TriggerActivation::OnExit,
+        _ => TriggerActivation::OnEnter,
+    };
+
+    let is_once = matches!(properties.get("Once"), Some(PropertyValue::BoolValue(true)));
+
+    let cooldown = match properties.get("Cooldown") {
+        Some(PropertyValue::FloatValue(seconds)) => Duration::from_secs_f32(*seconds),
+        Some(PropertyValue::IntValue(seconds)) => Duration::from_secs(*seconds as u64),
+        _ => Duration::ZERO,
+    };
+
+    Some(Trigger::new(action.unwrap(), activation, is_once, cooldown))
+}
+
+/// Fires the triggers the player has stepped into or out of.
+pub fn fire_triggers(
+    mut reached_targets: MessageReader<ReachedTarget>,
+    player: Query<Entity, With<Player>>,
+    map_markers: Query<(&InteractiveCollection, &PxDimensions, &GridDimensions)>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut trigger_history: ResMut<TriggerHistory>,
+    time: Res<Time>,
+    story_flags: Res<StoryFlags>,
+    mut fired_triggers: MessageWriter<TriggerFired>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+) {
+    if reached_targets.is_empty() {
+        return;
+    }
+
+    if player.is_empty() || map_markers.is_empty() || current_level.is_none() {
+        return;
+    }
+
+    let player_entity = player.single().unwrap();
+    let (marker_collection, map_dimensions_in_px, map_grid_dimensions) =
+        map_markers.single().unwrap();
+    let current_level = current_level.unwrap();
+    let level_path = current_level.get_level_path();
+
+    for reached_target in reached_targets.read() {
+        if reached_target.get_entity() != player_entity {
+            continue;
+        }
+
+        let marker_left = get_marker_at_tile(
+            marker_collection,
+            reached_target.get_from(),
+            map_dimensions_in_px,
+            map_grid_dimensions,
+        );
+        let marker_entered = get_marker_at_tile(
+            marker_collection,
+            reached_target.get_to(),
+            map_dimensions_in_px,
+            map_grid_dimensions,
+        );
+
+        // Walking around inside of a single marker neither enters nor exits it.
+        if marker_left == marker_entered {
+            continue;
+        }
+
+        let stepped_markers = [
+            (marker_left, TriggerActivation::OnExit),
+            (marker_entered, TriggerActivation::OnEnter),
+        ];
+
+        for (found_marker, activation) in stepped_markers {
+            if found_marker.is_none() {
+                continue;
+            }
+            let marker = found_marker.unwrap();
+
+            let trigger = match marker.get_interactive_type() {
+                InteractiveType::Trigger(trigger) => trigger,
+                _ => continue,
+            };
+
+            if trigger.get_activation() != &activation {
+                continue;
+            }
+
+            if !marker.get_flag_conditions().is_met_by(&story_flags) {
+                continue;
+            }
+
+            let now = time.elapsed();
+            if !trigger_history.can_fire(level_path, marker, &trigger, now) {
+                continue;
+            }
+
+            trigger_history.record(level_path, marker, now);
+            fired_triggers.write(TriggerFired::new(trigger.get_action().clone()));
+            marker
+                .get_flag_conditions()
+                .set_flag_with(&mut set_story_flag_requests);
+        }
+    }
+}
+
+/// Changes the level when a trigger asks for it.
+pub fn change_level_from_trigger(
+    mut fired_triggers: MessageReader<TriggerFired>,
+    mut change_level_requests: MessageWriter<ChangeLevel>,
+) {
+    for fired_trigger in fired_triggers.read() {
+        if let TriggerAction::ChangeLevel(level_path) = fired_trigger.get_action() {
+            change_level_requests.write(ChangeLevel::new(level_path.to_str().unwrap()));
+        }
+    }
+}
+
+/// Sets a story flag when a trigger asks for it.
+pub fn set_story_flag_from_trigger(
+    mut fired_triggers: MessageReader<TriggerFired>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+) {
+    for fired_trigger in fired_triggers.read() {
+        if let TriggerAction::SetFlag(flag_name) = fired_trigger.get_action() {
+            set_story_flag_requests.write(SetStoryFlag::new(flag_name, StoryFlag::Bool(true)));
+        }
+    }
+}
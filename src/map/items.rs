@@ -0,0 +1,396 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use tiled::{Map, Object, PropertyValue};
+
+use crate::{
+    map::{
+        get_character_grid_cords, get_string_property,
+        interactions::map_changing::CurrentLevel,
+        is_object_layer,
+        movement::grid_based_movement::{set_logical_destination, MovementDirection},
+        player::{Player, PlayerInteraction},
+        render::to_bevy_path,
+        GridCords3D, GridDimensions, PxDimensions,
+    },
+    narrative::story_flags::{FlagConditions, SetStoryFlag},
+};
+
+/// Something the player can pick up and carry around.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    id: String,
+    name: String,
+    icon: ItemIcon,
+    quantity: u32,
+}
+
+impl Item {
+    pub fn new(id: String, name: String, icon: ItemIcon, quantity: u32) -> Self {
+        Self {
+            id,
+            name,
+            icon,
+            quantity,
+        }
+    }
+
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_icon(&self) -> &ItemIcon {
+        &self.icon
+    }
+
+    pub fn get_quantity(&self) -> u32 {
+        self.quantity
+    }
+}
+
+/// The sprite an item is shown with, both on the map and in the inventory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemIcon {
+    spritesheet: PathBuf,
+    sprite_index: usize,
+    tile_dimensions: PxDimensions,
+    spritesheet_dimensions: PxDimensions,
+}
+
+impl ItemIcon {
+    pub fn new(
+        spritesheet: PathBuf,
+        sprite_index: usize,
+        tile_dimensions: PxDimensions,
+        spritesheet_dimensions: PxDimensions,
+    ) -> Self {
+        Self {
+            spritesheet,
+            sprite_index,
+            tile_dimensions,
+            spritesheet_dimensions,
+        }
+    }
+
+    /// Returns the path of the spritesheet, relative to the assets folder.
+    pub fn get_spritesheet(&self) -> &PathBuf {
+        &self.spritesheet
+    }
+
+    pub fn get_sprite_index(&self) -> usize {
+        self.sprite_index
+    }
+
+    pub fn get_tile_dimensions(&self) -> &PxDimensions {
+        &self.tile_dimensions
+    }
+
+    pub fn get_spritesheet_dimensions(&self) -> &PxDimensions {
+        &self.spritesheet_dimensions
+    }
+}
+
+/// An item lying on the map, waiting to be picked up.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ItemPickup {
+    /// The id of the Tiled object the item was placed as, which is unique within its map.
+    object_id: u32,
+    item: Item,
+}
+
+impl ItemPickup {
+    pub fn new(object_id: u32, item: Item) -> Self {
+        Self { object_id, item }
+    }
+
+    pub fn get_object_id(&self) -> u32 {
+        self.object_id
+    }
+
+    pub fn get_item(&self) -> &Item {
+        &self.item
+    }
+}
+
+/// Sent when the player picks up an item, along with how many of it they now carry.
+#[derive(Message, Debug, Clone)]
+pub struct ItemPickedUp {
+    item_id: String,
+    carried_quantity: u32,
+}
+
+impl ItemPickedUp {
+    pub fn new(item_id: String, carried_quantity: u32) -> Self {
+        Self {
+            item_id,
+            carried_quantity,
+        }
+    }
+
+    pub fn get_item_id(&self) -> &String {
+        &self.item_id
+    }
+
+    pub fn get_carried_quantity(&self) -> u32 {
+        self.carried_quantity
+    }
+}
+
+/// The properties of an item placed on a Tiled map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemMarker {
+    object_id: u32,
+    grid_cords: GridCords3D,
+    item: Item,
+    flag_conditions: FlagConditions,
+}
+
+impl ItemMarker {
+    pub fn new(
+        object_id: u32,
+        grid_cords: GridCords3D,
+        item: Item,
+        flag_conditions: FlagConditions,
+    ) -> Self {
+        Self {
+            object_id,
+            grid_cords,
+            item,
+            flag_conditions,
+        }
+    }
+
+    pub fn get_object_id(&self) -> u32 {
+        self.object_id
+    }
+
+    pub fn get_grid_coordinates(&self) -> &GridCords3D {
+        &self.grid_cords
+    }
+
+    pub fn get_item(&self) -> &Item {
+        &self.item
+    }
+
+    pub fn get_flag_conditions(&self) -> &FlagConditions {
+        &self.flag_conditions
+    }
+}
+
+/// Everything the player is carrying.
+///
+/// It lives on the Player, and is kept as a Resource while changing levels
+/// so that it can be handed to the Player of the next level.
+#[derive(Component, Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    items: Vec<Item>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an item, stacking it onto any item with the same id already carried.
+    pub fn add(&mut self, item: Item) {
+        let found_stack = self
+            .items
+            .iter_mut()
+            .find(|carried_item| carried_item.get_id() == item.get_id());
+
+        if let Some(stack) = found_stack {
+            stack.quantity += item.get_quantity();
+            return;
+        }
+
+        self.items.push(item);
+    }
+
+    pub fn get_items(&self) -> &Vec<Item> {
+        &self.items
+    }
+
+    /// Returns how many of some item are carried.
+    pub fn get_quantity_of(&self, item_id: &str) -> u32 {
+        self.items
+            .iter()
+            .find(|item| item.get_id() == item_id)
+            .map_or(0, |item| item.get_quantity())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// The items that have been picked up on each level, by the ids of their Tiled objects,
+/// so that they don't come back when returning to a level.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PickedUpItems {
+    picked_up: HashMap<PathBuf, Vec<u32>>,
+}
+
+impl PickedUpItems {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn has_picked_up(&self, level_path: &PathBuf, object_id: u32) -> bool {
+        self.picked_up
+            .get(level_path)
+            .is_some_and(|picked_up_ids| picked_up_ids.contains(&object_id))
+    }
+
+    pub fn record(&mut self, level_path: &PathBuf, object_id: u32) {
+        self.picked_up
+            .entry(level_path.clone())
+            .or_default()
+            .push(object_id);
+    }
+}
+
+/// Returns all items found on the Interaction layer of a Tiled map.
+pub fn get_items_from(tiled_map: &Map) -> Vec<ItemMarker> {
+    let tile_width = tiled_map.tile_width;
+    let tile_height = tiled_map.tile_height;
+
+    let mut item_markers = Vec::new();
+
+    for z in 0..tiled_map.layers().len() {
+        let is_object_layer = is_object_layer(&tiled_map, z);
+        if !is_object_layer {
+            continue;
+        }
+
+        let layer = tiled_map.get_layer(z).unwrap();
+        if layer.name != "Interaction" {
+            continue;
+        }
+
+        let object_layer = layer.as_object_layer().unwrap();
+
+        for object in object_layer.objects() {
+            if object.user_type != "Item" {
+                continue;
+            }
+
+            let grid_cords = get_character_grid_cords(&object, tile_width, tile_height, z);
+            let item = create_item(&object, tile_width, tile_height);
+            let flag_conditions = FlagConditions::from_properties(&object.properties);
+
+            item_markers.push(ItemMarker::new(
+                object.id(),
+                grid_cords,
+                item,
+                flag_conditions,
+            ));
+        }
+    }
+
+    item_markers
+}
+
+/// Returns an item from a Tiled object, where the Id and Name default to the name
+/// of the object, the Icon defaults to the tile of the object and the Quantity defaults to 1.
+fn create_item(object: &Object, tile_width: u32, tile_height: u32) -> Item {
+    let properties = &object.properties;
+
+    let id = get_string_property(properties, "Id").unwrap_or_else(|| object.name.clone());
+    let name = get_string_property(properties, "Name").unwrap_or_else(|| object.name.clone());
+
+    let quantity = match properties.get("Quantity") {
+        Some(PropertyValue::IntValue(quantity)) if *quantity > 0 => *quantity as u32,
+        _ => 1,
+    };
+
+    let tile = object
+        .get_tile()
+        .expect("create_item: Item does not have a tile.");
+    let tileset_image = tile
+        .get_tileset()
+        .image
+        .as_ref()
+        .expect("create_item: Item tileset does not have an image.");
+
+    let sprite_index = match properties.get("Icon") {
+        Some(PropertyValue::IntValue(icon)) => *icon as usize,
+        _ => tile.id() as usize,
+    };
+
+    let icon = ItemIcon::new(
+        to_bevy_path(&tileset_image.source),
+        sprite_index,
+        PxDimensions::new(tile_width, tile_height),
+        PxDimensions::new(tileset_image.width as u32, tileset_image.height as u32),
+    );
+
+    Item::new(id, name, icon, quantity)
+}
+
+/// Picks up the item in front of the player, putting it into their inventory.
+pub fn pick_up_item(
+    mut requests_to_interact: MessageReader<PlayerInteraction>,
+    mut commands: Commands,
+    mut player: Query<(&GridCords3D, &MovementDirection, &mut Inventory), With<Player>>,
+    items: Query<(Entity, &ItemPickup, &GridCords3D, Option<&FlagConditions>), Without<Player>>,
+    world: Query<&GridDimensions>,
+    current_level: Option<Res<CurrentLevel>>,
+    mut picked_up_items: ResMut<PickedUpItems>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+    mut picked_up_item_broadcaster: MessageWriter<ItemPickedUp>,
+) {
+    if requests_to_interact.is_empty() {
+        return;
+    }
+
+    if player.is_empty() || world.is_empty() || current_level.is_none() {
+        return;
+    }
+
+    let (player_grid_cords, player_direction, mut inventory) = player.single_mut().unwrap();
+    let map_grid_dimensions = world.single().unwrap();
+    let level_path = current_level.unwrap().get_level_path().clone();
+
+    for _ in requests_to_interact.read() {
+        let found_inspected_cords =
+            set_logical_destination(player_grid_cords, map_grid_dimensions, player_direction);
+
+        if found_inspected_cords.is_none() {
+            continue;
+        }
+
+        let inspected_cords = found_inspected_cords.unwrap();
+
+        // Only one item is picked up each time, starting with the first one placed on the spot.
+        let found_item = items
+            .iter()
+            .filter(|(_, item_pickup, item_grid_cords, _)| {
+                item_grid_cords.get_x() == inspected_cords.get_x()
+                    && item_grid_cords.get_y() == inspected_cords.get_y()
+                    // Items picked up this frame are still around until it's over.
+                    && !picked_up_items.has_picked_up(&level_path, item_pickup.get_object_id())
+            })
+            .min_by_key(|(_, item_pickup, _, _)| item_pickup.get_object_id());
+
+        let Some((item_entity, item_pickup, _, flag_conditions)) = found_item else {
+            continue;
+        };
+
+        let item_id = item_pickup.get_item().get_id().clone();
+        inventory.add(item_pickup.get_item().clone());
+        let carried_quantity = inventory.get_quantity_of(&item_id);
+        picked_up_item_broadcaster.write(ItemPickedUp::new(item_id, carried_quantity));
+
+        picked_up_items.record(&level_path, item_pickup.get_object_id());
+        commands.entity(item_entity).despawn();
+
+        if let Some(flag_conditions) = flag_conditions {
+            flag_conditions.set_flag_with(&mut set_story_flag_requests);
+        }
+    }
+}
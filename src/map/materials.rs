@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use tiled::Map;
+
+use crate::map::{get_string_property, GridCords3D};
+
+/// What the ground of each tile is made of, such as grass, wood or stone.
+///
+/// Materials are set by a Material property, either on a tile in its tileset or on a
+/// whole tile layer. Tiles on higher layers cover up the materials of those below them.
+#[derive(Component, Debug, Default)]
+pub struct MaterialCollection {
+    materials: HashMap<GridCords3D, String>,
+}
+
+impl MaterialCollection {
+    pub fn new() -> Self {
+        let materials = HashMap::new();
+
+        Self { materials }
+    }
+
+    pub fn get(&self, xyz_coord: &GridCords3D) -> Option<&String> {
+        // NOTE: Like collision, materials apply to all layers, so the z value is zeroed out.
+        let xy_coord = GridCords3D::new(xyz_coord.get_x(), xyz_coord.get_y(), 0);
+        self.materials.get(&xy_coord)
+    }
+
+    pub fn set(&mut self, xyz_coord: &GridCords3D, material: String) {
+        let xy_coord = GridCords3D::new(xyz_coord.get_x(), xyz_coord.get_y(), 0);
+        self.materials.insert(xy_coord, material);
+    }
+}
+
+/// Returns the material of every tile on the map that has one.
+pub fn get_materials_from(tiled_map: &Map) -> MaterialCollection {
+    let mut material_collection = MaterialCollection::new();
+
+    for layer in tiled_map.layers() {
+        let found_tile_layer = layer.as_tile_layer();
+        if found_tile_layer.is_none() {
+            continue;
+        }
+        let tile_layer = found_tile_layer.unwrap();
+
+        let layer_material = get_string_property(&layer.properties, "Material");
+
+        for y in 0..tiled_map.height {
+            for x in 0..tiled_map.width {
+                let found_layer_tile = tile_layer.get_tile(x as i32, y as i32);
+                if found_layer_tile.is_none() {
+                    continue;
+                }
+
+                // A tile's own material wins over the material of its layer.
+                let tile_material = found_layer_tile
+                    .unwrap()
+                    .get_tile()
+                    .and_then(|tile| get_string_property(&tile.properties, "Material"));
+
+                if let Some(material) = tile_material.or(layer_material.clone()) {
+                    material_collection.set(&GridCords3D::new_u32(x, y, 0), material);
+                }
+            }
+        }
+    }
+
+    material_collection
+}
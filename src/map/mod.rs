@@ -1,11 +1,17 @@
-use std::{ffi::OsString, path::PathBuf};
+use std::{collections::HashMap, ffi::OsString, path::PathBuf};
 
+pub mod ambient_sounds;
+pub mod camera;
+pub mod items;
+pub mod materials;
+pub mod npc;
 pub mod player;
 pub mod render;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use tiled::{Map, Object};
+use tiled::{Map, Object, PropertyValue};
 
 pub mod interactions;
 pub mod movement;
@@ -32,6 +38,12 @@ impl Tilemap {
             tiled_tiles.push(player);
         }
 
+        let mut npcs = get_character_tiles(&tiled_map, "NPC", TileType::Npc);
+        tiled_tiles.append(&mut npcs);
+
+        let mut items = get_character_tiles(&tiled_map, "Item", TileType::Item);
+        tiled_tiles.append(&mut items);
+
         let num_rows = get_num_rows_from_map(&tiled_tiles);
         let num_columns = get_num_columns_from_map(&tiled_tiles);
         let grid_dimensions = GridDimensions::new(num_columns, num_rows, num_layers);
@@ -81,6 +93,18 @@ impl Tilemap {
 
         found_players
     }
+
+    pub fn get_npcs(&self) -> Vec<&Tile> {
+        let mut found_npcs = Vec::new();
+
+        for tile in &self.tiled_tiles {
+            if *tile.get_tile_type() == TileType::Npc {
+                found_npcs.push(tile);
+            }
+        }
+
+        found_npcs
+    }
 }
 
 impl Default for Tilemap {
@@ -184,7 +208,9 @@ impl GridDimensions {
     }
 }
 
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub struct PxDimensions {
     px_width: usize,
     px_height: usize,
@@ -250,11 +276,20 @@ fn get_environment_tiles(tiled_map: &Map) -> Vec<Tile> {
 /// Returns a player found on the map from the Interaction layer if it exists.
 /// Returns None otherwise.
 fn get_player(tiled_map: &Map) -> Option<Tile> {
+    get_character_tiles(tiled_map, "Player", TileType::Player)
+        .into_iter()
+        .next()
+}
+
+/// Returns every character of some Tiled type found on the map from the Interaction layer.
+fn get_character_tiles(tiled_map: &Map, user_type: &str, tile_type: TileType) -> Vec<Tile> {
     let tile_width = tiled_map.tile_width;
     let tile_height = tiled_map.tile_height;
 
     let tile_dimensions = PxDimensions::new(tile_width, tile_height);
 
+    let mut characters = Vec::new();
+
     for z in 0..tiled_map.layers().len() {
         let is_object_layer = is_object_layer(&tiled_map, z);
         if !is_object_layer {
@@ -269,30 +304,44 @@ fn get_player(tiled_map: &Map) -> Option<Tile> {
         let object_layer = layer.as_object_layer().unwrap();
 
         for object in object_layer.objects() {
-            if object.user_type == "Player" {
-                let x = object.x as u32;
-                let y = object.y as u32;
-                let px_cords = PxCords::new_u32(x, y - tile_height, z);
-                // We have to subtract 1 from the y due to the y position tiled reports for image
-                // tiles being the bottom of the tile, while we consider it the top of the tile
-                let grid_cords = GridCords3D::new_u32(x / tile_width, (y / tile_height) - 1, z);
-                let tile_texture = Some(get_player_tile_texture(&object));
-                //let layer_number = z;
-                let tile_type = TileType::Player;
-
-                return Some(Tile::new(
-                    tile_dimensions,
-                    px_cords,
-                    grid_cords,
-                    tile_texture,
-                    //layer_number,
-                    tile_type,
-                ));
+            if object.user_type != user_type {
+                continue;
             }
+
+            let x = object.x as u32;
+            let y = object.y as u32;
+            let px_cords = PxCords::new_u32(x, y - tile_height, z);
+            let grid_cords = get_character_grid_cords(&object, tile_width, tile_height, z);
+            let tile_texture = Some(get_character_tile_texture(&object));
+            //let layer_number = z;
+
+            characters.push(Tile::new(
+                tile_dimensions,
+                px_cords,
+                grid_cords,
+                tile_texture,
+                //layer_number,
+                tile_type,
+            ));
         }
     }
 
-    None
+    characters
+}
+
+/// Returns the grid coordinates of a character object placed in Tiled.
+pub fn get_character_grid_cords(
+    object: &Object,
+    tile_width: u32,
+    tile_height: u32,
+    layer_num: usize,
+) -> GridCords3D {
+    let x = object.x as u32;
+    let y = object.y as u32;
+
+    // We have to subtract 1 from the y due to the y position tiled reports for image
+    // tiles being the bottom of the tile, while we consider it the top of the tile
+    GridCords3D::new_u32(x / tile_width, (y / tile_height) - 1, layer_num)
 }
 
 fn get_num_columns_from_map(tiles: &[Tile]) -> u32 {
@@ -330,10 +379,14 @@ pub enum TileType {
     Empty,
     Normal,
     Player,
+    Npc,
+    Item,
     Collision,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
 pub struct PxCords {
     px_x: usize,
     px_y: usize,
@@ -382,7 +435,20 @@ impl GridCords2D {
     }
 }
 
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(
+    Component,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+)]
 pub struct GridCords3D {
     grid_x: usize,
     grid_y: usize,
@@ -428,16 +494,28 @@ fn is_tile_layer(tiled_map: &Map, idx: usize) -> bool {
     found_tile_layer.is_some()
 }
 
+/// Returns the value of a string property of some Tiled object, if it has one.
+pub fn get_string_property(
+    properties: &HashMap<String, PropertyValue>,
+    name: &str,
+) -> Option<String> {
+    if let Some(PropertyValue::StringValue(value)) = properties.get(name) {
+        return Some(value.clone());
+    }
+
+    None
+}
+
 pub fn is_object_layer(tiled_map: &Map, idx: usize) -> bool {
     let found_object_layer = tiled_map.get_layer(idx).unwrap().as_object_layer();
 
     found_object_layer.is_some()
 }
 
-fn get_player_tile_texture(object: &Object) -> TileTexture {
+fn get_character_tile_texture(object: &Object) -> TileTexture {
     let tile = object
         .get_tile()
-        .expect("get_player_tile_texture: Player does not have a tile.");
+        .expect("get_character_tile_texture: Character does not have a tile.");
     let sprite_index = tile.id() as usize;
     let spritesheet = tile.get_tileset().image.clone().unwrap().source;
     let spritesheet_px_width = tile.get_tileset().image.as_ref().unwrap().width as u32;
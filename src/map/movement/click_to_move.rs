@@ -0,0 +1,356 @@
+use std::collections::VecDeque;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::map::{
+    interactions::interactives::{get_marker_at_tile, InteractiveCollection, InteractiveType},
+    items::ItemPickup,
+    npc::{is_occupied_by_character, Npc},
+    player::{Player, PlayerInteraction},
+    GridCords3D, GridDimensions, PxDimensions,
+};
+
+use super::{
+    collision::CollisionCollection,
+    grid_based_movement::{set_logical_destination, MovementDirection, Target},
+    npc_movement::get_direction_towards,
+    pathfinding::find_path,
+};
+
+/// A request for the player to walk to some tile, interacting with it if there's
+/// something there to interact with.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct WalkToTile {
+    grid_cords: GridCords3D,
+}
+
+impl WalkToTile {
+    pub fn new(grid_cords: GridCords3D) -> Self {
+        Self { grid_cords }
+    }
+
+    pub fn get_grid_coordinates(&self) -> &GridCords3D {
+        &self.grid_cords
+    }
+}
+
+/// The steps the player has left to take towards a clicked tile.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct QueuedPath {
+    steps: VecDeque<GridCords3D>,
+    interaction_cords: Option<GridCords3D>,
+    is_walking: bool,
+}
+
+impl QueuedPath {
+    pub fn new(steps: Vec<GridCords3D>, interaction_cords: Option<GridCords3D>) -> Self {
+        Self {
+            steps: VecDeque::from(steps),
+            interaction_cords,
+            is_walking: false,
+        }
+    }
+
+    pub fn get_steps(&self) -> &VecDeque<GridCords3D> {
+        &self.steps
+    }
+
+    pub fn get_interaction_coordinates(&self) -> &Option<GridCords3D> {
+        &self.interaction_cords
+    }
+}
+
+/// Requests the player to walk to whichever tile was clicked on.
+pub fn walk_to_tile_on_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    camera: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    world: Query<(&PxDimensions, &GridDimensions), With<CollisionCollection>>,
+    player: Query<&GridCords3D, With<Player>>,
+    mut walk_requests: MessageWriter<WalkToTile>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    if window.is_empty() || camera.is_empty() || world.is_empty() || player.is_empty() {
+        return;
+    }
+
+    let found_cursor_position = window.single().unwrap().cursor_position();
+    if found_cursor_position.is_none() {
+        return;
+    }
+    let cursor_position = found_cursor_position.unwrap();
+
+    let (camera, camera_transform) = camera.single().unwrap();
+    let found_world_position = camera.viewport_to_world_2d(camera_transform, cursor_position);
+    if found_world_position.is_err() {
+        return;
+    }
+    let world_position = found_world_position.unwrap();
+
+    let (map_px_dimensions, map_grid_dimensions) = world.single().unwrap();
+    let player_grid_cords = player.single().unwrap();
+
+    let found_clicked_cords = get_grid_cords_from_world_position(
+        world_position,
+        map_px_dimensions,
+        map_grid_dimensions,
+        player_grid_cords.get_z(),
+    );
+    if found_clicked_cords.is_none() {
+        return;
+    }
+
+    walk_requests.write(WalkToTile::new(found_clicked_cords.unwrap()));
+}
+
+/// Returns the grid coordinates of the tile found at some position in the world,
+/// or None if the position is outside of the map.
+pub fn get_grid_cords_from_world_position(
+    world_position: Vec2,
+    map_px_dimensions: &PxDimensions,
+    map_grid_dimensions: &GridDimensions,
+    layer: usize,
+) -> Option<GridCords3D> {
+    let columns = map_grid_dimensions.get_columns() as f32;
+    let rows = map_grid_dimensions.get_rows() as f32;
+    let tile_width = map_px_dimensions.get_width() as f32 / columns;
+    let tile_height = map_px_dimensions.get_height() as f32 / rows;
+
+    // Tiles are drawn from their center, so they reach half a tile behind their position.
+    let column = ((world_position.x + tile_width / 2.0) / tile_width).floor();
+    let flipped_row = ((world_position.y + tile_height / 2.0) / tile_height).floor();
+
+    if column < 0.0 || column >= columns || flipped_row < 0.0 || flipped_row >= rows {
+        return None;
+    }
+
+    // Y-axis flip, because Bevy is Y-Up while the grid is Y-Down
+    let row = rows - 1.0 - flipped_row;
+
+    Some(GridCords3D::new(column as usize, row as usize, layer))
+}
+
+/// Finds the way to a requested tile, or to a tile next to it when there's something there
+/// to interact with, and queues up the steps for the player to take.
+pub fn set_player_path(
+    mut walk_requests: MessageReader<WalkToTile>,
+    mut commands: Commands,
+    player: Query<(Entity, &GridCords3D), With<Player>>,
+    world: Query<(
+        &CollisionCollection,
+        &GridDimensions,
+        &PxDimensions,
+        &InteractiveCollection,
+    )>,
+    // Characters, and items lying on the map, which are solid until they're picked up.
+    blockers: Query<&GridCords3D, (Or<(With<Npc>, With<ItemPickup>)>, Without<Player>)>,
+) {
+    if walk_requests.is_empty() {
+        return;
+    }
+
+    if player.is_empty() {
+        return;
+    }
+
+    if world.is_empty() {
+        return;
+    }
+
+    // Only the latest click matters, since it cancels any path already being walked,
+    // even if there's no way to get to the newly clicked tile.
+    let walk_request = walk_requests
+        .read()
+        .last()
+        .expect("set_player_path: There are no requests to walk.");
+    let requested_cords = walk_request.get_grid_coordinates();
+
+    let (player_entity, player_grid_cords) = player.single().unwrap();
+    let (collision_tiles, map_grid_dimensions, map_px_dimensions, interactive_collection) =
+        world.single().unwrap();
+    let blocker_cords: Vec<GridCords3D> = blockers.iter().copied().collect();
+
+    let has_marker = has_marker_at(
+        requested_cords,
+        interactive_collection,
+        map_px_dimensions,
+        map_grid_dimensions,
+    );
+    let has_blocker = is_occupied_by_character(requested_cords, blocker_cords.iter());
+
+    let queued_path = if has_marker || has_blocker {
+        let found_steps = find_path_next_to(
+            player_grid_cords,
+            requested_cords,
+            map_grid_dimensions,
+            collision_tiles,
+            &blocker_cords,
+        );
+        if found_steps.is_none() {
+            commands.entity(player_entity).remove::<QueuedPath>();
+            return;
+        }
+
+        QueuedPath::new(found_steps.unwrap(), Some(*requested_cords))
+    } else {
+        let steps = find_path(
+            player_grid_cords,
+            requested_cords,
+            map_grid_dimensions,
+            collision_tiles,
+            &blocker_cords,
+        );
+        if steps.is_empty() {
+            commands.entity(player_entity).remove::<QueuedPath>();
+            return;
+        }
+
+        QueuedPath::new(steps, None)
+    };
+
+    commands.entity(player_entity).insert(queued_path);
+}
+
+/// Returns whether a marker the player can interact with covers some tile.
+fn has_marker_at(
+    grid_cords: &GridCords3D,
+    interactive_collection: &InteractiveCollection,
+    map_px_dimensions: &PxDimensions,
+    map_grid_dimensions: &GridDimensions,
+) -> bool {
+    let found_marker = get_marker_at_tile(
+        interactive_collection,
+        grid_cords,
+        map_px_dimensions,
+        map_grid_dimensions,
+    );
+
+    // Triggers are stepped on rather than interacted with, so they're walked onto instead.
+    found_marker
+        .is_some_and(|marker| !matches!(marker.get_interactive_type(), InteractiveType::Trigger(_)))
+}
+
+/// Returns the shortest path to any tile next to some grid coordinate, which is empty
+/// if the player is already there, or None if no tile next to it can be reached.
+fn find_path_next_to(
+    start: &GridCords3D,
+    grid_cords: &GridCords3D,
+    map_grid_dimensions: &GridDimensions,
+    collision_tiles: &CollisionCollection,
+    obstacles: &[GridCords3D],
+) -> Option<Vec<GridCords3D>> {
+    let directions = [
+        MovementDirection::Up,
+        MovementDirection::Down,
+        MovementDirection::Left,
+        MovementDirection::Right,
+    ];
+
+    let neighbors: Vec<GridCords3D> = directions
+        .iter()
+        .filter_map(|direction| set_logical_destination(grid_cords, map_grid_dimensions, direction))
+        .collect();
+
+    let is_next_to = neighbors
+        .iter()
+        .any(|neighbor| neighbor.get_x() == start.get_x() && neighbor.get_y() == start.get_y());
+    if is_next_to {
+        return Some(Vec::new());
+    }
+
+    neighbors
+        .iter()
+        .map(|neighbor| {
+            find_path(
+                start,
+                neighbor,
+                map_grid_dimensions,
+                collision_tiles,
+                obstacles,
+            )
+        })
+        .filter(|path| !path.is_empty())
+        .min_by_key(|path| path.len())
+}
+
+/// Moves the player one step at a time along their queued path, interacting with
+/// whatever was clicked on once they've reached it.
+///
+/// Pressing any direction key cancels the path, as does bumping into something on the way.
+pub fn follow_queued_path(
+    input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut player: Query<
+        (
+            Entity,
+            &GridCords3D,
+            &mut QueuedPath,
+            &mut MovementDirection,
+            Has<Target>,
+        ),
+        With<Player>,
+    >,
+    mut move_player_requester: MessageWriter<MovementDirection>,
+    mut interactive_event_writer: MessageWriter<PlayerInteraction>,
+) {
+    if player.is_empty() {
+        return;
+    }
+
+    let (player_entity, player_grid_cords, mut queued_path, mut player_direction, is_moving) =
+        player.single_mut().unwrap();
+
+    let direction_keys = [KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD];
+    if input.any_pressed(direction_keys) {
+        commands.entity(player_entity).remove::<QueuedPath>();
+        return;
+    }
+
+    if is_moving {
+        return;
+    }
+
+    if queued_path.is_walking {
+        queued_path.is_walking = false;
+
+        let has_arrived = queued_path.steps.front() == Some(player_grid_cords);
+        if !has_arrived {
+            commands.entity(player_entity).remove::<QueuedPath>();
+            return;
+        }
+
+        queued_path.steps.pop_front();
+    }
+
+    let found_next_step = queued_path.steps.front();
+    if let Some(next_step) = found_next_step {
+        let found_direction =
+            get_direction_towards(player_grid_cords, next_step.get_x(), next_step.get_y());
+        if found_direction.is_none() {
+            commands.entity(player_entity).remove::<QueuedPath>();
+            return;
+        }
+
+        move_player_requester.write(found_direction.unwrap());
+        queued_path.is_walking = true;
+        return;
+    }
+
+    if let Some(interaction_cords) = queued_path.interaction_cords {
+        let found_direction = get_direction_towards(
+            player_grid_cords,
+            interaction_cords.get_x(),
+            interaction_cords.get_y(),
+        );
+
+        if let Some(direction) = found_direction {
+            *player_direction = direction;
+            interactive_event_writer.write(PlayerInteraction);
+        }
+    }
+
+    commands.entity(player_entity).remove::<QueuedPath>();
+}
@@ -2,7 +2,12 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
-use crate::map::{player::*, GridCords3D, GridDimensions, PxDimensions};
+use crate::map::{
+    items::ItemPickup,
+    npc::{is_occupied_by_character, Npc},
+    player::*,
+    GridCords3D, GridDimensions, PxDimensions,
+};
 
 use super::collision::CollisionCollection;
 
@@ -14,6 +19,18 @@ pub enum MovementDirection {
     Down,
 }
 
+impl MovementDirection {
+    /// Returns the direction facing the other way.
+    pub fn opposite(&self) -> MovementDirection {
+        match self {
+            MovementDirection::Left => MovementDirection::Right,
+            MovementDirection::Right => MovementDirection::Left,
+            MovementDirection::Up => MovementDirection::Down,
+            MovementDirection::Down => MovementDirection::Up,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Target {
     position: Transform,
@@ -53,6 +70,12 @@ pub fn set_player_target(
         (With<Player>, Without<Target>, Without<ArrivalTimer>),
     >,
     world: Query<(&CollisionCollection, &GridDimensions, &PxDimensions)>,
+    // Everything the player can't walk through besides collision: characters, and items
+    // lying on the map, which are solid until they're picked up.
+    blockers: Query<
+        (&GridCords3D, Option<&Target>),
+        (Or<(With<Npc>, With<ItemPickup>)>, Without<Player>),
+    >,
     arrival_time: Res<ArrivalTime>,
 ) {
     if player.is_empty() {
@@ -111,6 +134,16 @@ pub fn set_player_target(
         movement_notifications.write(PlayerMovementActions::Bumping);
         return;
     }
+
+    // Characters already walking somewhere have claimed the tile they're walking to.
+    let blocker_cords = blockers.iter().flat_map(|(blocker_cords, blocker_target)| {
+        std::iter::once(blocker_cords)
+            .chain(blocker_target.map(|target| target.get_grid_coordinate()))
+    });
+    if is_occupied_by_character(&new_logical_position, blocker_cords) {
+        movement_notifications.write(PlayerMovementActions::Bumping);
+        return;
+    }
     let starting_position = StartingPosition::new(*current_player_position);
     let new_target = Target::new(new_physical_position, new_logical_position);
 
@@ -271,6 +304,32 @@ impl StartingPosition {
     }
 }
 
+/// Sent whenever some entity finishes moving from one tile to another.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ReachedTarget {
+    entity: Entity,
+    from: GridCords3D,
+    to: GridCords3D,
+}
+
+impl ReachedTarget {
+    pub fn new(entity: Entity, from: GridCords3D, to: GridCords3D) -> Self {
+        Self { entity, from, to }
+    }
+
+    pub fn get_entity(&self) -> Entity {
+        self.entity
+    }
+
+    pub fn get_from(&self) -> &GridCords3D {
+        &self.from
+    }
+
+    pub fn get_to(&self) -> &GridCords3D {
+        &self.to
+    }
+}
+
 /// Moves some entity towards a Target position.
 pub fn move_entity_to_target(
     mut movable_entities: Query<(
@@ -284,6 +343,7 @@ pub fn move_entity_to_target(
     )>,
     mut commands: Commands,
     time: Res<Time>,
+    mut reached_targets: MessageWriter<ReachedTarget>,
 ) {
     for (
         entity,
@@ -298,9 +358,17 @@ pub fn move_entity_to_target(
         time_to_reach_destination.advance(time.delta());
 
         if time_to_reach_destination.timer.is_finished() {
+            let starting_grid_coordinate = *entity_logical_position;
+
             *entity_physical_position = *entity_target.get_position();
             *entity_logical_position = *entity_target.get_grid_coordinate();
 
+            reached_targets.write(ReachedTarget::new(
+                entity,
+                starting_grid_coordinate,
+                *entity_logical_position,
+            ));
+
             commands.entity(entity).remove::<Target>();
             commands.entity(entity).remove::<ArrivalTimer>();
             commands.entity(entity).remove::<StartingPosition>();
@@ -1,2 +1,5 @@
+pub mod click_to_move;
 pub mod collision;
 pub mod grid_based_movement;
+pub mod npc_movement;
+pub mod pathfinding;
@@ -0,0 +1,379 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::map::{
+    items::ItemPickup,
+    npc::{is_occupied_by_character, MovementPattern, Npc},
+    player::Player,
+    GridCords2D, GridCords3D, GridDimensions, PxDimensions,
+};
+
+use super::{collision::CollisionCollection, grid_based_movement::*, pathfinding::find_path};
+
+/// How long characters stand still before taking their next step.
+#[derive(Resource)]
+pub struct IdleTime {
+    time: Duration,
+}
+
+impl IdleTime {
+    pub fn new(time: Duration) -> Self {
+        Self { time }
+    }
+
+    pub fn get_duration(&self) -> &Duration {
+        &self.time
+    }
+}
+
+impl Default for IdleTime {
+    fn default() -> Self {
+        Self::new(Duration::from_secs_f32(1.0))
+    }
+}
+
+#[derive(Component)]
+pub struct IdleTimer {
+    timer: Timer,
+}
+
+impl IdleTimer {
+    pub fn new(timer: Timer) -> Self {
+        Self { timer }
+    }
+
+    pub fn advance(&mut self, time_passed: Duration) {
+        self.timer.tick(time_passed);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.timer.is_finished()
+    }
+
+    pub fn reset(&mut self) {
+        self.timer.reset();
+    }
+}
+
+/// Keeps track of where a character is in its movement pattern.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct NpcBehavior {
+    origin: GridCords3D,
+    next_waypoint: usize,
+    is_reversing: bool,
+}
+
+impl NpcBehavior {
+    pub fn new(origin: GridCords3D) -> Self {
+        Self {
+            origin,
+            next_waypoint: 0,
+            is_reversing: false,
+        }
+    }
+
+    pub fn get_origin(&self) -> &GridCords3D {
+        &self.origin
+    }
+
+    pub fn get_next_waypoint(&self) -> usize {
+        self.next_waypoint
+    }
+
+    /// Moves onto the following waypoint of a path, turning around at either end
+    /// unless the path loops back onto itself.
+    fn advance_waypoint(&mut self, path_length: usize, is_looping: bool) {
+        if path_length <= 1 {
+            return;
+        }
+
+        if is_looping {
+            self.next_waypoint = (self.next_waypoint + 1) % path_length;
+            return;
+        }
+
+        if self.next_waypoint == path_length - 1 {
+            self.is_reversing = true;
+        } else if self.next_waypoint == 0 {
+            self.is_reversing = false;
+        }
+
+        if self.is_reversing {
+            self.next_waypoint -= 1;
+        } else {
+            self.next_waypoint += 1;
+        }
+    }
+}
+
+/// Sets the target location of every character that walks around on its own.
+pub fn set_npc_targets(
+    mut commands: Commands,
+    mut npcs: Query<
+        (
+            Entity,
+            &Npc,
+            &mut NpcBehavior,
+            &mut IdleTimer,
+            &PxDimensions,
+            &Transform,
+            &GridCords3D,
+            &mut MovementDirection,
+        ),
+        (Without<Player>, Without<Target>, Without<ArrivalTimer>),
+    >,
+    characters: Query<
+        (&GridCords3D, Option<&Target>),
+        Or<(With<Player>, With<Npc>, With<ItemPickup>)>,
+    >,
+    world: Query<(&CollisionCollection, &GridDimensions, &PxDimensions)>,
+    arrival_time: Res<ArrivalTime>,
+    time: Res<Time>,
+) {
+    if npcs.is_empty() {
+        return;
+    }
+
+    if world.is_empty() {
+        return;
+    }
+
+    let (collision_tiles, map_grid_dimensions, map_px_dimensions) = world.single().unwrap();
+
+    // Characters already on their way somewhere have claimed the tile they're walking to.
+    let mut occupied_cords: Vec<GridCords3D> = characters
+        .iter()
+        .flat_map(|(grid_cords, target)| {
+            let target_cords = target.map(|target| *target.get_grid_coordinate());
+            std::iter::once(*grid_cords).chain(target_cords)
+        })
+        .collect();
+
+    for (
+        npc_entity,
+        npc,
+        mut npc_behavior,
+        mut idle_timer,
+        npc_tile_dimensions,
+        current_npc_position,
+        current_npc_grid_coordinate,
+        mut npc_direction,
+    ) in &mut npcs
+    {
+        idle_timer.advance(time.delta());
+        if !idle_timer.is_finished() {
+            continue;
+        }
+
+        let found_direction = match npc.get_movement_pattern() {
+            MovementPattern::Wander(radius) => get_wander_direction(
+                current_npc_grid_coordinate,
+                npc_behavior.get_origin(),
+                map_grid_dimensions,
+                *radius,
+            ),
+            MovementPattern::Patrol(map_path) => get_patrol_direction(
+                current_npc_grid_coordinate,
+                &mut npc_behavior,
+                map_path.get_path(),
+                false,
+                map_grid_dimensions,
+                collision_tiles,
+                &occupied_cords,
+            ),
+            MovementPattern::Loop(map_path) => get_patrol_direction(
+                current_npc_grid_coordinate,
+                &mut npc_behavior,
+                map_path.get_path(),
+                true,
+                map_grid_dimensions,
+                collision_tiles,
+                &occupied_cords,
+            ),
+            MovementPattern::Stationary | MovementPattern::FacePlayer => None,
+        };
+
+        // Blocked characters wait out another idle period before trying again.
+        idle_timer.reset();
+
+        if found_direction.is_none() {
+            continue;
+        }
+        let direction = found_direction.unwrap();
+
+        *npc_direction = direction;
+
+        let found_new_physical_position = set_physical_destination(
+            current_npc_position,
+            npc_tile_dimensions,
+            map_px_dimensions,
+            &direction,
+        );
+        if found_new_physical_position.is_none() {
+            continue;
+        }
+        let new_physical_position = found_new_physical_position.unwrap();
+
+        let found_new_logical_position =
+            set_logical_destination(current_npc_grid_coordinate, map_grid_dimensions, &direction);
+        if found_new_logical_position.is_none() {
+            continue;
+        }
+        let new_logical_position = found_new_logical_position.unwrap();
+
+        if collision_tiles.has(&new_logical_position) {
+            continue;
+        }
+
+        if is_occupied_by_character(&new_logical_position, occupied_cords.iter()) {
+            continue;
+        }
+
+        occupied_cords.push(new_logical_position);
+
+        let starting_position = StartingPosition::new(*current_npc_position);
+        let new_target = Target::new(new_physical_position, new_logical_position);
+        let timer = Timer::new(*arrival_time.get_duration(), TimerMode::Once);
+        let arrival_timer = ArrivalTimer::new(timer);
+
+        commands
+            .entity(npc_entity)
+            .insert((starting_position, new_target, arrival_timer));
+    }
+}
+
+/// Returns a random direction to wander in, or None if it would stray too far from the origin.
+fn get_wander_direction(
+    current_grid_coordinate: &GridCords3D,
+    origin: &GridCords3D,
+    map_grid_dimensions: &GridDimensions,
+    radius: usize,
+) -> Option<MovementDirection> {
+    let directions = [
+        MovementDirection::Left,
+        MovementDirection::Right,
+        MovementDirection::Up,
+        MovementDirection::Down,
+    ];
+    let direction = directions[fastrand::usize(..directions.len())];
+
+    let new_grid_coordinate =
+        set_logical_destination(current_grid_coordinate, map_grid_dimensions, &direction)?;
+
+    let x_distance = new_grid_coordinate.get_x().abs_diff(origin.get_x());
+    let y_distance = new_grid_coordinate.get_y().abs_diff(origin.get_y());
+    if x_distance > radius || y_distance > radius {
+        return None;
+    }
+
+    Some(direction)
+}
+
+/// Returns the direction of the first step on the way to the next waypoint of a path,
+/// moving onto the following waypoint whenever the current one has been reached.
+fn get_patrol_direction(
+    current_grid_coordinate: &GridCords3D,
+    npc_behavior: &mut NpcBehavior,
+    path: &[GridCords2D],
+    is_looping: bool,
+    map_grid_dimensions: &GridDimensions,
+    collision_tiles: &CollisionCollection,
+    occupied_cords: &[GridCords3D],
+) -> Option<MovementDirection> {
+    if path.is_empty() {
+        return None;
+    }
+
+    let waypoint = &path[npc_behavior.get_next_waypoint()];
+    if waypoint.get_x() == current_grid_coordinate.get_x()
+        && waypoint.get_y() == current_grid_coordinate.get_y()
+    {
+        npc_behavior.advance_waypoint(path.len(), is_looping);
+    }
+
+    let waypoint = &path[npc_behavior.get_next_waypoint()];
+    let waypoint_cords = GridCords3D::new(
+        waypoint.get_x(),
+        waypoint.get_y(),
+        current_grid_coordinate.get_z(),
+    );
+
+    // Waypoints aren't always next to each other, such as when a character starts
+    // off away from its path, so the way there has to be found.
+    let path_to_waypoint = find_path(
+        current_grid_coordinate,
+        &waypoint_cords,
+        map_grid_dimensions,
+        collision_tiles,
+        occupied_cords,
+    );
+    let next_step = path_to_waypoint.first()?;
+
+    get_direction_towards(
+        current_grid_coordinate,
+        next_step.get_x(),
+        next_step.get_y(),
+    )
+}
+
+/// Returns the direction that closes the most distance between some grid coordinate
+/// and another, preferring horizontal movement on ties, or None if they are the same.
+pub fn get_direction_towards(
+    grid_coordinate: &GridCords3D,
+    towards_x: usize,
+    towards_y: usize,
+) -> Option<MovementDirection> {
+    let x_difference = towards_x as isize - grid_coordinate.get_x() as isize;
+    let y_difference = towards_y as isize - grid_coordinate.get_y() as isize;
+
+    if x_difference == 0 && y_difference == 0 {
+        return None;
+    }
+
+    if x_difference.abs() >= y_difference.abs() {
+        return match x_difference.is_positive() {
+            true => Some(MovementDirection::Right),
+            false => Some(MovementDirection::Left),
+        };
+    }
+
+    // Grid rows count downwards, so a larger y is further down the map.
+    match y_difference.is_positive() {
+        true => Some(MovementDirection::Down),
+        false => Some(MovementDirection::Up),
+    }
+}
+
+/// Turns characters that watch the player to face wherever the player is standing.
+pub fn face_player(
+    player: Query<&GridCords3D, With<Player>>,
+    mut npcs: Query<(&Npc, &GridCords3D, &mut MovementDirection), Without<Player>>,
+) {
+    if player.is_empty() {
+        return;
+    }
+
+    let player_grid_cords = player.single().unwrap();
+
+    for (npc, npc_grid_cords, mut npc_direction) in &mut npcs {
+        if npc.get_movement_pattern() != &MovementPattern::FacePlayer {
+            continue;
+        }
+
+        let found_direction = get_direction_towards(
+            npc_grid_cords,
+            player_grid_cords.get_x(),
+            player_grid_cords.get_y(),
+        );
+
+        if found_direction.is_none() {
+            continue;
+        }
+
+        let direction = found_direction.unwrap();
+        if *npc_direction != direction {
+            *npc_direction = direction;
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use crate::map::{GridCords3D, GridDimensions};
+
+use super::{
+    collision::CollisionCollection,
+    grid_based_movement::{set_logical_destination, MovementDirection},
+};
+
+const DIRECTIONS: [MovementDirection; 4] = [
+    MovementDirection::Up,
+    MovementDirection::Down,
+    MovementDirection::Left,
+    MovementDirection::Right,
+];
+
+/// Returns the shortest 4-directional path between two grid coordinates, walking around
+/// collision tiles and any other obstacles such as occupied tiles.
+///
+/// The path excludes the starting coordinate and ends at the goal. It is empty if the
+/// goal cannot be reached, or if the start and the goal are the same tile.
+pub fn find_path(
+    start: &GridCords3D,
+    goal: &GridCords3D,
+    map_grid_dimensions: &GridDimensions,
+    collision_tiles: &CollisionCollection,
+    obstacles: &[GridCords3D],
+) -> Vec<GridCords3D> {
+    // NOTE: Like collision, paths apply to all layers, so every coordinate is
+    // flattened onto the layer of the starting coordinate.
+    let layer = start.get_z();
+    let start = GridCords3D::new(start.get_x(), start.get_y(), layer);
+    let goal = GridCords3D::new(goal.get_x(), goal.get_y(), layer);
+
+    if start == goal {
+        return Vec::new();
+    }
+
+    let blocked_tiles: HashSet<GridCords3D> = obstacles
+        .iter()
+        .map(|obstacle| GridCords3D::new(obstacle.get_x(), obstacle.get_y(), layer))
+        .collect();
+
+    if is_blocked(&goal, map_grid_dimensions, collision_tiles, &blocked_tiles) {
+        return Vec::new();
+    }
+
+    let mut open_tiles = BinaryHeap::new();
+    let mut came_from: HashMap<GridCords3D, GridCords3D> = HashMap::new();
+    let mut cost_so_far: HashMap<GridCords3D, usize> = HashMap::new();
+
+    // Ties between equally promising tiles are broken by insertion order, which keeps
+    // the chosen path the same every time it's requested.
+    let mut insertion_order = 0;
+    open_tiles.push(Reverse((
+        manhattan_distance(&start, &goal),
+        insertion_order,
+        start,
+    )));
+    cost_so_far.insert(start, 0);
+
+    while let Some(Reverse((_, _, current))) = open_tiles.pop() {
+        if current == goal {
+            return reconstruct_path(&came_from, &start, &goal);
+        }
+
+        let current_cost = cost_so_far[&current];
+
+        for direction in &DIRECTIONS {
+            let found_neighbor = set_logical_destination(&current, map_grid_dimensions, direction);
+            if found_neighbor.is_none() {
+                continue;
+            }
+            let neighbor = found_neighbor.unwrap();
+
+            if is_blocked(
+                &neighbor,
+                map_grid_dimensions,
+                collision_tiles,
+                &blocked_tiles,
+            ) {
+                continue;
+            }
+
+            let new_cost = current_cost + 1;
+            let is_cheaper = cost_so_far
+                .get(&neighbor)
+                .is_none_or(|&known_cost| new_cost < known_cost);
+            if !is_cheaper {
+                continue;
+            }
+
+            cost_so_far.insert(neighbor, new_cost);
+            came_from.insert(neighbor, current);
+
+            insertion_order += 1;
+            let priority = new_cost + manhattan_distance(&neighbor, &goal);
+            open_tiles.push(Reverse((priority, insertion_order, neighbor)));
+        }
+    }
+
+    Vec::new()
+}
+
+/// Returns whether some grid coordinate can't be walked on.
+fn is_blocked(
+    grid_cords: &GridCords3D,
+    map_grid_dimensions: &GridDimensions,
+    collision_tiles: &CollisionCollection,
+    blocked_tiles: &HashSet<GridCords3D>,
+) -> bool {
+    let is_out_of_bounds = grid_cords.get_x() >= map_grid_dimensions.get_columns() as usize
+        || grid_cords.get_y() >= map_grid_dimensions.get_rows() as usize;
+
+    is_out_of_bounds || collision_tiles.has(grid_cords) || blocked_tiles.contains(grid_cords)
+}
+
+fn manhattan_distance(from: &GridCords3D, to: &GridCords3D) -> usize {
+    from.get_x().abs_diff(to.get_x()) + from.get_y().abs_diff(to.get_y())
+}
+
+/// Walks backwards from the goal to the start, returning the path in walking order.
+fn reconstruct_path(
+    came_from: &HashMap<GridCords3D, GridCords3D>,
+    start: &GridCords3D,
+    goal: &GridCords3D,
+) -> Vec<GridCords3D> {
+    let mut path = vec![*goal];
+    let mut current = goal;
+
+    while let Some(previous) = came_from.get(current) {
+        if previous == start {
+            break;
+        }
+
+        path.push(*previous);
+        current = previous;
+    }
+
+    path.reverse();
+    path
+}
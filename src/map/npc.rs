@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use tiled::{Map, ObjectShape, PropertyValue};
+
+use crate::{
+    map::{
+        get_character_grid_cords, get_string_property, is_object_layer,
+        movement::grid_based_movement::{set_logical_destination, MovementDirection},
+        player::{Player, PlayerInteraction},
+        GridCords3D, GridDimensions,
+    },
+    narrative::{
+        acts::{get_loop_from_points, get_path_from_points, MapPath},
+        story_flags::{FlagConditions, SetStoryFlag},
+    },
+};
+
+/// A non-playable character living on the map.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Npc {
+    name: String,
+    dialogue: Option<String>,
+    movement_pattern: MovementPattern,
+}
+
+impl Npc {
+    pub fn new(name: String, dialogue: Option<String>, movement_pattern: MovementPattern) -> Self {
+        Self {
+            name,
+            dialogue,
+            movement_pattern,
+        }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_dialogue(&self) -> &Option<String> {
+        &self.dialogue
+    }
+
+    pub fn get_movement_pattern(&self) -> &MovementPattern {
+        &self.movement_pattern
+    }
+}
+
+/// How a character moves around the map on its own.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MovementPattern {
+    Stationary,
+    /// Wanders around its starting tile, never straying more than the given number of tiles.
+    Wander(usize),
+    /// Walks back and forth along a Path object.
+    Patrol(MapPath),
+    /// Walks around a Loop object, ending each lap where it began.
+    Loop(MapPath),
+    /// Turns to face the player wherever they are.
+    FacePlayer,
+}
+
+/// The properties of a character placed on a Tiled map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpcMarker {
+    grid_cords: GridCords3D,
+    facing: MovementDirection,
+    npc: Npc,
+    flag_conditions: FlagConditions,
+}
+
+impl NpcMarker {
+    pub fn new(
+        grid_cords: GridCords3D,
+        facing: MovementDirection,
+        npc: Npc,
+        flag_conditions: FlagConditions,
+    ) -> Self {
+        Self {
+            grid_cords,
+            facing,
+            npc,
+            flag_conditions,
+        }
+    }
+
+    pub fn get_grid_coordinates(&self) -> &GridCords3D {
+        &self.grid_cords
+    }
+
+    pub fn get_facing(&self) -> &MovementDirection {
+        &self.facing
+    }
+
+    pub fn get_npc(&self) -> &Npc {
+        &self.npc
+    }
+
+    pub fn get_flag_conditions(&self) -> &FlagConditions {
+        &self.flag_conditions
+    }
+}
+
+/// Sent when the player interacts with a character in front of them.
+#[derive(Message, Debug, Clone)]
+pub struct NpcInteraction {
+    npc_name: String,
+    dialogue: Option<String>,
+}
+
+impl NpcInteraction {
+    pub fn new(npc_name: String, dialogue: Option<String>) -> Self {
+        Self { npc_name, dialogue }
+    }
+
+    pub fn get_npc_name(&self) -> &String {
+        &self.npc_name
+    }
+
+    pub fn get_dialogue(&self) -> &Option<String> {
+        &self.dialogue
+    }
+}
+
+/// Returns all characters found on the Interaction layer of a Tiled map.
+pub fn get_npcs_from(tiled_map: &Map) -> Vec<NpcMarker> {
+    let tile_width = tiled_map.tile_width;
+    let tile_height = tiled_map.tile_height;
+
+    let mut npc_markers = Vec::new();
+
+    for z in 0..tiled_map.layers().len() {
+        let is_object_layer = is_object_layer(&tiled_map, z);
+        if !is_object_layer {
+            continue;
+        }
+
+        let layer = tiled_map.get_layer(z).unwrap();
+        if layer.name != "Interaction" {
+            continue;
+        }
+
+        let object_layer = layer.as_object_layer().unwrap();
+
+        for object in object_layer.objects() {
+            if object.user_type != "NPC" {
+                continue;
+            }
+
+            let grid_cords = get_character_grid_cords(&object, tile_width, tile_height, z);
+
+            let properties = &object.properties;
+            let facing = get_facing_from(properties);
+            let dialogue = get_string_property(properties, "Dialogue");
+            let movement_pattern = get_movement_pattern_from(&tiled_map, properties);
+            let flag_conditions = FlagConditions::from_properties(properties);
+
+            let npc = Npc::new(object.name.clone(), dialogue, movement_pattern);
+            npc_markers.push(NpcMarker::new(grid_cords, facing, npc, flag_conditions));
+        }
+    }
+
+    npc_markers
+}
+
+/// Returns the direction a character starts off facing, which is down if none is given.
+fn get_facing_from(properties: &HashMap<String, PropertyValue>) -> MovementDirection {
+    let found_facing = get_string_property(properties, "Facing");
+
+    match found_facing.as_deref() {
+        Some("Up") => MovementDirection::Up,
+        Some("Left") => MovementDirection::Left,
+        Some("Right") => MovementDirection::Right,
+        Some("Down") | None => MovementDirection::Down,
+        Some(facing) => {
+            warn!(
+                "get_facing_from: Unrecognized facing found: {}, facing down instead.",
+                facing
+            );
+            MovementDirection::Down
+        }
+    }
+}
+
+/// Returns how a character moves around on its own, which is not at all if none is given.
+fn get_movement_pattern_from(
+    tiled_map: &Map,
+    properties: &HashMap<String, PropertyValue>,
+) -> MovementPattern {
+    let found_movement = get_string_property(properties, "Movement");
+
+    match found_movement.as_deref() {
+        Some("Stationary") | None => MovementPattern::Stationary,
+        Some("Wander") => {
+            let radius = match properties.get("Radius") {
+                Some(PropertyValue::IntValue(radius)) => *radius as usize,
+                _ => 1,
+            };
+
+            MovementPattern::Wander(radius)
+        }
+        Some("Patrol") => get_string_property(properties, "Path")
+            .and_then(|path_name| get_map_path_from(tiled_map, &path_name, "Path"))
+            .map(MovementPattern::Patrol)
+            .unwrap_or_else(|| {
+                warn!("get_movement_pattern_from: A patrolling character has no Path to follow, so it stands still instead.");
+                MovementPattern::Stationary
+            }),
+        Some("Loop") => get_string_property(properties, "Path")
+            .and_then(|path_name| get_map_path_from(tiled_map, &path_name, "Loop"))
+            .map(MovementPattern::Loop)
+            .unwrap_or_else(|| {
+                warn!("get_movement_pattern_from: A looping character has no Loop to follow, so it stands still instead.");
+                MovementPattern::Stationary
+            }),
+        Some("FacePlayer") => MovementPattern::FacePlayer,
+        Some(movement) => {
+            warn!(
+                "get_movement_pattern_from: Unrecognized movement found: {}, standing still instead.",
+                movement
+            );
+            MovementPattern::Stationary
+        }
+    }
+}
+
+/// Returns the grid coordinates of the Path or Loop object with the given name,
+/// searching every object layer of a Tiled map, or None if there isn't one of the right shape.
+fn get_map_path_from(tiled_map: &Map, path_name: &str, path_type: &str) -> Option<MapPath> {
+    let tile_width = tiled_map.tile_width;
+    let tile_height = tiled_map.tile_height;
+
+    for z in 0..tiled_map.layers().len() {
+        let is_object_layer = is_object_layer(&tiled_map, z);
+        if !is_object_layer {
+            continue;
+        }
+
+        let object_layer = tiled_map.get_layer(z).unwrap().as_object_layer().unwrap();
+
+        let found_path_object = object_layer
+            .objects()
+            .find(|object| object.user_type == path_type && object.name == path_name);
+
+        if found_path_object.is_none() {
+            continue;
+        }
+
+        let path_object = found_path_object.unwrap();
+
+        let path_cords = match (&path_object.shape, path_type) {
+            (ObjectShape::Polyline { points }, "Path") => {
+                get_path_from_points(path_object, points, tile_height, tile_width)
+            }
+            (ObjectShape::Polygon { points }, "Loop") => {
+                get_loop_from_points(path_object, points, tile_height, tile_width)
+            }
+            _ => {
+                warn!(
+                    "get_map_path_from: The {} object {} has the wrong shape.",
+                    path_type, path_name
+                );
+                return None;
+            }
+        };
+
+        let mut map_path = MapPath::new(path_name.to_string());
+        map_path.set_path(path_cords);
+
+        return Some(map_path);
+    }
+
+    warn!(
+        "get_map_path_from: No {} object with name {} found",
+        path_type, path_name
+    );
+    None
+}
+
+/// Returns whether some grid coordinate is taken up by one of the given characters.
+pub fn is_occupied_by_character<'a>(
+    grid_cords: &GridCords3D,
+    mut character_cords: impl Iterator<Item = &'a GridCords3D>,
+) -> bool {
+    // NOTE: Characters can be on different layers than the tiles they walk on,
+    // so like collision, only the x and y values are compared.
+    character_cords.any(|character_cords| {
+        character_cords.get_x() == grid_cords.get_x()
+            && character_cords.get_y() == grid_cords.get_y()
+    })
+}
+
+/// Talks to the character in front of the player, turning them to face the player.
+pub fn interact_with_npc(
+    mut requests_to_interact: MessageReader<PlayerInteraction>,
+    player: Query<(&GridCords3D, &MovementDirection), With<Player>>,
+    mut npcs: Query<
+        (
+            &Npc,
+            &GridCords3D,
+            &mut MovementDirection,
+            Option<&FlagConditions>,
+        ),
+        Without<Player>,
+    >,
+    world: Query<&GridDimensions>,
+    mut npc_interactions: MessageWriter<NpcInteraction>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+) {
+    if requests_to_interact.is_empty() {
+        return;
+    }
+
+    if player.is_empty() {
+        return;
+    }
+
+    if world.is_empty() {
+        return;
+    }
+
+    let (player_grid_cords, player_direction) = player.single().unwrap();
+    let map_grid_dimensions = world.single().unwrap();
+
+    for _ in requests_to_interact.read() {
+        let found_inspected_cords =
+            set_logical_destination(player_grid_cords, map_grid_dimensions, player_direction);
+
+        if found_inspected_cords.is_none() {
+            continue;
+        }
+
+        let inspected_cords = found_inspected_cords.unwrap();
+
+        for (npc, npc_grid_cords, mut npc_direction, flag_conditions) in &mut npcs {
+            if !is_occupied_by_character(&inspected_cords, std::iter::once(npc_grid_cords)) {
+                continue;
+            }
+
+            *npc_direction = player_direction.opposite();
+
+            let interaction =
+                NpcInteraction::new(npc.get_name().clone(), npc.get_dialogue().clone());
+            npc_interactions.write(interaction);
+
+            if let Some(flag_conditions) = flag_conditions {
+                flag_conditions.set_flag_with(&mut set_story_flag_requests);
+            }
+        }
+    }
+}
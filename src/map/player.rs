@@ -6,12 +6,6 @@ pub struct Player;
 #[derive(Default, Message)]
 pub struct PlayerInteraction;
 
-#[derive(Default, Component, Resource)]
-pub struct PlayerBumpChannel;
-
-#[derive(Default, Component, Resource)]
-pub struct PlayerWalkChannel;
-
 #[derive(PartialEq, PartialOrd, Clone, Copy, Message)]
 pub enum PlayerMovementActions {
     Walking,
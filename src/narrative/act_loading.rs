@@ -1,9 +1,15 @@
-use crate::map::interactions::map_changing::ChangeLevel;
-use crate::narrative::acts::{Act, SceneContents};
+use crate::loading::{hold_back_until_loaded, PendingLoad, PendingLoads};
+use crate::map::camera::{PanCamera, PanTarget};
+use crate::map::interactions::map_changing::{ChangeLevel, CurrentLevel};
+use crate::map::interactions::triggers::{TriggerAction, TriggerFired};
+use crate::narrative::acts::{Act, ImageCutsceneSettings, ImageTransition, SceneContents};
+use crate::narrative::image_layouts::add_image_layout;
+use crate::narrative::story_flags::SetStoryFlag;
 use crate::plugins::acts::{FadeDuration, MapsFolderPath};
-use crate::{map::interactions::map_changing::CameraBundle, ui::menus::ImageNodeBundle};
+use crate::{map::interactions::map_changing::CameraBundle, ui::menus::ImageNodeBundle, AppState};
 use bevy::prelude::*;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use super::acts::ActLoader;
 
@@ -11,15 +17,74 @@ use super::acts::ActLoader;
 #[derive(Component)]
 pub struct SceneUI;
 
-/// Timer for fading Image Cutscenes
+/// Timer for transitioning into Image Cutscenes
 #[derive(Component)]
 pub struct FadeTimer {
     timer: Timer,
+    transition: ImageTransition,
+    skippable: bool,
 }
 
 impl FadeTimer {
-    pub fn new(fade_duration: &FadeDuration) -> Self {
-        let timer = Timer::new(fade_duration.get_duration(), TimerMode::Once);
+    /// Times the transition a scene's settings ask for, taking the default fade duration
+    /// when they don't give one of their own.
+    pub fn new(
+        image_cutscene_settings: &ImageCutsceneSettings,
+        fade_duration: &FadeDuration,
+    ) -> Self {
+        let transition = image_cutscene_settings.get_transition();
+        let duration = match transition {
+            ImageTransition::Cut => Duration::ZERO,
+            _ => image_cutscene_settings
+                .get_transition_duration()
+                .unwrap_or(fade_duration.get_duration()),
+        };
+
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            transition,
+            skippable: image_cutscene_settings.is_skippable(),
+        }
+    }
+
+    pub fn get_timer(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+
+    pub fn get_transition(&self) -> ImageTransition {
+        self.transition
+    }
+
+    pub fn is_skippable(&self) -> bool {
+        self.skippable
+    }
+
+    /// Returns how far along the transition is, from 0 to 1.
+    pub fn get_progress(&self) -> f32 {
+        if self.timer.duration().is_zero() {
+            return 1.0;
+        }
+
+        self.timer.fraction()
+    }
+
+    /// Finishes the transition right away.
+    pub fn skip(&mut self) {
+        let remaining = self.timer.remaining();
+        self.timer.tick(remaining);
+    }
+}
+
+/// Timer for moving on from an Image Cutscene without player input,
+/// which starts once the image has been fully transitioned into
+#[derive(Component)]
+pub struct AutoAdvanceTimer {
+    timer: Timer,
+}
+
+impl AutoAdvanceTimer {
+    pub fn new(auto_advance_delay: Duration) -> Self {
+        let timer = Timer::new(auto_advance_delay, TimerMode::Once);
 
         Self { timer }
     }
@@ -64,10 +129,24 @@ impl LoadNextScene {
     }
 }
 
-/// Loads initial act of the game
-pub fn load_starting_act(mut load_act_broadcaster: MessageWriter<LoadAct>) {
-    let starting_act = LoadAct::new("assets/acts/introductory_act.json");
-    load_act_broadcaster.write(starting_act);
+/// How long the camera takes to pan over to where a map cutscene's action starts.
+const CUTSCENE_PAN_DURATION: Duration = Duration::from_secs(1);
+/// How long the camera stays on where a map cutscene's action starts before following the player again.
+const CUTSCENE_PAN_HOLD: Duration = Duration::from_secs(2);
+
+/// Where the act the game starts with is found.
+pub const STARTING_ACT_PATH: &str = "assets/acts/introductory_act.json";
+
+/// Loads an act when a trigger asks for it.
+pub fn load_act_from_trigger(
+    mut fired_triggers: MessageReader<TriggerFired>,
+    mut load_act_broadcaster: MessageWriter<LoadAct>,
+) {
+    for fired_trigger in fired_triggers.read() {
+        if let TriggerAction::StartAct(act_path) = fired_trigger.get_action() {
+            load_act_broadcaster.write(LoadAct::new(act_path));
+        }
+    }
 }
 
 pub fn load_act(
@@ -75,20 +154,32 @@ pub fn load_act(
     mut commands: Commands,
     loaded_act: Query<Entity, With<Act>>,
     maps_path_folder: Res<MapsFolderPath>,
+    mut pending_loads: Option<ResMut<PendingLoads>>,
+    mut next_state: ResMut<NextState<AppState>>,
 ) {
     if load_act_requests.is_empty() {
         return;
     }
 
+    let load_act_request = load_act_requests.read().next().unwrap();
+    let act_file_path = PathBuf::from(load_act_request.get_act_file_path());
+
+    // The act carries on as it is until the next one's assets have loaded.
+    let is_held_back = hold_back_until_loaded(
+        pending_loads.as_deref_mut(),
+        &mut next_state,
+        PendingLoad::Act(act_file_path.clone()),
+    );
+    if is_held_back {
+        return;
+    }
+
     if loaded_act.iter().next().is_some() {
         for entity in loaded_act.iter() {
             commands.entity(entity).despawn();
         }
     }
 
-    let load_act_request = load_act_requests.read().next().unwrap();
-
-    let act_file_path = PathBuf::from(load_act_request.get_act_file_path());
     let maps_folder = maps_path_folder.get_path();
 
     let act_loader = ActLoader::new(act_file_path, maps_folder);
@@ -100,18 +191,29 @@ pub fn load_act(
     commands.spawn(the_camera);
 }
 
+/// Removes the loaded act and whatever cutscene images it is showing.
+pub fn unload_act(
+    loaded_act: Query<Entity, With<Act>>,
+    scene_ui: Query<Entity, With<SceneUI>>,
+    mut commands: Commands,
+) {
+    for entity in loaded_act.iter().chain(scene_ui.iter()) {
+        commands.entity(entity).despawn();
+    }
+}
+
 /// Render an Image Cutscene into the game
 pub fn render_image_cutscene(
     asset_server: Res<AssetServer>,
     fade_duration: Res<FadeDuration>,
     mut commands: Commands,
-    current_act: Single<(&Act, Ref<Act>)>,
+    current_act: Single<Ref<Act>>,
 ) {
-    if !current_act.1.is_changed() {
+    if !current_act.is_changed() {
         return;
     }
 
-    let current_scene = current_act.0.get_current_scene();
+    let current_scene = current_act.get_current_scene();
 
     if let SceneContents::ImageCutscene(image_path) = current_scene.get_scene_contents() {
         let node = create_full_screen_node();
@@ -126,27 +228,34 @@ pub fn render_image_cutscene(
         let ui_container = (ImageNodeBundle::from_nodes(node, image), SceneUI);
 
         // Create Timer Component
-        let fade_timer = FadeTimer::new(&fade_duration);
+        let image_cutscene_settings = current_scene.get_image_cutscene_settings();
+        let fade_timer = FadeTimer::new(image_cutscene_settings, &fade_duration);
 
-        let z_index = ZIndex(!current_act.1.is_added() as i32);
+        let z_index = ZIndex(!current_act.is_added() as i32);
 
-        commands
-            .spawn(ui_container)
-            .insert(z_index)
-            .insert(fade_timer);
+        let mut image_entity = commands.spawn(ui_container);
+        image_entity.insert(z_index).insert(fade_timer);
+
+        if let Some(auto_advance_delay) = image_cutscene_settings.get_auto_advance_delay() {
+            image_entity.insert(AutoAdvanceTimer::new(auto_advance_delay));
+        }
+
+        if let Some(layout) = current_scene.get_image_layout() {
+            add_image_layout(&mut image_entity, &asset_server, layout.clone());
+        }
     }
 }
 
 /// Render a Map Cutscene into the game
 pub fn render_map_cutscene(
-    current_act: Single<(&Act, Ref<Act>)>,
+    current_act: Single<Ref<Act>>,
     mut load_level_broadcaster: MessageWriter<ChangeLevel>,
 ) {
-    if !current_act.1.is_changed() {
+    if !current_act.is_changed() {
         return;
     }
 
-    let current_scene = current_act.0.get_current_scene();
+    let current_scene = current_act.get_current_scene();
 
     if let SceneContents::MapCutscene(map_path, map_actions) = current_scene.get_scene_contents() {
         let level_name = map_path.to_str().unwrap();
@@ -156,6 +265,59 @@ pub fn render_map_cutscene(
     }
 }
 
+/// Pans the camera over to where a map cutscene's action starts once its level has loaded,
+/// so whatever happens away from the player is framed.
+pub fn pan_to_map_cutscene(
+    current_level: Option<Res<CurrentLevel>>,
+    current_act: Single<&Act>,
+    mut pan_requests: MessageWriter<PanCamera>,
+) {
+    let Some(current_level) = current_level else {
+        return;
+    };
+    if !current_level.is_changed() {
+        return;
+    }
+
+    let current_scene = current_act.get_current_scene();
+    let SceneContents::MapCutscene(map_path, map_actions) = current_scene.get_scene_contents()
+    else {
+        return;
+    };
+
+    // The level may have been changed by something other than the cutscene.
+    if map_path != current_level.get_level_path() {
+        return;
+    }
+
+    let found_starting_cords = map_actions
+        .iter()
+        .flat_map(|map_action| map_action.get_instructions())
+        .find_map(|map_instruction| map_instruction.get_starting_cords());
+
+    if let Some(starting_cords) = found_starting_cords {
+        let pan_target = PanTarget::Tile(starting_cords.clone());
+        pan_requests
+            .write(PanCamera::new(pan_target, CUTSCENE_PAN_DURATION).with_hold(CUTSCENE_PAN_HOLD));
+    }
+}
+
+/// Sets the story flags of a scene as soon as it's entered
+pub fn set_flags_on_scene_entry(
+    current_act: Single<Ref<Act>>,
+    mut set_story_flag_requests: MessageWriter<SetStoryFlag>,
+) {
+    if !current_act.is_changed() {
+        return;
+    }
+
+    let current_scene = current_act.get_current_scene();
+
+    for (flag_name, value) in current_scene.get_flags_on_entry() {
+        set_story_flag_requests.write(SetStoryFlag::new(flag_name, value.clone()));
+    }
+}
+
 /// Renders the next scene into the game from the current act
 pub fn load_next_scene(
     mut load_next_scene_requests: MessageReader<LoadNextScene>,
@@ -175,17 +337,41 @@ pub fn load_next_scene(
     current_act.move_to_next_scene();
 }
 
+/// Plays the transition into a newly rendered image, over the image before it
 pub fn fade_into(
-    mut query: Query<(&mut ImageNode, &mut FadeTimer)>,
+    mut query: Query<(&mut ImageNode, &mut Node, &mut FadeTimer)>,
+    mut previous_images: Query<&mut ImageNode, (With<SceneUI>, Without<FadeTimer>)>,
     time: Res<Time>,
     mut despawn_image_broadcaster: MessageWriter<ImageDespawn>,
 ) {
-    for (mut image_node, mut fade_timer) in query.iter_mut() {
+    for (mut image_node, mut node, mut fade_timer) in query.iter_mut() {
         fade_timer.get_timer().tick(time.delta());
 
-        image_node
-            .color
-            .set_alpha(fade_timer.get_timer().fraction());
+        let progress = fade_timer.get_progress();
+
+        match fade_timer.get_transition() {
+            ImageTransition::Cut | ImageTransition::Crossfade => {
+                image_node.color.set_alpha(progress)
+            }
+            ImageTransition::FadeThroughBlack => {
+                // The image before darkens over the first half,
+                // then the new image brightens out of black over the second.
+                if progress < 0.5 {
+                    let brightness = 1.0 - progress * 2.0;
+                    for mut previous_image in previous_images.iter_mut() {
+                        previous_image.color = Color::srgb(brightness, brightness, brightness);
+                    }
+                    image_node.color.set_alpha(0.0);
+                } else {
+                    let brightness = progress * 2.0 - 1.0;
+                    image_node.color = Color::srgb(brightness, brightness, brightness);
+                }
+            }
+            ImageTransition::Slide => {
+                image_node.color.set_alpha(1.0);
+                node.left = Val::Percent((1.0 - progress) * 100.0);
+            }
+        }
 
         if fade_timer.get_timer().is_finished() {
             despawn_image_broadcaster.write(ImageDespawn::new());
@@ -229,11 +415,37 @@ pub fn create_full_screen_node() -> Node {
     }
 }
 
-/// Progresses to the next image cutscene on any key or mouse button press
+/// Moves on from an image cutscene on its own once its auto advance delay has passed
+pub fn auto_advance_scenes(
+    mut auto_advancing_images: Query<(Entity, &mut AutoAdvanceTimer), Without<FadeTimer>>,
+    fading_images: Query<(), With<FadeTimer>>,
+    time: Res<Time>,
+    mut load_next_scene_broadcaster: MessageWriter<LoadNextScene>,
+    mut commands: Commands,
+) {
+    // An image still being transitioned out of shouldn't move the act along.
+    if !fading_images.is_empty() {
+        return;
+    }
+
+    for (entity, mut auto_advance_timer) in auto_advancing_images.iter_mut() {
+        auto_advance_timer.get_timer().tick(time.delta());
+
+        if auto_advance_timer.get_timer().is_finished() {
+            load_next_scene_broadcaster.write(LoadNextScene::new());
+            commands.entity(entity).remove::<AutoAdvanceTimer>();
+        }
+    }
+}
+
+/// Progresses to the next image cutscene on any key or mouse button press.
+///
+/// Pressing during a transition finishes it instead, if the scene lets it be skipped.
 pub fn load_next_scene_on_player_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     current_act_query: Query<&mut Act>,
+    mut fading_images: Query<&mut FadeTimer>,
     mut load_next_scene_broadcaster: MessageWriter<LoadNextScene>,
 ) {
     let found_loaded_act = current_act_query.iter().next();
@@ -246,11 +458,24 @@ pub fn load_next_scene_on_player_input(
     let current_scene = current_act.get_current_scene();
     let current_scene_type = current_scene.get_scene_contents();
 
-    if matches!(current_scene_type, SceneContents::ImageCutscene(_)) {
-        if keyboard_input.get_just_pressed().next().is_some()
-            || mouse_button_input.get_just_pressed().next().is_some()
-        {
-            load_next_scene_broadcaster.write(LoadNextScene::new());
+    if !matches!(current_scene_type, SceneContents::ImageCutscene(_)) {
+        return;
+    }
+
+    if keyboard_input.get_just_pressed().next().is_none()
+        && mouse_button_input.get_just_pressed().next().is_none()
+    {
+        return;
+    }
+
+    if fading_images.is_empty() {
+        load_next_scene_broadcaster.write(LoadNextScene::new());
+        return;
+    }
+
+    for mut fade_timer in fading_images.iter_mut() {
+        if fade_timer.is_skippable() {
+            fade_timer.skip();
         }
     }
 }
@@ -1,4 +1,5 @@
 use bevy::ecs::component::Component;
+use bevy::log::warn;
 use regex::Regex;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -8,8 +9,11 @@ use std::path::PathBuf;
 use std::time::Duration;
 use tiled::ObjectShape;
 
+use crate::audio::music::get_music_path;
 use crate::map::interactions::map_changing::load_tiled_map;
 use crate::map::{is_object_layer, GridCords2D};
+use crate::narrative::image_layouts::ImageCutsceneLayout;
+use crate::narrative::story_flags::StoryFlag;
 
 #[derive(Clone)]
 pub struct SceneNode {
@@ -36,6 +40,10 @@ pub struct Scene {
     title: String,
     scene_type: SceneType,
     scene_contents: SceneContents,
+    flags_on_entry: Vec<(String, StoryFlag)>,
+    image_cutscene_settings: ImageCutsceneSettings,
+    image_layout: Option<ImageCutsceneLayout>,
+    music: Option<PathBuf>,
 }
 impl Scene {
     pub fn make_scene(title: String, scene_type: SceneType, scene_contents: SceneContents) -> Self {
@@ -43,9 +51,40 @@ impl Scene {
             title,
             scene_type,
             scene_contents,
+            flags_on_entry: Vec::new(),
+            image_cutscene_settings: ImageCutsceneSettings::default(),
+            image_layout: None,
+            music: None,
         }
     }
 
+    /// Returns the scene, setting the given story flags whenever it's entered.
+    pub fn with_flags_on_entry(mut self, flags_on_entry: Vec<(String, StoryFlag)>) -> Self {
+        self.flags_on_entry = flags_on_entry;
+        self
+    }
+
+    /// Returns the scene, showing its image the way the given settings describe.
+    pub fn with_image_cutscene_settings(
+        mut self,
+        image_cutscene_settings: ImageCutsceneSettings,
+    ) -> Self {
+        self.image_cutscene_settings = image_cutscene_settings;
+        self
+    }
+
+    /// Returns the scene, layering, panning and zooming its image the way the given layout describes.
+    pub fn with_image_layout(mut self, image_layout: ImageCutsceneLayout) -> Self {
+        self.image_layout = Some(image_layout);
+        self
+    }
+
+    /// Returns the scene, playing the given track over the music of whatever level is loaded.
+    pub fn with_music(mut self, music: PathBuf) -> Self {
+        self.music = Some(music);
+        self
+    }
+
     pub fn get_title(&self) -> String {
         self.title.clone()
     }
@@ -57,6 +96,122 @@ impl Scene {
     pub fn get_scene_type(&self) -> &SceneType {
         &self.scene_type
     }
+
+    pub fn get_flags_on_entry(&self) -> &Vec<(String, StoryFlag)> {
+        &self.flags_on_entry
+    }
+
+    pub fn get_image_cutscene_settings(&self) -> &ImageCutsceneSettings {
+        &self.image_cutscene_settings
+    }
+
+    pub fn get_image_layout(&self) -> Option<&ImageCutsceneLayout> {
+        self.image_layout.as_ref()
+    }
+
+    pub fn get_music(&self) -> Option<&PathBuf> {
+        self.music.as_ref()
+    }
+}
+
+/// How an image cutscene's image takes over from the one before it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageTransition {
+    /// The image shows up right away.
+    Cut,
+    /// The image fades in over the one before it.
+    #[default]
+    Crossfade,
+    /// The image before it fades to black, then the image fades in out of the black.
+    FadeThroughBlack,
+    /// The image slides in from the right, over the one before it.
+    Slide,
+}
+
+impl ImageTransition {
+    /// Returns a transition from its name in Arcweave, such as "Crossfade" or "Fade Through Black".
+    pub fn parse_from(transition_name: &str) -> Option<Self> {
+        // Writers aren't held to any one way of spacing or capitalizing the name.
+        let transition_name: String = transition_name
+            .chars()
+            .filter(|character| character.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+
+        match transition_name.as_str() {
+            "cut" => Some(ImageTransition::Cut),
+            "crossfade" => Some(ImageTransition::Crossfade),
+            "fadethroughblack" => Some(ImageTransition::FadeThroughBlack),
+            "slide" => Some(ImageTransition::Slide),
+            _ => None,
+        }
+    }
+}
+
+/// How an image cutscene plays out, set per scene through Arcweave attributes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageCutsceneSettings {
+    transition: ImageTransition,
+    transition_duration: Option<Duration>,
+    auto_advance_delay: Option<Duration>,
+    skippable: bool,
+}
+
+impl Default for ImageCutsceneSettings {
+    fn default() -> Self {
+        Self {
+            transition: ImageTransition::default(),
+            transition_duration: None,
+            auto_advance_delay: None,
+            skippable: true,
+        }
+    }
+}
+
+impl ImageCutsceneSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transition(mut self, transition: ImageTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Returns the settings, taking the given time to transition rather than the default fade duration.
+    pub fn with_transition_duration(mut self, transition_duration: Duration) -> Self {
+        self.transition_duration = Some(transition_duration);
+        self
+    }
+
+    /// Returns the settings, moving on to the next scene on its own once the image
+    /// has been fully shown for the given time.
+    pub fn with_auto_advance_delay(mut self, auto_advance_delay: Duration) -> Self {
+        self.auto_advance_delay = Some(auto_advance_delay);
+        self
+    }
+
+    /// Returns the settings, letting player input finish the transition early or not.
+    pub fn with_skippable(mut self, skippable: bool) -> Self {
+        self.skippable = skippable;
+        self
+    }
+
+    pub fn get_transition(&self) -> ImageTransition {
+        self.transition
+    }
+
+    pub fn get_transition_duration(&self) -> Option<Duration> {
+        self.transition_duration
+    }
+
+    pub fn get_auto_advance_delay(&self) -> Option<Duration> {
+        self.auto_advance_delay
+    }
+
+    pub fn is_skippable(&self) -> bool {
+        self.skippable
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -83,6 +238,19 @@ pub enum MapInstruction {
     Loop(Character, MapPath),
 }
 
+impl MapInstruction {
+    /// Returns the tile an instruction starts playing out at, if it plays out anywhere.
+    pub fn get_starting_cords(&self) -> Option<&GridCords2D> {
+        match self {
+            MapInstruction::Wait(_) => None,
+            MapInstruction::Place(_, location) => Some(location.get_cords()),
+            MapInstruction::Move(_, path) | MapInstruction::Loop(_, path) => {
+                path.get_path().first()
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Character {
     name: String,
@@ -238,6 +406,10 @@ impl Act {
         }
     }
 
+    pub fn get_scenes(&self) -> &Vec<Scene> {
+        &self.scenes
+    }
+
     pub fn get_current_scene(&self) -> &Scene {
         &self.scenes.get(self.current_scene_idx).expect(
             &format!(
@@ -313,15 +485,23 @@ impl Act {
 pub struct ActLoader {
     arcweave_act_json: Value,
     maps_folder: PathBuf,
+    images_folder: PathBuf,
 }
 
 impl ActLoader {
     pub fn new(act_file: PathBuf, maps_folder: PathBuf) -> Self {
+        // Image cutscene images, and the layouts written next to them,
+        // sit in an images folder alongside the act | ex. assets/acts/images
+        let images_folder = act_file
+            .parent()
+            .map(|act_folder| act_folder.join("images"))
+            .unwrap_or_else(|| PathBuf::from("images"));
         let arcweave_act_json = load_json_file(act_file);
 
         Self {
             arcweave_act_json,
             maps_folder,
+            images_folder,
         }
     }
 
@@ -372,10 +552,161 @@ impl ActLoader {
             self.maps_folder.clone(),
         );
 
-        let scene = Scene::make_scene(title, scene_type, scene_contents);
+        let flags_on_entry = self.get_flags_on_entry_from_id(&id);
+        let image_cutscene_settings = self.get_image_cutscene_settings_from_id(&id);
+
+        let mut scene = Scene::make_scene(title, scene_type, scene_contents)
+            .with_flags_on_entry(flags_on_entry)
+            .with_image_cutscene_settings(image_cutscene_settings);
+
+        // Scenes name a track in the music folder | ex. Music: village_theme
+        if let Some(track_name) = self.get_attribute_value_from_id(&id, "Music") {
+            scene = scene.with_music(get_music_path(&track_name));
+        }
+
+        // Layouts are read once here, rather than every time the scene is shown.
+        if let SceneContents::ImageCutscene(image_path) = scene.get_scene_contents() {
+            if let Some(layout) = ImageCutsceneLayout::find_for(&self.images_folder, image_path) {
+                scene = scene.with_image_layout(layout);
+            }
+        }
         SceneNode::make_scene_node(id, scene)
     }
 
+    /// Gets the story flags an Arcweave node sets, written into its "Sets Flags" attribute
+    /// as a comma separated list | ex. met_oma, acorns = 3, mood = grumpy
+    fn get_flags_on_entry_from_id(&self, id: &String) -> Vec<(String, StoryFlag)> {
+        let mut flags_on_entry = Vec::new();
+
+        for flag_list in self.get_attribute_values_from_id(id, "Sets Flags") {
+            flags_on_entry.extend(parse_flag_list(&flag_list));
+        }
+
+        flags_on_entry
+    }
+
+    /// Gets how an Arcweave node's image cutscene plays out from its "Transition",
+    /// "Transition Duration", "Auto Advance" and "Skippable" attributes.
+    /// Durations are written in seconds | ex. Transition Duration: 1.5
+    fn get_image_cutscene_settings_from_id(&self, id: &String) -> ImageCutsceneSettings {
+        let mut image_cutscene_settings = ImageCutsceneSettings::new();
+
+        if let Some(transition_name) = self.get_attribute_value_from_id(id, "Transition") {
+            match ImageTransition::parse_from(&transition_name) {
+                Some(transition) => {
+                    image_cutscene_settings = image_cutscene_settings.with_transition(transition)
+                }
+                None => warn!(
+                    "get_image_cutscene_settings_from_id: Unknown transition '{}' for item {}",
+                    transition_name, id
+                ),
+            }
+        }
+
+        if let Some(transition_duration) =
+            self.get_duration_attribute_from_id(id, "Transition Duration")
+        {
+            image_cutscene_settings =
+                image_cutscene_settings.with_transition_duration(transition_duration);
+        }
+
+        if let Some(auto_advance_delay) = self.get_duration_attribute_from_id(id, "Auto Advance") {
+            image_cutscene_settings =
+                image_cutscene_settings.with_auto_advance_delay(auto_advance_delay);
+        }
+
+        if let Some(skippable) = self.get_attribute_value_from_id(id, "Skippable") {
+            match skippable.trim().to_lowercase().parse::<bool>() {
+                Ok(skippable) => {
+                    image_cutscene_settings = image_cutscene_settings.with_skippable(skippable)
+                }
+                Err(_) => warn!(
+                    "get_image_cutscene_settings_from_id: Skippable should be true or false for item {}, not '{}'",
+                    id, skippable
+                ),
+            }
+        }
+
+        image_cutscene_settings
+    }
+
+    /// Gets an attribute of an Arcweave node written as some number of seconds
+    fn get_duration_attribute_from_id(
+        &self,
+        id: &String,
+        attribute_name: &str,
+    ) -> Option<Duration> {
+        let seconds = self.get_attribute_value_from_id(id, attribute_name)?;
+
+        match seconds.trim().parse::<f32>() {
+            Ok(seconds) if seconds >= 0.0 => Some(Duration::from_secs_f32(seconds)),
+            _ => {
+                warn!(
+                    "get_duration_attribute_from_id: {} should be a number of seconds for item {}, not '{}'",
+                    attribute_name, id, seconds
+                );
+                None
+            }
+        }
+    }
+
+    /// Gets the first value of an Arcweave node's attribute with the given name
+    fn get_attribute_value_from_id(&self, id: &String, attribute_name: &str) -> Option<String> {
+        self.get_attribute_values_from_id(id, attribute_name)
+            .into_iter()
+            .next()
+    }
+
+    /// Gets the values of every attribute of an Arcweave node with the given name,
+    /// with Arcweave's HTML tags stripped out
+    fn get_attribute_values_from_id(&self, id: &String, attribute_name: &str) -> Vec<String> {
+        let mut attribute_values = Vec::new();
+
+        // Not every node has attributes, so a missing list means there are no values.
+        let found_attribute_ids = self
+            .arcweave_act_json
+            .get("elements")
+            .and_then(|elements| elements.get(&id))
+            .and_then(|element| element.get("attributes"))
+            .and_then(|attributes| attributes.as_array());
+        if found_attribute_ids.is_none() {
+            return attribute_values;
+        }
+
+        for attribute_id in found_attribute_ids.unwrap() {
+            let attribute_id = get_string_from_json_value(attribute_id);
+            let found_attribute = self
+                .arcweave_act_json
+                .get("attributes")
+                .and_then(|attributes| attributes.get(&attribute_id));
+            if found_attribute.is_none() {
+                continue;
+            }
+            let attribute = found_attribute.unwrap();
+
+            let is_named_attribute = attribute
+                .get("name")
+                .and_then(|name| name.as_str())
+                .is_some_and(|name| name == attribute_name);
+            if !is_named_attribute {
+                continue;
+            }
+
+            let attribute_value = attribute
+                .get("value")
+                .and_then(|value| value.get("data"))
+                .and_then(|data| data.as_str())
+                .expect(&format!(
+                    "get_attribute_values_from_id: Unable to get {} for item {}",
+                    attribute_name, id
+                ));
+
+            attribute_values.push(strip_html_tags_simple(attribute_value.to_string()));
+        }
+
+        attribute_values
+    }
+
     /// Gets an Arcweave nodes type name
     fn get_scene_type_from_id(&self, id: &String) -> SceneType {
         // Array(Vec<Value>)
@@ -818,7 +1149,7 @@ impl MapCutsceneLoader {
                                 ));
 
                             if let ObjectShape::Polyline { points } = &move_path_object.shape {
-                                let new_map_path_vec = self.get_path_from_points(
+                                let new_map_path_vec = get_path_from_points(
                                     move_path_object,
                                     points,
                                     tile_height,
@@ -844,7 +1175,7 @@ impl MapCutsceneLoader {
                                 ));
 
                             if let ObjectShape::Polygon { points } = &loop_path_object.shape {
-                                let new_map_path_vec = self.get_loop_from_points(
+                                let new_map_path_vec = get_loop_from_points(
                                     loop_path_object,
                                     points,
                                     tile_height,
@@ -870,122 +1201,122 @@ impl MapCutsceneLoader {
         }
         complete_map_actions
     }
+}
 
-    fn get_path_from_points(
-        &self,
-        move_path_object: tiled::Object<'_>,
-        points: &[(f32, f32)],
-        tile_height: u32,
-        tile_width: u32,
-    ) -> Vec<GridCords2D> {
-        let tile_width_float = tile_width as f32;
-        let tile_height_float = tile_height as f32;
-
-        let mut final_path = self.calculate_point_path(
-            points,
-            move_path_object,
-            tile_width_float,
-            tile_height_float,
-        );
-
-        // The final point is added to finish the path
-        let last_point_x = (move_path_object.x + points[points.len() - 1].0) / tile_width_float;
-        let last_point_y = (move_path_object.y + points[points.len() - 1].1) / tile_height_float;
-        let last_point = GridCords2D::new(last_point_x as usize, last_point_y as usize);
-        final_path.push(last_point);
-
-        final_path
-    }
-
-    fn get_loop_from_points(
-        &self,
-        loop_path_object: tiled::Object<'_>,
-        points: &[(f32, f32)],
-        tile_height: u32,
-        tile_width: u32,
-    ) -> Vec<GridCords2D> {
-        let tile_width_float = tile_width as f32;
-        let tile_height_float = tile_height as f32;
-
-        // A looping path is defined by ending where it began, so we append
-        // the first point to the end to close the loop
-        let mut new_points = points.to_vec();
-        new_points.push(points[0]);
-        let new_points_slice = &new_points[..];
-
-        let final_path = self.calculate_point_path(
-            new_points_slice,
-            loop_path_object,
-            tile_width_float,
-            tile_height_float,
-        );
+/// Returns the grid coordinates walked along a Tiled polyline, including its final point.
+pub fn get_path_from_points(
+    move_path_object: tiled::Object<'_>,
+    points: &[(f32, f32)],
+    tile_height: u32,
+    tile_width: u32,
+) -> Vec<GridCords2D> {
+    let tile_width_float = tile_width as f32;
+    let tile_height_float = tile_height as f32;
+
+    let mut final_path = calculate_point_path(
+        points,
+        move_path_object,
+        tile_width_float,
+        tile_height_float,
+    );
+
+    // The final point is added to finish the path
+    let last_point_x = (move_path_object.x + points[points.len() - 1].0) / tile_width_float;
+    let last_point_y = (move_path_object.y + points[points.len() - 1].1) / tile_height_float;
+    let last_point = GridCords2D::new(last_point_x as usize, last_point_y as usize);
+    final_path.push(last_point);
+
+    final_path
+}
 
-        final_path
-    }
+/// Returns the grid coordinates walked along a Tiled polygon, ending where it began.
+pub fn get_loop_from_points(
+    loop_path_object: tiled::Object<'_>,
+    points: &[(f32, f32)],
+    tile_height: u32,
+    tile_width: u32,
+) -> Vec<GridCords2D> {
+    let tile_width_float = tile_width as f32;
+    let tile_height_float = tile_height as f32;
+
+    // A looping path is defined by ending where it began, so we append
+    // the first point to the end to close the loop
+    let mut new_points = points.to_vec();
+    new_points.push(points[0]);
+    let new_points_slice = &new_points[..];
+
+    let final_path = calculate_point_path(
+        new_points_slice,
+        loop_path_object,
+        tile_width_float,
+        tile_height_float,
+    );
+
+    final_path
+}
 
-    fn calculate_point_path(
-        &self,
-        points: &[(f32, f32)],
-        move_path_object: tiled::Object<'_>,
-        tile_width: f32,
-        tile_height: f32,
-    ) -> Vec<GridCords2D> {
-        let origin_x = move_path_object.x;
-        let origin_y = move_path_object.y;
-        let mut final_path = Vec::new();
-
-        let line_segments = points.len() - 1;
-        for i in 0..line_segments {
-            let from_point_x = (origin_x + points[i].0) / tile_width;
-            let from_point_y = (origin_y + points[i].1) / tile_height;
-            let from_point = GridCords2D::new(from_point_x as usize, from_point_y as usize);
-
-            let to_point_x = (origin_x + points[i + 1].0) / tile_width;
-            let to_point_y = (origin_y + points[i + 1].1) / tile_height;
-            let to_point = GridCords2D::new(to_point_x as usize, to_point_y as usize);
-
-            let x_diff = to_point.get_x() as isize - from_point.get_x() as isize;
-            let y_diff = to_point.get_y() as isize - from_point.get_y() as isize;
-
-            let absolute_x_diff = x_diff.abs();
-            let absolute_y_diff = y_diff.abs();
-
-            if absolute_x_diff > 0 {
-                if x_diff > 0 {
-                    // pos x movement
-                    for i in 0..absolute_x_diff {
-                        let tile =
-                            GridCords2D::new(from_point.get_x() + i as usize, from_point.get_y());
-                        final_path.push(tile);
-                    }
-                } else if x_diff < 0 {
-                    // neg x movement
-                    for i in 0..absolute_x_diff {
-                        let tile =
-                            GridCords2D::new(from_point.get_x() - i as usize, from_point.get_y());
-                        final_path.push(tile);
-                    }
+/// Returns the grid coordinates covered by each line segment between some Tiled points.
+pub fn calculate_point_path(
+    points: &[(f32, f32)],
+    move_path_object: tiled::Object<'_>,
+    tile_width: f32,
+    tile_height: f32,
+) -> Vec<GridCords2D> {
+    let origin_x = move_path_object.x;
+    let origin_y = move_path_object.y;
+    let mut final_path = Vec::new();
+
+    let line_segments = points.len() - 1;
+    for i in 0..line_segments {
+        let from_point_x = (origin_x + points[i].0) / tile_width;
+        let from_point_y = (origin_y + points[i].1) / tile_height;
+        let from_point = GridCords2D::new(from_point_x as usize, from_point_y as usize);
+
+        let to_point_x = (origin_x + points[i + 1].0) / tile_width;
+        let to_point_y = (origin_y + points[i + 1].1) / tile_height;
+        let to_point = GridCords2D::new(to_point_x as usize, to_point_y as usize);
+
+        let x_diff = to_point.get_x() as isize - from_point.get_x() as isize;
+        let y_diff = to_point.get_y() as isize - from_point.get_y() as isize;
+
+        let absolute_x_diff = x_diff.abs();
+        let absolute_y_diff = y_diff.abs();
+
+        if absolute_x_diff > 0 {
+            if x_diff > 0 {
+                // pos x movement
+                for i in 0..absolute_x_diff {
+                    let tile =
+                        GridCords2D::new(from_point.get_x() + i as usize, from_point.get_y());
+                    final_path.push(tile);
                 }
-            } else if absolute_y_diff > 0 {
-                if y_diff > 0 {
-                    // pos y movement
-                    for i in 0..absolute_y_diff {
-                        let tile =
-                            GridCords2D::new(from_point.get_x(), from_point.get_y() + i as usize);
-                        final_path.push(tile);
-                    }
-                } else if y_diff < 0 {
-                    // neg y movement
-                    for i in 0..absolute_y_diff {
-                        let tile =
-                            GridCords2D::new(from_point.get_x(), from_point.get_y() - i as usize);
-                        final_path.push(tile);
-                    }
+            } else if x_diff < 0 {
+                // neg x movement
+                for i in 0..absolute_x_diff {
+                    let tile =
+                        GridCords2D::new(from_point.get_x() - i as usize, from_point.get_y());
+                    final_path.push(tile);
+                }
+            }
+        } else if absolute_y_diff > 0 {
+            if y_diff > 0 {
+                // pos y movement
+                for i in 0..absolute_y_diff {
+                    let tile =
+                        GridCords2D::new(from_point.get_x(), from_point.get_y() + i as usize);
+                    final_path.push(tile);
+                }
+            } else if y_diff < 0 {
+                // neg y movement
+                for i in 0..absolute_y_diff {
+                    let tile =
+                        GridCords2D::new(from_point.get_x(), from_point.get_y() - i as usize);
+                    final_path.push(tile);
                 }
             }
         }
-        final_path
     }
+    final_path
 }
 
 /// Modified version of from_reader example of serde_json
@@ -1023,6 +1354,26 @@ fn get_title_from_id(act: &Value, id: &String) -> String {
     strip_html_tags_simple(title)
 }
 
+/// Parses a comma separated list of story flags, where flags without a value are set to true.
+pub fn parse_flag_list(flag_list: &str) -> Vec<(String, StoryFlag)> {
+    let mut flags = Vec::new();
+
+    for flag in flag_list.split(',') {
+        let (flag_name, value) = match flag.split_once('=') {
+            Some((flag_name, value)) => (flag_name.trim(), StoryFlag::parse_from(value)),
+            None => (flag.trim(), StoryFlag::Bool(true)),
+        };
+
+        if flag_name.is_empty() {
+            continue;
+        }
+
+        flags.push((flag_name.to_string(), value));
+    }
+
+    flags
+}
+
 /// Removes HTML tags added by Arcweave | ex. <p>text<\/p>
 pub fn strip_html_tags_simple(line: String) -> String {
     // Create a regex to match HTML tags
@@ -0,0 +1,299 @@
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::{
+    prelude::*,
+    ui::{UiTransform, Val2},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    narrative::act_loading::{check_image_path, SceneUI},
+    ui::menus::ImageNodeBundle,
+};
+
+/// An image layered over an image cutscene, such as a midground or the characters.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LayerDefinition {
+    /// The layer's image, found alongside the image cutscene's own.
+    image: PathBuf,
+    /// How strongly the layer follows the pan and zoom, where the image cutscene's own image is 1.
+    /// Layers further back follow less, and layers up front follow more.
+    #[serde(default = "default_parallax")]
+    parallax: f32,
+}
+
+fn default_parallax() -> f32 {
+    1.0
+}
+
+impl LayerDefinition {
+    pub fn new(image: PathBuf, parallax: f32) -> Self {
+        Self { image, parallax }
+    }
+
+    pub fn get_image(&self) -> &PathBuf {
+        &self.image
+    }
+
+    pub fn get_parallax(&self) -> f32 {
+        self.parallax
+    }
+}
+
+/// How far an image cutscene is panned and zoomed at some point of the scene.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PanZoomKeyframe {
+    /// Seconds since the scene was shown.
+    time: f32,
+    /// How far the image is moved, as a percent of the screen | ex. [-10.0, 5.0]
+    #[serde(default)]
+    pan: [f32; 2],
+    #[serde(default = "default_zoom")]
+    zoom: f32,
+}
+
+fn default_zoom() -> f32 {
+    1.0
+}
+
+impl PanZoomKeyframe {
+    pub fn new(time: f32, pan: Vec2, zoom: f32) -> Self {
+        Self {
+            time,
+            pan: pan.to_array(),
+            zoom,
+        }
+    }
+
+    pub fn get_time(&self) -> f32 {
+        self.time
+    }
+
+    pub fn get_pan(&self) -> Vec2 {
+        Vec2::from_array(self.pan)
+    }
+
+    pub fn get_zoom(&self) -> f32 {
+        self.zoom
+    }
+}
+
+/// The layers and the pan and zoom of an image cutscene, written in a JSON file
+/// next to its image with the same name | ex. PI1.png is laid out by PI1.json
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ImageCutsceneLayout {
+    #[serde(default)]
+    layers: Vec<LayerDefinition>,
+    #[serde(default)]
+    keyframes: Vec<PanZoomKeyframe>,
+}
+
+impl ImageCutsceneLayout {
+    pub fn new(layers: Vec<LayerDefinition>, mut keyframes: Vec<PanZoomKeyframe>) -> Self {
+        keyframes.sort_by(|first, second| first.get_time().total_cmp(&second.get_time()));
+
+        Self { layers, keyframes }
+    }
+
+    /// Returns where the layout of an image cutscene's image would be written.
+    pub fn get_layout_path(images_folder: &Path, image_path: &Path) -> PathBuf {
+        images_folder.join(image_path).with_extension("json")
+    }
+
+    /// Returns the layout written next to an image cutscene's image, if it has one.
+    pub fn find_for(images_folder: &Path, image_path: &Path) -> Option<Self> {
+        let layout_path = Self::get_layout_path(images_folder, image_path);
+        if !layout_path.exists() {
+            return None;
+        }
+
+        Self::load_from(layout_path)
+    }
+
+    /// Returns the layout written in a JSON layout file, or nothing if it can't be read.
+    pub fn load_from(layout_file: PathBuf) -> Option<Self> {
+        let file = match File::open(&layout_file) {
+            Ok(file) => file,
+            Err(error) => {
+                warn!(
+                    "load_from: Unable to open the image layout {}: {}",
+                    layout_file.display(),
+                    error
+                );
+                return None;
+            }
+        };
+        let reader = BufReader::new(file);
+
+        let layout: ImageCutsceneLayout = match serde_json::from_reader(reader) {
+            Ok(layout) => layout,
+            Err(error) => {
+                warn!(
+                    "load_from: Unable to parse the image layout {}: {}",
+                    layout_file.display(),
+                    error
+                );
+                return None;
+            }
+        };
+
+        Some(Self::new(layout.layers, layout.keyframes))
+    }
+
+    pub fn get_layers(&self) -> &Vec<LayerDefinition> {
+        &self.layers
+    }
+
+    pub fn get_keyframes(&self) -> &Vec<PanZoomKeyframe> {
+        &self.keyframes
+    }
+
+    /// Returns the pan and zoom some time into the scene, easing evenly from one keyframe
+    /// to the next and holding still before the first and after the last.
+    pub fn get_pan_zoom_at(&self, elapsed: Duration) -> (Vec2, f32) {
+        if self.keyframes.is_empty() {
+            return (Vec2::ZERO, 1.0);
+        }
+
+        let seconds = elapsed.as_secs_f32();
+
+        let first_keyframe = self.keyframes[0];
+        if seconds <= first_keyframe.get_time() {
+            return (first_keyframe.get_pan(), first_keyframe.get_zoom());
+        }
+
+        for keyframe_pair in self.keyframes.windows(2) {
+            let (from, to) = (keyframe_pair[0], keyframe_pair[1]);
+            if seconds > to.get_time() {
+                continue;
+            }
+
+            let keyframe_length = to.get_time() - from.get_time();
+            let progress = if keyframe_length <= 0.0 {
+                1.0
+            } else {
+                (seconds - from.get_time()) / keyframe_length
+            };
+
+            let pan = from.get_pan().lerp(to.get_pan(), progress);
+            let zoom = from.get_zoom() + (to.get_zoom() - from.get_zoom()) * progress;
+            return (pan, zoom);
+        }
+
+        let last_keyframe = self.keyframes[self.keyframes.len() - 1];
+        (last_keyframe.get_pan(), last_keyframe.get_zoom())
+    }
+}
+
+/// Plays an image cutscene's pan and zoom from when it was shown.
+#[derive(Component)]
+pub struct PanZoom {
+    layout: ImageCutsceneLayout,
+    elapsed: Duration,
+}
+
+impl PanZoom {
+    pub fn new(layout: ImageCutsceneLayout) -> Self {
+        Self {
+            layout,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+/// An image layered over an image cutscene.
+#[derive(Component)]
+pub struct ImageLayer {
+    parallax: f32,
+}
+
+impl ImageLayer {
+    pub fn new(parallax: f32) -> Self {
+        Self { parallax }
+    }
+
+    pub fn get_parallax(&self) -> f32 {
+        self.parallax
+    }
+}
+
+/// Layers an image cutscene's image with the images its layout declares, and starts its pan and zoom.
+pub fn add_image_layout(
+    scene_image: &mut EntityCommands,
+    asset_server: &AssetServer,
+    layout: ImageCutsceneLayout,
+) {
+    scene_image.with_children(|scene_image| {
+        for layer in layout.get_layers() {
+            let node = Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            };
+
+            let mut image = check_image_path(asset_server, layer.get_image().to_str().unwrap());
+
+            // Layers show up along with the image they're layered on
+            image.color.set_alpha(0.0);
+
+            scene_image.spawn((
+                ImageNodeBundle::from_nodes(node, image),
+                ImageLayer::new(layer.get_parallax()),
+            ));
+        }
+    });
+
+    scene_image.insert(PanZoom::new(layout));
+}
+
+/// Pans and zooms image cutscenes along their keyframes, with each layer following at its own pace.
+pub fn play_pan_zoom(
+    time: Res<Time>,
+    mut scene_images: Query<(&mut PanZoom, &mut UiTransform, Option<&Children>)>,
+    mut image_layers: Query<(&ImageLayer, &mut UiTransform), Without<PanZoom>>,
+) {
+    for (mut pan_zoom, mut scene_transform, children) in scene_images.iter_mut() {
+        pan_zoom.elapsed += time.delta();
+
+        let (pan, zoom) = pan_zoom.layout.get_pan_zoom_at(pan_zoom.elapsed);
+        scene_transform.translation = Val2::percent(pan.x, pan.y);
+        scene_transform.scale = Vec2::splat(zoom);
+
+        if children.is_none() {
+            continue;
+        }
+
+        // Layers are already moved along with the image they're layered on,
+        // so they only make up the difference between their parallax and its.
+        for child in children.unwrap().iter() {
+            if let Ok((image_layer, mut layer_transform)) = image_layers.get_mut(child) {
+                let parallax = image_layer.get_parallax();
+                let layer_zoom = 1.0 + (zoom - 1.0) * parallax;
+                let layer_pan = pan * (parallax - 1.0) / zoom;
+
+                layer_transform.translation = Val2::percent(layer_pan.x, layer_pan.y);
+                layer_transform.scale = Vec2::splat(layer_zoom / zoom);
+            }
+        }
+    }
+}
+
+/// Keeps image layers as faded in, or as darkened, as the image cutscene they're layered on.
+pub fn match_layers_to_scene_image(
+    scene_images: Query<(&ImageNode, &Children), (With<SceneUI>, Changed<ImageNode>)>,
+    mut image_layers: Query<&mut ImageNode, (With<ImageLayer>, Without<SceneUI>)>,
+) {
+    for (scene_image, children) in scene_images.iter() {
+        for child in children.iter() {
+            if let Ok(mut layer_image) = image_layers.get_mut(child) {
+                layer_image.color = scene_image.color;
+            }
+        }
+    }
+}
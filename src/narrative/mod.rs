@@ -1,2 +1,5 @@
 pub mod act_loading;
 pub mod acts;
+pub mod image_layouts;
+pub mod quests;
+pub mod story_flags;
@@ -0,0 +1,411 @@
+use std::{fs::File, io::BufReader, path::PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    map::{interactions::map_changing::LevelLoaded, items::ItemPickedUp, npc::NpcInteraction},
+    narrative::{
+        act_loading::{LoadAct, LoadNextScene},
+        acts::Act,
+    },
+};
+
+/// A quest as it's written down in a quest definition file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuestDefinition {
+    id: String,
+    title: String,
+    #[serde(default)]
+    description: String,
+    objectives: Vec<ObjectiveDefinition>,
+}
+
+impl QuestDefinition {
+    pub fn new(
+        id: String,
+        title: String,
+        description: String,
+        objectives: Vec<ObjectiveDefinition>,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            description,
+            objectives,
+        }
+    }
+
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn get_title(&self) -> &String {
+        &self.title
+    }
+
+    pub fn get_description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn get_objectives(&self) -> &Vec<ObjectiveDefinition> {
+        &self.objectives
+    }
+}
+
+/// A single task of a quest, such as talking to someone.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ObjectiveDefinition {
+    description: String,
+    #[serde(flatten)]
+    goal: ObjectiveGoal,
+}
+
+impl ObjectiveDefinition {
+    pub fn new(description: String, goal: ObjectiveGoal) -> Self {
+        Self { description, goal }
+    }
+
+    pub fn get_description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn get_goal(&self) -> &ObjectiveGoal {
+        &self.goal
+    }
+}
+
+/// What has to happen for an objective to be completed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ObjectiveGoal {
+    /// Talk to the character with the given name.
+    TalkTo { character: String },
+    /// Carry some amount of the item with the given id.
+    PickUp {
+        item: String,
+        #[serde(default = "default_quantity")]
+        quantity: u32,
+    },
+    /// Enter the map with the given file name or path.
+    EnterMap { map: String },
+    /// Reach the act scene with the given title.
+    ReachScene { scene: String },
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+impl ObjectiveGoal {
+    /// Returns how much progress is needed to complete the goal.
+    pub fn get_target(&self) -> u32 {
+        match self {
+            ObjectiveGoal::PickUp { quantity, .. } => *quantity,
+            _ => 1,
+        }
+    }
+
+    /// Returns the progress made towards the goal by some event, or None if it's unrelated.
+    pub fn get_progress_from(&self, quest_event: &QuestEvent) -> Option<u32> {
+        match (self, quest_event) {
+            (ObjectiveGoal::TalkTo { character }, QuestEvent::TalkedTo(npc_name))
+                if character == npc_name =>
+            {
+                Some(1)
+            }
+            (ObjectiveGoal::PickUp { item, .. }, QuestEvent::Carrying(item_id, quantity))
+                if item == item_id =>
+            {
+                Some(*quantity)
+            }
+            (ObjectiveGoal::EnterMap { map }, QuestEvent::EnteredMap(level_path))
+                if level_path.ends_with(map) =>
+            {
+                Some(1)
+            }
+            (ObjectiveGoal::ReachScene { scene }, QuestEvent::ReachedScene(scene_title))
+                if scene == scene_title =>
+            {
+                Some(1)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The file quests are defined in.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct QuestFile {
+    quests: Vec<QuestDefinition>,
+}
+
+/// Returns every quest defined in a JSON quest definition file.
+pub fn load_quests_from(quest_file: PathBuf) -> Vec<QuestDefinition> {
+    let file = File::open(quest_file).expect("load_quests_from: Unable to open file");
+    let reader = BufReader::new(file);
+
+    let quest_file: QuestFile = serde_json::from_reader(reader)
+        .expect("load_quests_from: Unable to parse the quest definition file.");
+
+    quest_file.quests
+}
+
+/// A quest along with how far along each of its objectives are.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Quest {
+    definition: QuestDefinition,
+    objective_progress: Vec<u32>,
+}
+
+impl Quest {
+    pub fn new(definition: QuestDefinition) -> Self {
+        let objective_progress = vec![0; definition.get_objectives().len()];
+
+        Self {
+            definition,
+            objective_progress,
+        }
+    }
+
+    pub fn get_definition(&self) -> &QuestDefinition {
+        &self.definition
+    }
+
+    pub fn get_id(&self) -> &String {
+        self.definition.get_id()
+    }
+
+    pub fn get_title(&self) -> &String {
+        self.definition.get_title()
+    }
+
+    pub fn get_objective_progress(&self, objective_index: usize) -> u32 {
+        self.objective_progress[objective_index]
+    }
+
+    pub fn is_objective_completed(&self, objective_index: usize) -> bool {
+        let objective = &self.definition.get_objectives()[objective_index];
+
+        self.objective_progress[objective_index] >= objective.get_goal().get_target()
+    }
+
+    /// Returns how many objectives of the quest are completed.
+    pub fn get_num_completed_objectives(&self) -> usize {
+        (0..self.objective_progress.len())
+            .filter(|objective_index| self.is_objective_completed(*objective_index))
+            .count()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.get_num_completed_objectives() == self.objective_progress.len()
+    }
+}
+
+/// Every quest of the game and how far along they are.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct QuestLog {
+    quests: Vec<Quest>,
+}
+
+impl QuestLog {
+    pub fn new(definitions: Vec<QuestDefinition>) -> Self {
+        let quests = definitions.into_iter().map(Quest::new).collect();
+
+        Self { quests }
+    }
+
+    pub fn get_quests(&self) -> &Vec<Quest> {
+        &self.quests
+    }
+
+    pub fn get_quest(&self, quest_id: &str) -> Option<&Quest> {
+        self.quests.iter().find(|quest| quest.get_id() == quest_id)
+    }
+
+    /// Makes progress on every objective related to some event, returning the objectives
+    /// and quests that were completed by it.
+    pub fn record(
+        &mut self,
+        quest_event: &QuestEvent,
+    ) -> (Vec<ObjectiveCompleted>, Vec<QuestCompleted>) {
+        let mut completed_objectives = Vec::new();
+        let mut completed_quests = Vec::new();
+
+        for quest in &mut self.quests {
+            if quest.is_completed() {
+                continue;
+            }
+
+            for objective_index in 0..quest.objective_progress.len() {
+                if quest.is_objective_completed(objective_index) {
+                    continue;
+                }
+
+                let objective = &quest.definition.get_objectives()[objective_index];
+                let found_progress = objective.get_goal().get_progress_from(quest_event);
+                if found_progress.is_none() {
+                    continue;
+                }
+
+                let progress = &mut quest.objective_progress[objective_index];
+                *progress = (*progress).max(found_progress.unwrap());
+
+                if quest.is_objective_completed(objective_index) {
+                    completed_objectives.push(ObjectiveCompleted::new(
+                        quest.get_id(),
+                        quest.definition.get_objectives()[objective_index].get_description(),
+                    ));
+                }
+            }
+
+            if quest.is_completed() {
+                completed_quests.push(QuestCompleted::new(quest.get_id(), quest.get_title()));
+            }
+        }
+
+        (completed_objectives, completed_quests)
+    }
+}
+
+/// Something that happened in the game which objectives might be waiting on.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum QuestEvent {
+    /// The player talked to the character with the given name.
+    TalkedTo(String),
+    /// The player is carrying the given amount of the item with the given id.
+    Carrying(String, u32),
+    /// The level at the given path was entered.
+    EnteredMap(PathBuf),
+    /// The act scene with the given title was reached.
+    ReachedScene(String),
+}
+
+/// Sent whenever an objective of a quest is completed.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct ObjectiveCompleted {
+    quest_id: String,
+    description: String,
+}
+
+impl ObjectiveCompleted {
+    pub fn new(quest_id: &str, description: &str) -> Self {
+        Self {
+            quest_id: quest_id.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    pub fn get_quest_id(&self) -> &String {
+        &self.quest_id
+    }
+
+    pub fn get_description(&self) -> &String {
+        &self.description
+    }
+}
+
+/// Sent whenever every objective of a quest is completed.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct QuestCompleted {
+    quest_id: String,
+    title: String,
+}
+
+impl QuestCompleted {
+    pub fn new(quest_id: &str, title: &str) -> Self {
+        Self {
+            quest_id: quest_id.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    pub fn get_quest_id(&self) -> &String {
+        &self.quest_id
+    }
+
+    pub fn get_title(&self) -> &String {
+        &self.title
+    }
+}
+
+/// Makes progress on the quests waiting on what happened.
+pub fn update_quest_log(
+    mut quest_events: MessageReader<QuestEvent>,
+    mut quest_log: ResMut<QuestLog>,
+    mut completed_objectives: MessageWriter<ObjectiveCompleted>,
+    mut completed_quests: MessageWriter<QuestCompleted>,
+) {
+    for quest_event in quest_events.read() {
+        let (objectives, quests) = quest_log.record(quest_event);
+
+        completed_objectives.write_batch(objectives);
+        completed_quests.write_batch(quests);
+    }
+}
+
+/// Lets quests know which characters the player talks to.
+pub fn track_talked_to_characters(
+    mut npc_interactions: MessageReader<NpcInteraction>,
+    mut quest_events: MessageWriter<QuestEvent>,
+) {
+    for npc_interaction in npc_interactions.read() {
+        quest_events.write(QuestEvent::TalkedTo(npc_interaction.get_npc_name().clone()));
+    }
+}
+
+/// Lets quests know how many of an item the player carries once they pick some up.
+pub fn track_carried_items(
+    mut picked_up_items: MessageReader<ItemPickedUp>,
+    mut quest_events: MessageWriter<QuestEvent>,
+) {
+    for picked_up_item in picked_up_items.read() {
+        quest_events.write(QuestEvent::Carrying(
+            picked_up_item.get_item_id().clone(),
+            picked_up_item.get_carried_quantity(),
+        ));
+    }
+}
+
+/// Lets quests know which levels are entered, including the level the game starts in.
+///
+/// Levels can be asked for more than once while waiting on the loading screen, so this goes
+/// by the levels that have been built instead.
+pub fn track_entered_maps(
+    mut loaded_levels: MessageReader<LevelLoaded>,
+    mut quest_events: MessageWriter<QuestEvent>,
+) {
+    for loaded_level in loaded_levels.read() {
+        quest_events.write(QuestEvent::EnteredMap(
+            loaded_level.get_level_path().clone(),
+        ));
+    }
+}
+
+/// Lets quests know which act scenes are reached, including the first scene of a newly added act.
+///
+/// Acts can be held back while their assets load, so a newly asked for act only counts once
+/// it has been added.
+pub fn track_reached_scenes(
+    mut load_act_requests: MessageReader<LoadAct>,
+    mut load_next_scene_requests: MessageReader<LoadNextScene>,
+    current_act: Query<Ref<Act>>,
+    mut quest_events: MessageWriter<QuestEvent>,
+) {
+    let has_loaded_act = load_act_requests.read().count() > 0;
+    let has_moved_scene = load_next_scene_requests.read().count() > 0;
+    if !has_loaded_act && !has_moved_scene {
+        return;
+    }
+
+    let Ok(current_act) = current_act.single() else {
+        return;
+    };
+
+    if !has_moved_scene && !current_act.is_added() {
+        return;
+    }
+
+    let scene_title = current_act.get_current_scene().get_title();
+    quest_events.write(QuestEvent::ReachedScene(scene_title));
+}
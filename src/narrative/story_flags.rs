@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use tiled::PropertyValue;
+
+use crate::map::get_string_property;
+
+/// The value of a single story flag.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum StoryFlag {
+    Bool(bool),
+    Counter(i64),
+    Text(String),
+}
+
+impl StoryFlag {
+    /// Returns a flag from how it's written down, such as in Tiled or Arcweave,
+    /// where "true" and "false" are booleans, whole numbers are counters and anything
+    /// else is text.
+    pub fn parse_from(value: &str) -> Self {
+        let trimmed_value = value.trim();
+
+        if let Ok(is_true) = trimmed_value.parse::<bool>() {
+            return StoryFlag::Bool(is_true);
+        }
+
+        if let Ok(count) = trimmed_value.parse::<i64>() {
+            return StoryFlag::Counter(count);
+        }
+
+        StoryFlag::Text(trimmed_value.to_string())
+    }
+
+    /// Returns whether the flag counts as being set, which is when it's true,
+    /// a counter above zero or some text that isn't empty.
+    pub fn is_set(&self) -> bool {
+        match self {
+            StoryFlag::Bool(is_true) => *is_true,
+            StoryFlag::Counter(count) => *count > 0,
+            StoryFlag::Text(text) => !text.is_empty(),
+        }
+    }
+}
+
+/// Everything that has happened in the story so far, shared by maps, interactives and acts.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StoryFlags {
+    flags: HashMap<String, StoryFlag>,
+}
+
+impl StoryFlags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, flag_name: &str) -> Option<&StoryFlag> {
+        self.flags.get(flag_name)
+    }
+
+    pub fn set(&mut self, flag_name: &str, value: StoryFlag) {
+        self.flags.insert(flag_name.to_string(), value);
+    }
+
+    /// Returns whether a flag exists and counts as being set.
+    pub fn is_set(&self, flag_name: &str) -> bool {
+        self.get(flag_name).is_some_and(|flag| flag.is_set())
+    }
+
+    /// Returns whether a requirement is met, where a requirement is the name of a flag
+    /// that has to be set, or the name of a flag starting with ! that can't be set.
+    pub fn meets(&self, requirement: &str) -> bool {
+        match requirement.strip_prefix('!') {
+            Some(flag_name) => !self.is_set(flag_name.trim()),
+            None => self.is_set(requirement.trim()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+}
+
+/// A request to change the value of a story flag.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct SetStoryFlag {
+    flag_name: String,
+    value: StoryFlag,
+}
+
+impl SetStoryFlag {
+    pub fn new(flag_name: &str, value: StoryFlag) -> Self {
+        Self {
+            flag_name: flag_name.to_string(),
+            value,
+        }
+    }
+
+    pub fn get_flag_name(&self) -> &String {
+        &self.flag_name
+    }
+
+    pub fn get_value(&self) -> &StoryFlag {
+        &self.value
+    }
+}
+
+/// A request to hear back what some story flag is currently set to.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct QueryStoryFlag {
+    flag_name: String,
+}
+
+impl QueryStoryFlag {
+    pub fn new(flag_name: &str) -> Self {
+        Self {
+            flag_name: flag_name.to_string(),
+        }
+    }
+
+    pub fn get_flag_name(&self) -> &String {
+        &self.flag_name
+    }
+}
+
+/// The value of a story flag, sent whenever a flag changes or is queried.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct StoryFlagReport {
+    flag_name: String,
+    value: Option<StoryFlag>,
+}
+
+impl StoryFlagReport {
+    pub fn new(flag_name: &str, value: Option<StoryFlag>) -> Self {
+        Self {
+            flag_name: flag_name.to_string(),
+            value,
+        }
+    }
+
+    pub fn get_flag_name(&self) -> &String {
+        &self.flag_name
+    }
+
+    pub fn get_value(&self) -> &Option<StoryFlag> {
+        &self.value
+    }
+}
+
+/// The RequiresFlag and SetsFlag properties of a Tiled object.
+///
+/// Objects that require a flag are left out when their level is loaded without it,
+/// or can't be used in the case of markers. Objects that set a flag set it to true
+/// once they're used, such as when a character is talked to or an item is picked up.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FlagConditions {
+    required_flag: Option<String>,
+    set_flag: Option<String>,
+}
+
+impl FlagConditions {
+    pub fn new(required_flag: Option<String>, set_flag: Option<String>) -> Self {
+        Self {
+            required_flag,
+            set_flag,
+        }
+    }
+
+    pub fn from_properties(properties: &HashMap<String, PropertyValue>) -> Self {
+        Self::new(
+            get_string_property(properties, "RequiresFlag"),
+            get_string_property(properties, "SetsFlag"),
+        )
+    }
+
+    pub fn get_required_flag(&self) -> &Option<String> {
+        &self.required_flag
+    }
+
+    pub fn get_set_flag(&self) -> &Option<String> {
+        &self.set_flag
+    }
+
+    /// Returns whether the story so far allows the object to be used.
+    pub fn is_met_by(&self, story_flags: &StoryFlags) -> bool {
+        match &self.required_flag {
+            Some(requirement) => story_flags.meets(requirement),
+            None => true,
+        }
+    }
+
+    /// Asks for the flag set by the object to be set, if it sets one.
+    pub fn set_flag_with(&self, set_story_flag_requests: &mut MessageWriter<SetStoryFlag>) {
+        if let Some(flag_name) = &self.set_flag {
+            set_story_flag_requests.write(SetStoryFlag::new(flag_name, StoryFlag::Bool(true)));
+        }
+    }
+}
+
+/// Changes story flags as requested, reporting their new values.
+pub fn set_story_flags(
+    mut set_story_flag_requests: MessageReader<SetStoryFlag>,
+    mut story_flags: ResMut<StoryFlags>,
+    mut story_flag_reports: MessageWriter<StoryFlagReport>,
+) {
+    for set_story_flag_request in set_story_flag_requests.read() {
+        let flag_name = set_story_flag_request.get_flag_name();
+        let value = set_story_flag_request.get_value().clone();
+
+        story_flags.set(flag_name, value.clone());
+        story_flag_reports.write(StoryFlagReport::new(flag_name, Some(value)));
+    }
+}
+
+/// Reports the values of the story flags asked about.
+pub fn answer_story_flag_queries(
+    mut story_flag_queries: MessageReader<QueryStoryFlag>,
+    story_flags: Res<StoryFlags>,
+    mut story_flag_reports: MessageWriter<StoryFlagReport>,
+) {
+    for story_flag_query in story_flag_queries.read() {
+        let flag_name = story_flag_query.get_flag_name();
+        let value = story_flags.get(flag_name).cloned();
+
+        story_flag_reports.write(StoryFlagReport::new(flag_name, value));
+    }
+}
@@ -1,26 +1,23 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
-use crate::map::interactions::map_changing::ChangeLevel;
+use crate::map::camera::PanCamera;
+use crate::map::interactions::{map_changing::ChangeLevel, triggers::TriggerFired};
 use crate::narrative::act_loading::*;
-use crate::AppState;
+use crate::narrative::image_layouts::{match_layers_to_scene_image, play_pan_zoom};
+use crate::plugins::story_flags::StoryFlagsPlugin;
+use crate::{AppState, PauseState, QUIT_TO_TITLE};
 use bevy::prelude::*;
 
+/// Plays through the acts of the game. The starting act is loaded by the loading plugin,
+/// once its assets have loaded.
 pub struct ActsPlugin;
 
 impl Plugin for ActsPlugin {
     fn build(&self, app: &mut App) {
         let map_folder_path = PathBuf::from("assets/map/");
 
-        app.add_plugins(CoreActsPlugin::new(Duration::from_secs(3), map_folder_path))
-            .add_systems(
-                Update,
-                (load_next_scene_on_player_input,).run_if(in_state(AppState::InScene)),
-            )
-            .add_systems(
-                OnEnter(AppState::InScene),
-                load_starting_act.run_if(in_state(AppState::InScene)),
-            );
+        app.add_plugins(CoreActsPlugin::new(Duration::from_secs(3), map_folder_path));
     }
 }
 
@@ -75,21 +72,40 @@ impl Plugin for CoreActsPlugin {
         app.insert_resource(self.time_to_fade);
         app.insert_resource(self.maps_path_folder.clone());
 
+        if !app.is_plugin_added::<StoryFlagsPlugin>() {
+            app.add_plugins(StoryFlagsPlugin);
+        }
+
         app.add_message::<LoadAct>()
             .add_message::<LoadNextScene>()
             .add_message::<ImageDespawn>()
             .add_message::<ChangeLevel>()
+            .add_message::<PanCamera>()
+            .add_message::<TriggerFired>()
             .add_systems(
                 Update,
                 (
+                    load_act_from_trigger.before(load_act),
                     load_act,
                     fade_into,
                     despawn_image.after(fade_into),
+                    auto_advance_scenes
+                        .after(despawn_image)
+                        .before(load_next_scene),
+                    load_next_scene_on_player_input
+                        .after(despawn_image)
+                        .before(load_next_scene)
+                        .run_if(in_state(PauseState::Running)),
                     load_next_scene.after(despawn_image),
                     render_image_cutscene.after(load_next_scene),
                     render_map_cutscene.after(load_next_scene),
+                    pan_to_map_cutscene.after(load_next_scene),
+                    set_flags_on_scene_entry.after(load_next_scene),
+                    play_pan_zoom,
+                    match_layers_to_scene_image.after(fade_into),
                 )
                     .run_if(in_state(AppState::InScene)),
-            );
+            )
+            .add_systems(QUIT_TO_TITLE, unload_act);
     }
 }
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        ambience::*,
+        buses::{AudioBus, AudioBusApp},
+    },
+    map::{ambient_sounds::mix_ambient_sounds, interactions::map_changing::load_map},
+    AppState,
+};
+
+/// Loops the ambient sounds placed around each level, mixed for where the player stands.
+///
+/// The mix itself is worked out by the levels plugin, so this only plays it.
+pub struct AmbiencePlugin;
+
+impl Plugin for AmbiencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_channel_on_bus::<AmbientChannel>(AudioBus::Ambient)
+            .add_systems(
+                Update,
+                (
+                    stop_removed_ambient_sounds.before(play_ambient_sounds),
+                    play_ambient_sounds
+                        .after(load_map)
+                        .after(mix_ambient_sounds),
+                    follow_ambient_mix.after(play_ambient_sounds),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(OnExit(AppState::InScene), stop_ambience);
+    }
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use crate::display::*;
+
+/// Keeps the window, UI scale and camera zoom matching the display settings.
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        // The settings are normally loaded from the settings file, which replaces these.
+        if !app.world().contains_resource::<DisplaySettings>() {
+            app.init_resource::<DisplaySettings>();
+        }
+
+        app.init_resource::<UiScale>().add_systems(
+            Update,
+            (
+                apply_display_settings,
+                apply_camera_scaling.after(apply_display_settings),
+            ),
+        );
+
+        // The zoom keys are only for debugging, so they're left out of release builds.
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            Update,
+            zoom_camera_on_key_press.before(apply_camera_scaling),
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::{ui::menus::inventory::*, AppState, PauseState};
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(PauseState::Inventory), spawn_inventory_screen)
+            .add_systems(
+                Update,
+                (
+                    toggle_inventory,
+                    inventory_button_system.run_if(in_state(PauseState::Inventory)),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(OnExit(PauseState::Inventory), unload_inventory_screen);
+    }
+}
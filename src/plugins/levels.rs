@@ -1,28 +1,40 @@
 use bevy::prelude::*;
-use bevy_kira_audio::AudioApp;
 
 use crate::{
-    audio::music::{play_level_music, MusicChannel},
     map::{
-        interactions::{interactives::*, map_changing::*},
-        movement::grid_based_movement::*,
+        ambient_sounds::mix_ambient_sounds,
+        camera::*,
+        interactions::{interactives::*, level_transitions::*, map_changing::*, triggers::*},
+        items::{pick_up_item, ItemPickedUp, PickedUpItems},
+        movement::{click_to_move::*, grid_based_movement::*, npc_movement::*},
+        npc::{interact_with_npc, NpcInteraction},
         player::PlayerInteraction,
     },
-    ui::*,
-    AppState,
+    narrative::story_flags::set_story_flags,
+    plugins::story_flags::StoryFlagsPlugin,
+    ui::text_box::*,
+    AppState, PauseState, QUIT_TO_TITLE,
 };
 
 pub struct LevelsPlugin;
 
 impl Plugin for LevelsPlugin {
     fn build(&self, app: &mut App) {
+        // The camera eases after the player, letting them move a little before it follows.
+        let camera_follow = CameraFollow::new()
+            .with_smoothing(6.0)
+            .with_dead_zone(Vec2::new(128.0, 64.0));
+
         // Loading the map
-        app.add_plugins(CoreLevelsPlugin)
-            .add_systems(
-                Update,
-                (play_level_music, interact_entity).run_if(in_state(AppState::InScene)),
-            )
-            .add_audio_channel::<MusicChannel>();
+        app.insert_resource(camera_follow);
+        app.add_plugins(CoreLevelsPlugin).add_systems(
+            Update,
+            interact_entity
+                .before(dismiss_text_box)
+                .run_if(in_state(AppState::InScene))
+                .run_if(in_state(PauseState::Running))
+                .run_if(no_level_transition_running),
+        );
     }
 }
 
@@ -30,20 +42,82 @@ pub struct CoreLevelsPlugin;
 
 impl Plugin for CoreLevelsPlugin {
     fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StoryFlagsPlugin>() {
+            app.add_plugins(StoryFlagsPlugin);
+        }
+
         // Loading the map
         app.add_message::<LoadLevel>()
             .add_message::<ChangeLevel>()
+            .add_message::<LevelLoaded>()
+            .add_message::<ItemPickedUp>()
             .add_message::<PlayerInteraction>()
+            .add_message::<NpcInteraction>()
+            .add_message::<WalkToTile>()
+            .add_message::<ShowTextBox>()
+            .add_message::<ReachedTarget>()
+            .add_message::<TriggerFired>()
+            .add_message::<ScreenCovered>()
+            .add_message::<PanCamera>()
+            .add_message::<ShakeCamera>()
+            .add_message::<CameraPanFinished>()
+            .init_resource::<TriggerHistory>()
+            .init_resource::<LevelTransitionDuration>()
+            .init_resource::<IdleTime>()
+            .init_resource::<CameraFollow>()
+            .init_resource::<PickedUpItems>()
+            .add_sub_state::<PauseState>()
             .add_systems(
                 Update,
                 (
-                    load_map,
-                    change_to_new_level,
-                    follow_player.after(move_player_on_key_press),
-                    move_player_on_key_press,
+                    load_map.after(change_to_new_level),
+                    mix_ambient_sounds
+                        .after(load_map)
+                        .after(move_entity_to_target),
+                    change_to_new_level.after(advance_level_transitions),
+                    advance_level_transitions,
+                    (
+                        start_camera_pans,
+                        start_camera_shakes,
+                        move_camera
+                            .after(start_camera_pans)
+                            .after(start_camera_shakes)
+                            .after(move_player_on_key_press)
+                            .after(move_entity_to_target),
+                    ),
+                    move_player_on_key_press
+                        .run_if(in_state(PauseState::Running))
+                        .run_if(no_level_transition_running),
                     change_level_from_marker,
+                    inspect_marker,
+                    (
+                        fire_triggers.after(move_entity_to_target),
+                        change_level_from_trigger.after(fire_triggers),
+                        set_story_flag_from_trigger
+                            .after(fire_triggers)
+                            .before(set_story_flags),
+                    ),
+                    dismiss_text_box.before(spawn_text_box),
+                    spawn_text_box,
+                    interact_with_npc,
+                    pick_up_item,
+                    set_npc_targets,
+                    face_player.after(move_entity_to_target),
+                    walk_to_tile_on_click
+                        .run_if(in_state(PauseState::Running))
+                        .run_if(no_level_transition_running),
+                    set_player_path.after(walk_to_tile_on_click),
+                    follow_queued_path
+                        .after(set_player_path)
+                        .before(set_player_target)
+                        .run_if(in_state(PauseState::Running))
+                        .run_if(no_level_transition_running),
                 )
                     .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(
+                QUIT_TO_TITLE,
+                (unload_level, remove_text_boxes, remove_level_transitions),
             );
     }
 }
@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::{
+    loading::*, narrative::act_loading::STARTING_ACT_PATH, ui::loading_screen::*, AppState,
+};
+
+/// Loads everything the starting act, and every level and act after it, needs, including
+/// their music, behind a loading screen before entering the scene.
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(CoreLoadingPlugin::new(PathBuf::from(STARTING_ACT_PATH)))
+            .add_systems(
+                OnEnter(AppState::Loading),
+                queue_needed_audio
+                    .after(queue_needed_images)
+                    .before(spawn_loading_screen),
+            );
+    }
+}
+
+pub struct CoreLoadingPlugin {
+    starting_act: StartingAct,
+}
+
+impl CoreLoadingPlugin {
+    pub fn new(act_path: PathBuf) -> Self {
+        Self {
+            starting_act: StartingAct::new(act_path),
+        }
+    }
+}
+
+impl Plugin for CoreLoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.starting_act.clone())
+            .init_resource::<PendingLoads>()
+            .add_systems(
+                OnEnter(AppState::Loading),
+                (
+                    queue_starting_act,
+                    queue_needed_images.after(queue_starting_act),
+                    spawn_loading_screen.after(queue_needed_images),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    show_loading_progress,
+                    finish_loading.after(show_loading_progress),
+                )
+                    .run_if(in_state(AppState::Loading)),
+            )
+            .add_systems(OnExit(AppState::Loading), unload_loading_screen)
+            .add_systems(OnEnter(AppState::InScene), release_pending_loads);
+    }
+}
@@ -1,5 +1,15 @@
 pub mod acts;
+pub mod ambience;
+pub mod display;
+pub mod inventory;
 pub mod levels;
+pub mod loading;
 pub mod main_menu;
+pub mod music;
+pub mod pause_menu;
 pub mod playable_character;
+pub mod quests;
 pub mod settings_menu;
+pub mod sfx;
+pub mod story_flags;
+pub mod widgets;
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        buses::{AudioBus, AudioBusApp, AudioBuses},
+        ducking::*,
+        music::*,
+        voice::*,
+    },
+    map::interactions::map_changing::load_map,
+    narrative::act_loading::load_next_scene,
+    AppState, QUIT_TO_TITLE,
+};
+
+/// Plays the music of each level and act scene, crossfading between different tracks.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(CoreMusicPlugin::new(Duration::from_secs_f32(1.5)))
+            .add_audio_channel_on_bus::<MusicChannel>(AudioBus::Music)
+            .add_audio_channel_on_bus::<VoiceChannel>(AudioBus::Voice)
+            .add_systems(
+                Update,
+                (
+                    crossfade_music.after(switch_music),
+                    track_voice_playback.before(choose_music_ducking),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(QUIT_TO_TITLE, stop_music);
+    }
+}
+
+/// Picks the track that should be playing, and how far it's ducked, without playing any audio.
+pub struct CoreMusicPlugin {
+    music_fade_duration: MusicFadeDuration,
+    ducking_settings: DuckingSettings,
+}
+
+impl CoreMusicPlugin {
+    pub fn new(music_fade_duration: Duration) -> Self {
+        Self {
+            music_fade_duration: MusicFadeDuration::new(music_fade_duration),
+            ducking_settings: DuckingSettings::default(),
+        }
+    }
+
+    /// Returns the plugin, ducking the music the way the given settings describe.
+    pub fn with_ducking(mut self, ducking_settings: DuckingSettings) -> Self {
+        self.ducking_settings = ducking_settings;
+        self
+    }
+}
+
+impl Plugin for CoreMusicPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<AudioBuses>() {
+            app.init_resource::<AudioBuses>();
+        }
+
+        app.insert_resource(self.music_fade_duration)
+            .insert_resource(self.ducking_settings.clone())
+            .init_resource::<MusicDucking>()
+            .init_resource::<PlayingMusic>()
+            .init_resource::<SceneMusic>()
+            .init_resource::<VoicePlayback>()
+            .add_message::<PlayMusic>()
+            .add_message::<MusicChanged>()
+            .add_systems(
+                Update,
+                (
+                    choose_scene_music.after(load_next_scene),
+                    choose_level_music.after(load_map).after(choose_scene_music),
+                    switch_music
+                        .after(choose_level_music)
+                        .after(choose_scene_music),
+                    choose_music_ducking.after(load_next_scene),
+                    ramp_music_ducking.after(choose_music_ducking),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(QUIT_TO_TITLE, (reset_music, reset_music_ducking));
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        ambience::{pause_ambience, resume_ambience},
+        music::{pause_music, resume_music},
+    },
+    saving::*,
+    ui::menus::pause_menu::*,
+    AppState, PauseState,
+};
+
+/// Lets the game be paused with Escape, freezing it behind a menu to resume, open the
+/// settings, save or quit to the title screen.
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(CorePauseMenuPlugin::new(PathBuf::from("saves/save.json")))
+            .add_systems(OnEnter(PauseState::Paused), (pause_music, pause_ambience))
+            .add_systems(
+                OnEnter(PauseState::Running),
+                (resume_music, resume_ambience),
+            );
+    }
+}
+
+pub struct CorePauseMenuPlugin {
+    save_file_path: SaveFilePath,
+}
+
+impl CorePauseMenuPlugin {
+    pub fn new(save_file_path: PathBuf) -> Self {
+        Self {
+            save_file_path: SaveFilePath::new(save_file_path),
+        }
+    }
+}
+
+impl Plugin for CorePauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.save_file_path.clone())
+            .add_message::<SaveGame>()
+            .add_message::<SaveOutcome>()
+            .add_systems(OnEnter(PauseState::Paused), (freeze_time, spawn_pause_menu))
+            // Time is frozen behind every menu, not just the pause menu, and stays frozen while
+            // going from one menu to another, so it only starts again once the game is resumed
+            // or left.
+            .add_systems(OnEnter(PauseState::Inventory), freeze_time)
+            .add_systems(OnEnter(PauseState::QuestLog), freeze_time)
+            .add_systems(OnEnter(PauseState::Settings), freeze_time)
+            .add_systems(OnEnter(PauseState::Running), unfreeze_time)
+            .add_systems(OnExit(AppState::InScene), unfreeze_time)
+            .add_systems(
+                Update,
+                (
+                    toggle_pause_menu,
+                    pause_menu_button_system.run_if(in_state(PauseState::Paused)),
+                    save_game.after(pause_menu_button_system),
+                    show_save_outcome.after(save_game),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(OnExit(PauseState::Paused), unload_pause_menu);
+    }
+}
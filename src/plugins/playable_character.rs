@@ -1,10 +1,9 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use bevy::prelude::*;
-use bevy_kira_audio::AudioApp;
 
 use crate::{
-    audio::sfx::*,
+    audio::footsteps::*,
     map::{movement::grid_based_movement::*, player::*},
     AppState,
 };
@@ -14,25 +13,23 @@ pub struct PlayableCharacterPlugin;
 impl Plugin for PlayableCharacterPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<MovementDirection>();
+        app.add_message::<ReachedTarget>();
+        app.add_message::<PlayFootstep>();
         app.insert_resource(ArrivalTime::new(Duration::from_secs_f32(0.15)));
+        app.insert_resource(FootstepSounds::load_from(PathBuf::from(
+            "assets/audio/footsteps.json",
+        )));
 
         app.add_systems(
-            Update,
-            (set_player_target, move_entity_to_target).run_if(in_state(AppState::InScene)),
-        )
-        .add_systems(
             Update,
             (
-                play_player_movement_sound.after(set_player_target),
-                play_player_bump_sound.after(set_player_target),
-            ),
+                set_player_target,
+                move_entity_to_target,
+                choose_footstep_sounds.after(move_entity_to_target),
+            )
+                .run_if(in_state(AppState::InScene)),
         )
-        .add_systems(
-            OnEnter(AppState::InScene),
-            (load_player_movement_sound, load_player_bump_sound),
-        )
-        .add_audio_channel::<PlayerWalkChannel>()
-        .add_audio_channel::<PlayerBumpChannel>()
+        .add_systems(Update, play_footstep_sounds.after(choose_footstep_sounds))
         .add_message::<PlayerMovementActions>();
     }
 }
@@ -42,11 +39,19 @@ pub struct PlayableCharacterTestingPlugin;
 impl Plugin for PlayableCharacterTestingPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<MovementDirection>();
+        app.add_message::<ReachedTarget>();
+        app.add_message::<PlayFootstep>();
         app.insert_resource(ArrivalTime::new(Duration::from_secs_f32(0.15)));
+        app.init_resource::<FootstepSounds>();
 
         app.add_systems(
             Update,
-            (set_player_target, move_entity_to_target).run_if(in_state(AppState::InScene)),
+            (
+                set_player_target,
+                move_entity_to_target,
+                choose_footstep_sounds.after(move_entity_to_target),
+            )
+                .run_if(in_state(AppState::InScene)),
         )
         .add_message::<PlayerMovementActions>();
     }
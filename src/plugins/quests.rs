@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::{
+    map::{
+        interactions::map_changing::{load_map, LevelLoaded},
+        items::{pick_up_item, ItemPickedUp},
+        npc::{interact_with_npc, NpcInteraction},
+    },
+    narrative::{
+        act_loading::{load_act, load_next_scene, LoadAct, LoadNextScene},
+        quests::*,
+    },
+    ui::{menus::quest_log::*, quest_toast::*},
+    AppState, PauseState, QUIT_TO_TITLE,
+};
+
+/// Tracks the quests defined in a quest definition file, showing their progress
+/// in a toast and on the quest log screen.
+pub struct QuestsPlugin {
+    quest_file: PathBuf,
+}
+
+impl QuestsPlugin {
+    pub fn new(quest_file: PathBuf) -> Self {
+        Self { quest_file }
+    }
+}
+
+impl Plugin for QuestsPlugin {
+    fn build(&self, app: &mut App) {
+        let quest_log = QuestLog::new(load_quests_from(self.quest_file.clone()));
+
+        app.insert_resource(quest_log)
+            .add_message::<QuestEvent>()
+            .add_message::<ObjectiveCompleted>()
+            .add_message::<QuestCompleted>()
+            .add_message::<NpcInteraction>()
+            .add_message::<ItemPickedUp>()
+            .add_message::<LevelLoaded>()
+            .add_message::<LoadAct>()
+            .add_message::<LoadNextScene>()
+            .add_systems(OnEnter(PauseState::QuestLog), spawn_quest_log_screen)
+            .add_systems(
+                Update,
+                (
+                    track_talked_to_characters.after(interact_with_npc),
+                    track_carried_items.after(pick_up_item),
+                    track_entered_maps.after(load_map),
+                    track_reached_scenes.after(load_act).after(load_next_scene),
+                    update_quest_log
+                        .after(track_talked_to_characters)
+                        .after(track_carried_items)
+                        .after(track_entered_maps)
+                        .after(track_reached_scenes),
+                    spawn_quest_toast.after(update_quest_log),
+                    despawn_quest_toasts,
+                    toggle_quest_log,
+                    quest_log_button_system.run_if(in_state(PauseState::QuestLog)),
+                )
+                    .run_if(in_state(AppState::InScene)),
+            )
+            .add_systems(OnExit(PauseState::QuestLog), unload_quest_log_screen)
+            .add_systems(QUIT_TO_TITLE, remove_quest_toasts);
+    }
+}
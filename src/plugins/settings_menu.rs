@@ -1,39 +1,92 @@
+use std::path::PathBuf;
+
 use bevy::prelude::*;
 
-use crate::{ui::menus::settings_menu::*, AppState};
+use crate::{
+    plugins::widgets::WidgetsPlugin,
+    settings::*,
+    ui::{
+        menus::settings_menu::*,
+        widgets::{
+            dropdown::{choose_dropdown_options, step_focused_dropdowns},
+            slider::drag_sliders,
+            spinner::spinner_buttons_system,
+            toggle::press_toggles,
+        },
+    },
+    AppState, PauseState,
+};
 
 pub struct SettingsMenuPlugin;
 
 impl Plugin for SettingsMenuPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(CoreSettingsPlugin::new(PathBuf::from(
+            "saves/settings.json",
+        )));
+
+        if !app.is_plugin_added::<WidgetsPlugin>() {
+            app.add_plugins(WidgetsPlugin);
+        }
+
+        // The settings menu is opened either from the main menu or from the pause menu.
         app.add_systems(OnEnter(AppState::SettingsMenu), spawn_settings_menu)
+            .add_systems(OnEnter(PauseState::Settings), spawn_settings_menu)
             .add_systems(
                 Update,
                 (
                     load_background_image,
                     load_box_image,
-                    load_tab_image,
                     load_button_image,
-                    load_increment_button_image,
-                    load_decrement_button_image,
                     load_text_font,
-                    set_keys,
-                    add_widget_components,
                 )
-                    .run_if(in_state(AppState::SettingsMenu)),
+                    .run_if(in_state(AppState::SettingsMenu).or(in_state(PauseState::Settings))),
             )
             .add_systems(
                 Update,
                 (
                     save_button_system,
-                    spinner_buttons_system,
-                    get_handle_click_position,
-                    update_handle_position_on_hold,
-                    change_music_volume,
-                    change_sfx_volumes,
+                    change_bus_volumes
+                        .after(drag_sliders)
+                        .after(spinner_buttons_system),
+                    mute_buses.after(press_toggles),
+                    change_display_settings
+                        .after(choose_dropdown_options)
+                        .after(step_focused_dropdowns)
+                        .after(press_toggles)
+                        .after(spinner_buttons_system),
+                    sync_volume_controls
+                        .after(change_bus_volumes)
+                        .after(mute_buses),
                 )
-                    .run_if(in_state(AppState::SettingsMenu)),
+                    .run_if(in_state(AppState::SettingsMenu).or(in_state(PauseState::Settings))),
             )
-            .add_systems(OnExit(AppState::SettingsMenu), unload_settings_menu);
+            .add_systems(OnExit(AppState::SettingsMenu), unload_settings_menu)
+            .add_systems(OnExit(PauseState::Settings), unload_settings_menu);
+    }
+}
+
+/// Loads the settings saved from the last time the game was played, and saves them when asked to.
+pub struct CoreSettingsPlugin {
+    settings_file_path: SettingsFilePath,
+}
+
+impl CoreSettingsPlugin {
+    pub fn new(settings_file_path: PathBuf) -> Self {
+        Self {
+            settings_file_path: SettingsFilePath::new(settings_file_path),
+        }
+    }
+}
+
+impl Plugin for CoreSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = load_settings_from(self.settings_file_path.get_path().clone());
+
+        app.insert_resource(self.settings_file_path.clone())
+            .insert_resource(settings.get_audio_buses().clone())
+            .insert_resource(settings.get_display().clone())
+            .add_message::<SaveSettings>()
+            .add_systems(Update, save_settings);
     }
 }
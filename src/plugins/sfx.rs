@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::{
+    audio::{
+        buses::{AudioBus, AudioBusApp},
+        sfx::*,
+    },
+    map::{
+        interactions::map_changing::ChangeLevel,
+        player::{PlayerInteraction, PlayerMovementActions},
+    },
+};
+
+/// Plays the sound effects set off by the game, as written in the SFX manifest.
+pub struct SfxPlugin;
+
+impl Plugin for SfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(CoreSfxPlugin::new(PathBuf::from("assets/audio/sfx.json")))
+            .add_audio_channel_on_bus::<SfxChannel>(AudioBus::Sfx)
+            .add_systems(Update, play_sfx.after(choose_sfx));
+    }
+}
+
+/// Picks the sound effect of each cue from a manifest, without playing any audio.
+pub struct CoreSfxPlugin {
+    manifest_path: PathBuf,
+}
+
+impl CoreSfxPlugin {
+    pub fn new(manifest_path: PathBuf) -> Self {
+        Self { manifest_path }
+    }
+}
+
+impl Plugin for CoreSfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SfxRegistry::load_from(self.manifest_path.clone()))
+            .init_resource::<SfxCooldowns>()
+            .add_message::<PlayerMovementActions>()
+            .add_message::<PlayerInteraction>()
+            .add_message::<ChangeLevel>()
+            .add_message::<CueSfx>()
+            .add_message::<PlaySfx>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        cue_sfx_from::<PlayerMovementActions>,
+                        cue_sfx_from::<PlayerInteraction>,
+                        cue_sfx_from::<ChangeLevel>,
+                        cue_button_click_sfx,
+                    )
+                        .before(choose_sfx),
+                    choose_sfx,
+                ),
+            );
+    }
+}
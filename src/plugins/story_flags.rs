@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use crate::narrative::story_flags::*;
+
+/// Keeps track of the story flags shared by maps, interactives and acts.
+///
+/// Both CoreLevelsPlugin and CoreActsPlugin depend on it, so they add it themselves
+/// when it hasn't been added yet.
+pub struct StoryFlagsPlugin;
+
+impl Plugin for StoryFlagsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<SetStoryFlag>()
+            .add_message::<QueryStoryFlag>()
+            .add_message::<StoryFlagReport>()
+            .init_resource::<StoryFlags>()
+            .add_systems(
+                Update,
+                (
+                    set_story_flags,
+                    answer_story_flag_queries.after(set_story_flags),
+                ),
+            );
+    }
+}
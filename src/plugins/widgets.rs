@@ -0,0 +1,159 @@
+use bevy::{input::InputSystems, prelude::*};
+
+use crate::ui::widgets::{
+    dropdown::*, focus::*, load_widget_button_image, load_widget_font, slider::*, spinner::*,
+    tab_bar::*, toggle::*,
+};
+
+/// Every widget menus are built out of.
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            SliderPlugin,
+            SpinnerPlugin,
+            TogglePlugin,
+            DropdownPlugin,
+            TabBarPlugin,
+        ));
+    }
+}
+
+/// Moves the focus between widgets with the keyboard or a controller, and gives every
+/// widget its shared look. Each widget plugin adds this if it's missing.
+pub struct WidgetBasePlugin;
+
+impl Plugin for WidgetBasePlugin {
+    fn build(&self, app: &mut App) {
+        // Focus is handled before Update, so a widget pressed through it is seen as pressed
+        // by every system checking for clicks that frame.
+        app.add_message::<FocusInput>()
+            .add_systems(
+                PreUpdate,
+                (
+                    release_focus_presses,
+                    read_focus_input.run_if(any_with_component::<Focusable>),
+                    navigate_focus,
+                )
+                    .chain()
+                    .after(InputSystems),
+            )
+            .add_systems(
+                Update,
+                (
+                    focus_clicked_widgets,
+                    outline_focused_widget.after(focus_clicked_widgets),
+                    load_widget_font,
+                    load_widget_button_image,
+                ),
+            );
+    }
+}
+
+fn add_widget_base(app: &mut App) {
+    if !app.is_plugin_added::<WidgetBasePlugin>() {
+        app.add_plugins(WidgetBasePlugin);
+    }
+}
+
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        add_widget_base(app);
+
+        app.add_message::<SliderChanged>().add_systems(
+            Update,
+            (
+                step_focused_sliders,
+                drag_sliders,
+                update_slider_fills
+                    .after(step_focused_sliders)
+                    .after(drag_sliders),
+            ),
+        );
+    }
+}
+
+pub struct SpinnerPlugin;
+
+impl Plugin for SpinnerPlugin {
+    fn build(&self, app: &mut App) {
+        add_widget_base(app);
+
+        app.add_message::<SpinnerChanged>().add_systems(
+            Update,
+            (
+                step_focused_spinners,
+                spinner_buttons_system,
+                update_spinner_texts
+                    .after(step_focused_spinners)
+                    .after(spinner_buttons_system),
+                load_spinner_button_images,
+            ),
+        );
+    }
+}
+
+pub struct TogglePlugin;
+
+impl Plugin for TogglePlugin {
+    fn build(&self, app: &mut App) {
+        add_widget_base(app);
+
+        app.add_message::<ToggleChanged>().add_systems(
+            Update,
+            (
+                press_toggles,
+                switch_focused_toggles,
+                update_toggle_texts
+                    .after(press_toggles)
+                    .after(switch_focused_toggles),
+            ),
+        );
+    }
+}
+
+pub struct DropdownPlugin;
+
+impl Plugin for DropdownPlugin {
+    fn build(&self, app: &mut App) {
+        add_widget_base(app);
+
+        app.add_message::<DropdownChanged>().add_systems(
+            Update,
+            (
+                press_dropdowns,
+                // Focus goes back to the dropdown once an option is chosen, so the click
+                // focusing the option has to come first.
+                choose_dropdown_options.after(focus_clicked_widgets),
+                step_focused_dropdowns,
+                update_dropdowns
+                    .after(press_dropdowns)
+                    .after(choose_dropdown_options)
+                    .after(step_focused_dropdowns),
+            ),
+        );
+    }
+}
+
+pub struct TabBarPlugin;
+
+impl Plugin for TabBarPlugin {
+    fn build(&self, app: &mut App) {
+        add_widget_base(app);
+
+        app.add_message::<TabChanged>().add_systems(
+            Update,
+            (
+                press_tab_buttons,
+                step_focused_tabs,
+                show_selected_tabs
+                    .after(press_tab_buttons)
+                    .after(step_focused_tabs),
+                load_tab_button_images,
+            ),
+        );
+    }
+}
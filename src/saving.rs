@@ -0,0 +1,210 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufReader},
+    path::PathBuf,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    map::{
+        interactions::{map_changing::CurrentLevel, triggers::TriggerHistory},
+        items::{Inventory, PickedUpItems},
+        player::Player,
+        GridCords3D,
+    },
+    narrative::{quests::QuestLog, story_flags::StoryFlags},
+};
+
+/// Everything needed to pick the game back up from where it was saved.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveData {
+    level_path: Option<PathBuf>,
+    player_position: Option<GridCords3D>,
+    story_flags: StoryFlags,
+    quest_log: Option<QuestLog>,
+    #[serde(default)]
+    inventory: Inventory,
+    #[serde(default)]
+    picked_up_items: PickedUpItems,
+    #[serde(default)]
+    trigger_history: TriggerHistory,
+}
+
+impl SaveData {
+    pub fn new(
+        level_path: Option<PathBuf>,
+        story_flags: StoryFlags,
+        quest_log: Option<QuestLog>,
+    ) -> Self {
+        Self {
+            level_path,
+            player_position: None,
+            story_flags,
+            quest_log,
+            inventory: Inventory::default(),
+            picked_up_items: PickedUpItems::default(),
+            trigger_history: TriggerHistory::default(),
+        }
+    }
+
+    /// Returns the save data, along with where the Player was standing.
+    pub fn with_player_position(mut self, player_position: Option<GridCords3D>) -> Self {
+        self.player_position = player_position;
+        self
+    }
+
+    /// Returns the save data, along with what the Player was carrying.
+    pub fn with_inventory(mut self, inventory: Inventory) -> Self {
+        self.inventory = inventory;
+        self
+    }
+
+    /// Returns the save data, along with which items were already picked up and which
+    /// triggers already fired, so neither comes back after loading.
+    pub fn with_level_history(
+        mut self,
+        picked_up_items: PickedUpItems,
+        trigger_history: TriggerHistory,
+    ) -> Self {
+        self.picked_up_items = picked_up_items;
+        self.trigger_history = trigger_history;
+        self
+    }
+
+    pub fn get_level_path(&self) -> &Option<PathBuf> {
+        &self.level_path
+    }
+
+    pub fn get_player_position(&self) -> &Option<GridCords3D> {
+        &self.player_position
+    }
+
+    pub fn get_story_flags(&self) -> &StoryFlags {
+        &self.story_flags
+    }
+
+    pub fn get_quest_log(&self) -> &Option<QuestLog> {
+        &self.quest_log
+    }
+
+    pub fn get_inventory(&self) -> &Inventory {
+        &self.inventory
+    }
+
+    pub fn get_picked_up_items(&self) -> &PickedUpItems {
+        &self.picked_up_items
+    }
+
+    pub fn get_trigger_history(&self) -> &TriggerHistory {
+        &self.trigger_history
+    }
+}
+
+/// Where the game gets saved to.
+#[derive(Resource, Clone)]
+pub struct SaveFilePath {
+    file_path: PathBuf,
+}
+
+impl SaveFilePath {
+    pub fn new(file_path: PathBuf) -> Self {
+        Self { file_path }
+    }
+
+    pub fn get_path(&self) -> &PathBuf {
+        &self.file_path
+    }
+}
+
+/// A request to save the game as it is right now.
+#[derive(Message, Debug, Clone, Default)]
+pub struct SaveGame;
+
+impl SaveGame {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// How saving the game went, so it can be shown to the player.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum SaveOutcome {
+    Saved,
+    Failed(String),
+}
+
+/// Writes the current level, the Player, story flags, quests and what was already picked up
+/// or triggered to the save file when asked to.
+pub fn save_game(
+    mut save_requests: MessageReader<SaveGame>,
+    mut save_outcomes: MessageWriter<SaveOutcome>,
+    save_file_path: Res<SaveFilePath>,
+    player: Query<(&GridCords3D, &Inventory), With<Player>>,
+    current_level: Option<Res<CurrentLevel>>,
+    story_flags: Option<Res<StoryFlags>>,
+    quest_log: Option<Res<QuestLog>>,
+    picked_up_items: Option<Res<PickedUpItems>>,
+    trigger_history: Option<Res<TriggerHistory>>,
+) {
+    if save_requests.is_empty() {
+        return;
+    }
+
+    save_requests.clear();
+
+    let found_player = player.single().ok();
+    let player_position = found_player.map(|(grid_cords, _)| *grid_cords);
+    let inventory = found_player
+        .map(|(_, inventory)| inventory.clone())
+        .unwrap_or_default();
+
+    let save_data = SaveData::new(
+        current_level.map(|level| level.get_level_path().clone()),
+        story_flags.map(|flags| flags.clone()).unwrap_or_default(),
+        quest_log.map(|quests| quests.clone()),
+    )
+    .with_player_position(player_position)
+    .with_inventory(inventory)
+    .with_level_history(
+        picked_up_items
+            .map(|items| items.clone())
+            .unwrap_or_default(),
+        trigger_history
+            .map(|history| history.clone())
+            .unwrap_or_default(),
+    );
+
+    let save_outcome = match write_save_to(save_file_path.get_path(), &save_data) {
+        Ok(()) => SaveOutcome::Saved,
+        Err(error) => {
+            warn!("save_game: Unable to write the save file: {}", error);
+            SaveOutcome::Failed(error.to_string())
+        }
+    };
+
+    save_outcomes.write(save_outcome);
+}
+
+/// Writes some save data to a save file, creating its folder if needed.
+pub fn write_save_to(save_file: &PathBuf, save_data: &SaveData) -> io::Result<()> {
+    if let Some(save_folder) = save_file.parent() {
+        fs::create_dir_all(save_folder)?;
+    }
+
+    let file = File::create(save_file)?;
+    serde_json::to_writer_pretty(file, save_data)?;
+
+    Ok(())
+}
+
+/// Returns the save data written to some save file, or why it couldn't be read.
+pub fn load_save_from(save_file: PathBuf) -> io::Result<SaveData> {
+    let file = File::open(save_file)?;
+    let reader = BufReader::new(file);
+
+    let save_data = serde_json::from_reader(reader)?;
+
+    Ok(save_data)
+}
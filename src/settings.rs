@@ -0,0 +1,102 @@
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::PathBuf,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{audio::buses::AudioBuses, display::DisplaySettings};
+
+/// The player's preferences, kept between plays of the game.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    #[serde(default)]
+    audio_buses: AudioBuses,
+    #[serde(default)]
+    display: DisplaySettings,
+}
+
+impl Settings {
+    pub fn new(audio_buses: AudioBuses, display: DisplaySettings) -> Self {
+        Self {
+            audio_buses,
+            display,
+        }
+    }
+
+    pub fn get_audio_buses(&self) -> &AudioBuses {
+        &self.audio_buses
+    }
+
+    pub fn get_display(&self) -> &DisplaySettings {
+        &self.display
+    }
+}
+
+/// Where the settings get saved to.
+#[derive(Resource, Clone)]
+pub struct SettingsFilePath {
+    file_path: PathBuf,
+}
+
+impl SettingsFilePath {
+    pub fn new(file_path: PathBuf) -> Self {
+        Self { file_path }
+    }
+
+    pub fn get_path(&self) -> &PathBuf {
+        &self.file_path
+    }
+}
+
+/// A request to save the settings as they are right now.
+#[derive(Message, Debug, Clone, Default)]
+pub struct SaveSettings;
+
+impl SaveSettings {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Writes the settings to the settings file when asked to.
+pub fn save_settings(
+    mut save_requests: MessageReader<SaveSettings>,
+    settings_file_path: Res<SettingsFilePath>,
+    audio_buses: Res<AudioBuses>,
+    display_settings: Res<DisplaySettings>,
+) {
+    if save_requests.is_empty() {
+        return;
+    }
+
+    save_requests.clear();
+
+    let settings = Settings::new(audio_buses.clone(), display_settings.clone());
+
+    let settings_file = settings_file_path.get_path();
+    if let Some(settings_folder) = settings_file.parent() {
+        fs::create_dir_all(settings_folder)
+            .expect("save_settings: Unable to create the settings folder.");
+    }
+
+    let file =
+        File::create(settings_file).expect("save_settings: Unable to create the settings file.");
+    serde_json::to_writer_pretty(file, &settings)
+        .expect("save_settings: Unable to write the settings file.");
+}
+
+/// Returns the settings written to some settings file, or the default settings when
+/// they've never been saved.
+pub fn load_settings_from(settings_file: PathBuf) -> Settings {
+    if !settings_file.exists() {
+        return Settings::default();
+    }
+
+    let file = File::open(settings_file).expect("load_settings_from: Unable to open file");
+    let reader = BufReader::new(file);
+
+    serde_json::from_reader(reader).expect("load_settings_from: Unable to parse the settings file.")
+}
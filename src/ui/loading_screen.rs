@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::{loading::LoadingAssets, map::interactions::map_changing::CameraBundle};
+
+use super::menus::{ColoredNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const LOADING_BACKGROUND: Color = Color::srgb(0.05, 0.05, 0.1);
+const PROGRESS_BAR_BACKGROUND: Color = Color::srgb(0.2, 0.2, 0.25);
+const PROGRESS_BAR_FILL: Color = Color::srgb(0.49, 0.29, 0.14);
+
+/// Identifies everything that makes up the loading screen.
+#[derive(Component)]
+pub struct LoadingScreenUI;
+
+/// Identifies the part of the progress bar that fills up as assets are loaded.
+#[derive(Component)]
+pub struct LoadingProgressBar;
+
+/// Identifies the text showing how far along loading is.
+#[derive(Component)]
+pub struct LoadingProgressText;
+
+/// Shows a progress bar while the assets of the next level or act are loaded.
+///
+/// It isn't shown when they're already loaded, such as a level used earlier on in the act,
+/// since the scene is entered again right away.
+pub fn spawn_loading_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    loading_assets: Res<LoadingAssets>,
+) {
+    if loading_assets.is_finished(&asset_server) {
+        return;
+    }
+
+    let ui_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Percent(3.0),
+                ..default()
+            },
+            BackgroundColor(LOADING_BACKGROUND),
+        ),
+        // Drawn over everything else, such as a level transition left covering the screen
+        // while the next level loads.
+        GlobalZIndex(40),
+        LoadingScreenUI,
+    );
+
+    let progress_text = (
+        TextNodeBundle::from_nodes(
+            Text::new("Loading... 0%"),
+            TextFont {
+                font: asset_server.load("fonts/Untitled.ttf"),
+                font_size: 40.0,
+                ..default()
+            },
+            TextColor(WHITE),
+        ),
+        LoadingProgressText,
+    );
+
+    let progress_bar = ColoredNodeBundle::from_nodes(
+        Node {
+            width: Val::Percent(50.0),
+            height: Val::Px(30.0),
+            ..default()
+        },
+        BackgroundColor(PROGRESS_BAR_BACKGROUND),
+    );
+
+    let progress_bar_fill = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                width: Val::Percent(0.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(PROGRESS_BAR_FILL),
+        ),
+        LoadingProgressBar,
+    );
+
+    commands.spawn((CameraBundle::default(), LoadingScreenUI));
+
+    commands.spawn(ui_container).with_children(|ui_container| {
+        ui_container.spawn(progress_text);
+        ui_container
+            .spawn(progress_bar)
+            .with_children(|progress_bar| {
+                progress_bar.spawn(progress_bar_fill);
+            });
+    });
+}
+
+/// Fills the progress bar up as far as loading has come along.
+pub fn show_loading_progress(
+    loading_assets: Res<LoadingAssets>,
+    asset_server: Res<AssetServer>,
+    mut progress_bar: Query<&mut Node, With<LoadingProgressBar>>,
+    mut progress_text: Query<&mut Text, With<LoadingProgressText>>,
+) {
+    let progress = loading_assets.get_progress(&asset_server);
+
+    for mut progress_bar_node in &mut progress_bar {
+        progress_bar_node.width = Val::Percent(progress * 100.0);
+    }
+
+    for mut text in &mut progress_text {
+        text.0 = format!("Loading... {}%", (progress * 100.0).round() as u32);
+    }
+}
+
+pub fn unload_loading_screen(
+    mut commands: Commands,
+    loading_screen_ui: Query<Entity, With<LoadingScreenUI>>,
+) {
+    for ui_entity in &loading_screen_ui {
+        commands.entity(ui_entity).despawn();
+    }
+}
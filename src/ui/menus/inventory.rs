@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use crate::{
+    map::{
+        items::{Inventory, Item},
+        player::Player,
+    },
+    PauseState,
+};
+
+use super::{ButtonNodeBundle, ColoredNodeBundle, ImageNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const INVENTORY_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.9);
+
+#[derive(Component)]
+pub enum InventoryButtonTypes {
+    Close,
+}
+
+/// Identifies everything that makes up the inventory screen.
+#[derive(Component)]
+pub struct InventoryUI;
+
+/// Identifies the label under an item shown on the inventory screen.
+#[derive(Component)]
+pub struct InventorySlotText;
+
+/// Opens the inventory while playing, and closes it again while it's open.
+pub fn toggle_inventory(
+    input: Res<ButtonInput<KeyCode>>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    match pause_state.get() {
+        PauseState::Running => {
+            if input.just_pressed(KeyCode::KeyI) {
+                next_pause_state.set(PauseState::Inventory);
+            }
+        }
+        PauseState::Inventory => {
+            if input.any_just_pressed([KeyCode::KeyI, KeyCode::Escape]) {
+                next_pause_state.set(PauseState::Running);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn inventory_button_system(
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+    interaction_query: Query<
+        (&Interaction, &InventoryButtonTypes),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (interaction, button_type) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button_type {
+            InventoryButtonTypes::Close => next_pause_state.set(PauseState::Running),
+        }
+    }
+}
+
+/// Shows every item the player is carrying on top of the level.
+pub fn spawn_inventory_screen(
+    mut commands: Commands,
+    player: Query<&Inventory, With<Player>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_assets: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let font = asset_server.load("fonts/Untitled.ttf");
+
+    let ui_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            BackgroundColor(INVENTORY_BACKGROUND),
+        ),
+        ZIndex(20),
+        InventoryUI,
+    );
+
+    let title_text = create_text(String::from("Inventory"), font.clone(), 80.0);
+
+    let item_grid = Node {
+        width: Val::Percent(80.0),
+        flex_wrap: FlexWrap::Wrap,
+        justify_content: JustifyContent::Center,
+        column_gap: Val::Px(40.0),
+        row_gap: Val::Px(40.0),
+        ..default()
+    };
+
+    let close_button = (
+        ButtonNodeBundle {
+            node: Node {
+                width: Val::Percent(15.0),
+                height: Val::Percent(12.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            image: ImageNode::new(asset_server.load("textures/main_menu/button.png")),
+            ..default()
+        },
+        InventoryButtonTypes::Close,
+    );
+    let close_text = create_text(String::from("Close"), font.clone(), 40.0);
+
+    let carried_items = match player.single() {
+        Ok(inventory) => inventory.get_items().clone(),
+        Err(_) => Vec::new(),
+    };
+
+    commands.spawn(ui_container).with_children(|ui_container| {
+        ui_container.spawn(title_text);
+
+        ui_container.spawn(item_grid).with_children(|item_grid| {
+            if carried_items.is_empty() {
+                item_grid.spawn(create_text(
+                    String::from("Your pockets are empty."),
+                    font.clone(),
+                    40.0,
+                ));
+            }
+
+            for item in &carried_items {
+                let item_slot = create_item_slot(item, &asset_server, &mut texture_atlas_assets);
+                let item_label = (
+                    create_text(
+                        format!("{} x{}", item.get_name(), item.get_quantity()),
+                        font.clone(),
+                        30.0,
+                    ),
+                    InventorySlotText,
+                );
+
+                item_grid
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    })
+                    .with_children(|slot| {
+                        slot.spawn(item_slot);
+                        slot.spawn(item_label);
+                    });
+            }
+        });
+
+        ui_container
+            .spawn(close_button)
+            .with_children(|close_button| {
+                close_button.spawn(close_text);
+            });
+    });
+}
+
+pub fn unload_inventory_screen(
+    mut commands: Commands,
+    inventory_ui: Query<Entity, With<InventoryUI>>,
+) {
+    for ui_entity in &inventory_ui {
+        commands.entity(ui_entity).despawn();
+    }
+}
+
+/// Returns the icon of an item, cut out of its spritesheet.
+fn create_item_slot(
+    item: &Item,
+    asset_server: &AssetServer,
+    texture_atlas_assets: &mut Assets<TextureAtlasLayout>,
+) -> ImageNodeBundle {
+    let icon = item.get_icon();
+    let tile_dimensions = icon.get_tile_dimensions();
+    let spritesheet_dimensions = icon.get_spritesheet_dimensions();
+
+    let sheet_layout = TextureAtlasLayout::from_grid(
+        UVec2::new(
+            tile_dimensions.get_width() as u32,
+            tile_dimensions.get_height() as u32,
+        ),
+        (spritesheet_dimensions.get_width() / tile_dimensions.get_width()) as u32,
+        (spritesheet_dimensions.get_height() / tile_dimensions.get_height()) as u32,
+        None,
+        None,
+    );
+
+    let texture_atlas = TextureAtlas {
+        layout: texture_atlas_assets.add(sheet_layout),
+        index: icon.get_sprite_index(),
+    };
+
+    ImageNodeBundle {
+        node: Node {
+            width: Val::Px(tile_dimensions.get_width() as f32 * 1.5),
+            height: Val::Px(tile_dimensions.get_height() as f32 * 1.5),
+            ..default()
+        },
+        image: ImageNode::from_atlas_image(
+            asset_server.load(icon.get_spritesheet().clone()),
+            texture_atlas,
+        ),
+    }
+}
+
+fn create_text(text: String, font: Handle<Font>, font_size: f32) -> TextNodeBundle {
+    TextNodeBundle {
+        text: Text::new(text),
+        font: TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        color: TextColor(WHITE),
+    }
+}
@@ -1,4 +1,7 @@
+pub mod inventory;
 pub mod main_menu;
+pub mod pause_menu;
+pub mod quest_log;
 pub mod settings_menu;
 use bevy::prelude::*;
 
@@ -40,6 +43,16 @@ impl Default for ButtonNodeBundle {
     }
 }
 
+impl ButtonNodeBundle {
+    pub fn from_nodes(node: Node, image: ImageNode) -> Self {
+        Self {
+            node,
+            image,
+            button: Button::default(),
+        }
+    }
+}
+
 #[derive(Bundle)]
 pub struct TextNodeBundle {
     text: Text,
@@ -57,6 +70,12 @@ impl Default for TextNodeBundle {
     }
 }
 
+impl TextNodeBundle {
+    pub fn from_nodes(text: Text, font: TextFont, color: TextColor) -> Self {
+        Self { text, font, color }
+    }
+}
+
 #[derive(Bundle)]
 pub struct ColoredNodeBundle {
     node: Node,
@@ -71,3 +90,12 @@ impl Default for ColoredNodeBundle {
         }
     }
 }
+
+impl ColoredNodeBundle {
+    pub fn from_nodes(node: Node, background_color: BackgroundColor) -> Self {
+        Self {
+            node,
+            background_color,
+        }
+    }
+}
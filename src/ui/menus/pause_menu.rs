@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+use crate::{
+    saving::{SaveGame, SaveOutcome},
+    AppState, PauseState,
+};
+
+use super::{ButtonNodeBundle, ColoredNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const PAUSE_MENU_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.75);
+
+#[derive(Component)]
+pub enum PauseMenuButtonTypes {
+    Resume,
+    Settings,
+    Save,
+    QuitToTitle,
+}
+
+/// Identifies everything that makes up the pause menu.
+#[derive(Component)]
+pub struct PauseMenuUI;
+
+/// Identifies the line under the pause menu buttons telling how saving went.
+#[derive(Component)]
+pub struct SaveStatusText;
+
+/// Pauses the game while playing, and resumes it again while the pause menu is open.
+/// Backing out of the settings menu opened from the pause menu returns to the pause menu.
+pub fn toggle_pause_menu(
+    input: Res<ButtonInput<KeyCode>>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    if !input.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    match pause_state.get() {
+        PauseState::Running => next_pause_state.set(PauseState::Paused),
+        PauseState::Paused => next_pause_state.set(PauseState::Running),
+        PauseState::Settings => next_pause_state.set(PauseState::Paused),
+        _ => {}
+    }
+}
+
+pub fn pause_menu_button_system(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+    mut save_requests: MessageWriter<SaveGame>,
+    interaction_query: Query<
+        (&Interaction, &PauseMenuButtonTypes),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (interaction, button_type) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button_type {
+            PauseMenuButtonTypes::Resume => next_pause_state.set(PauseState::Running),
+            PauseMenuButtonTypes::Settings => next_pause_state.set(PauseState::Settings),
+            PauseMenuButtonTypes::Save => {
+                save_requests.write(SaveGame::new());
+            }
+            PauseMenuButtonTypes::QuitToTitle => next_state.set(AppState::MainMenu),
+        }
+    }
+}
+
+/// Tells the player whether their game was saved, or why it couldn't be.
+pub fn show_save_outcome(
+    mut save_outcomes: MessageReader<SaveOutcome>,
+    mut save_status_texts: Query<&mut Text, With<SaveStatusText>>,
+) {
+    let Some(save_outcome) = save_outcomes.read().last() else {
+        return;
+    };
+
+    let status = match save_outcome {
+        SaveOutcome::Saved => String::from("Game saved."),
+        SaveOutcome::Failed(reason) => format!("Could not save the game: {}", reason),
+    };
+
+    for mut save_status_text in &mut save_status_texts {
+        *save_status_text = Text::new(status.clone());
+    }
+}
+
+/// Stops time for everything that moves or counts down, such as the player and cutscenes.
+pub fn freeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+/// Lets time pass again once the game is back to being played or left altogether.
+pub fn unfreeze_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+/// Shows the pause menu on top of the level.
+pub fn spawn_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/Untitled.ttf");
+
+    let ui_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Percent(3.0),
+                ..default()
+            },
+            BackgroundColor(PAUSE_MENU_BACKGROUND),
+        ),
+        ZIndex(20),
+        PauseMenuUI,
+    );
+
+    let title_text = create_text(String::from("Paused"), font.clone(), 80.0);
+
+    let buttons = [
+        (PauseMenuButtonTypes::Resume, "Resume"),
+        (PauseMenuButtonTypes::Settings, "Settings"),
+        (PauseMenuButtonTypes::Save, "Save"),
+        (PauseMenuButtonTypes::QuitToTitle, "Quit to Title"),
+    ];
+
+    commands.spawn(ui_container).with_children(|ui_container| {
+        ui_container.spawn(title_text);
+
+        for (button_type, button_text) in buttons {
+            let button = (
+                ButtonNodeBundle::from_nodes(
+                    Node {
+                        width: Val::Percent(20.0),
+                        height: Val::Percent(10.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    ImageNode::new(asset_server.load("textures/main_menu/button.png")),
+                ),
+                button_type,
+            );
+
+            ui_container.spawn(button).with_children(|button| {
+                button.spawn(create_text(String::from(button_text), font.clone(), 40.0));
+            });
+        }
+
+        ui_container.spawn((
+            create_text(String::new(), font.clone(), 30.0),
+            SaveStatusText,
+        ));
+    });
+}
+
+pub fn unload_pause_menu(mut commands: Commands, pause_menu_ui: Query<Entity, With<PauseMenuUI>>) {
+    for ui_entity in &pause_menu_ui {
+        commands.entity(ui_entity).despawn();
+    }
+}
+
+fn create_text(text: String, font: Handle<Font>, font_size: f32) -> TextNodeBundle {
+    TextNodeBundle {
+        text: Text::new(text),
+        font: TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        color: TextColor(WHITE),
+    }
+}
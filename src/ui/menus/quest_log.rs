@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::{narrative::quests::QuestLog, PauseState};
+
+use super::{ButtonNodeBundle, ColoredNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const GREY: Color = Color::srgb(0.6, 0.6, 0.6);
+const QUEST_LOG_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.9);
+
+#[derive(Component)]
+pub enum QuestLogButtonTypes {
+    Close,
+}
+
+/// Identifies everything that makes up the quest log screen.
+#[derive(Component)]
+pub struct QuestLogUI;
+
+/// Identifies the line written for each objective on the quest log screen.
+#[derive(Component)]
+pub struct QuestObjectiveText;
+
+/// Opens the quest log while playing, and closes it again while it's open.
+pub fn toggle_quest_log(
+    input: Res<ButtonInput<KeyCode>>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+) {
+    match pause_state.get() {
+        PauseState::Running => {
+            if input.just_pressed(KeyCode::KeyJ) {
+                next_pause_state.set(PauseState::QuestLog);
+            }
+        }
+        PauseState::QuestLog => {
+            if input.any_just_pressed([KeyCode::KeyJ, KeyCode::Escape]) {
+                next_pause_state.set(PauseState::Running);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn quest_log_button_system(
+    mut next_pause_state: ResMut<NextState<PauseState>>,
+    interaction_query: Query<
+        (&Interaction, &QuestLogButtonTypes),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (interaction, button_type) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button_type {
+            QuestLogButtonTypes::Close => next_pause_state.set(PauseState::Running),
+        }
+    }
+}
+
+/// Shows every quest and how far along its objectives are on top of the level.
+pub fn spawn_quest_log_screen(
+    mut commands: Commands,
+    quest_log: Res<QuestLog>,
+    asset_server: Res<AssetServer>,
+) {
+    let font = asset_server.load("fonts/Untitled.ttf");
+
+    let ui_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceEvenly,
+                ..default()
+            },
+            BackgroundColor(QUEST_LOG_BACKGROUND),
+        ),
+        ZIndex(20),
+        QuestLogUI,
+    );
+
+    let title_text = create_text(String::from("Quests"), font.clone(), 80.0, WHITE);
+
+    let quest_list = Node {
+        width: Val::Percent(80.0),
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(30.0),
+        ..default()
+    };
+
+    let close_button = (
+        ButtonNodeBundle::from_nodes(
+            Node {
+                width: Val::Percent(15.0),
+                height: Val::Percent(12.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ImageNode::new(asset_server.load("textures/main_menu/button.png")),
+        ),
+        QuestLogButtonTypes::Close,
+    );
+    let close_text = create_text(String::from("Close"), font.clone(), 40.0, WHITE);
+
+    commands.spawn(ui_container).with_children(|ui_container| {
+        ui_container.spawn(title_text);
+
+        ui_container.spawn(quest_list).with_children(|quest_list| {
+            if quest_log.get_quests().is_empty() {
+                quest_list.spawn(create_text(
+                    String::from("Nobody needs a hand right now."),
+                    font.clone(),
+                    40.0,
+                    WHITE,
+                ));
+            }
+
+            for quest in quest_log.get_quests() {
+                let objectives = quest.get_definition().get_objectives();
+                let quest_title = create_text(
+                    format!(
+                        "{} ({}/{})",
+                        quest.get_title(),
+                        quest.get_num_completed_objectives(),
+                        objectives.len()
+                    ),
+                    font.clone(),
+                    40.0,
+                    WHITE,
+                );
+
+                quest_list
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|quest_entry| {
+                        quest_entry.spawn(quest_title);
+
+                        for (objective_index, objective) in objectives.iter().enumerate() {
+                            let is_completed = quest.is_objective_completed(objective_index);
+                            let (check_mark, color) = if is_completed {
+                                ("[x]", GREY)
+                            } else {
+                                ("[ ]", WHITE)
+                            };
+
+                            let target = objective.get_goal().get_target();
+                            // Only objectives that need more than one of something show a count.
+                            let progress = if target > 1 {
+                                format!(
+                                    " {}/{}",
+                                    quest.get_objective_progress(objective_index).min(target),
+                                    target
+                                )
+                            } else {
+                                String::new()
+                            };
+
+                            quest_entry.spawn((
+                                create_text(
+                                    format!(
+                                        "{} {}{}",
+                                        check_mark,
+                                        objective.get_description(),
+                                        progress
+                                    ),
+                                    font.clone(),
+                                    30.0,
+                                    color,
+                                ),
+                                QuestObjectiveText,
+                            ));
+                        }
+                    });
+            }
+        });
+
+        ui_container
+            .spawn(close_button)
+            .with_children(|close_button| {
+                close_button.spawn(close_text);
+            });
+    });
+}
+
+pub fn unload_quest_log_screen(
+    mut commands: Commands,
+    quest_log_ui: Query<Entity, With<QuestLogUI>>,
+) {
+    for ui_entity in &quest_log_ui {
+        commands.entity(ui_entity).despawn();
+    }
+}
+
+fn create_text(text: String, font: Handle<Font>, font_size: f32, color: Color) -> TextNodeBundle {
+    TextNodeBundle {
+        text: Text::new(text),
+        font: TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        color: TextColor(color),
+    }
+}
@@ -1,76 +1,83 @@
-use bevy::{prelude::*, window::PrimaryWindow};
-use bevy_kira_audio::{AudioChannel, AudioControl};
+use bevy::{
+    prelude::*,
+    window::{PrimaryMonitor, PrimaryWindow},
+};
 
 use crate::{
-    audio::music::*,
-    map::{interactions::map_changing::CameraBundle, player::*},
-    AppState,
+    audio::buses::{AudioBus, AudioBuses},
+    display::{
+        get_resolution_options, get_window_resolution, DisplaySettings, Resolution,
+        WindowModeSetting,
+    },
+    map::interactions::map_changing::CameraBundle,
+    settings::SaveSettings,
+    ui::widgets::{
+        create_widget_text,
+        dropdown::{spawn_dropdown, Dropdown, DropdownChanged},
+        focus::Focusable,
+        slider::{spawn_slider, Slider, SliderChanged},
+        spinner::{spawn_spinner, Spinner, SpinnerChanged},
+        tab_bar::{spawn_tab_bar, TabBar, TabPage},
+        toggle::{spawn_toggle, Toggle, ToggleChanged},
+    },
+    AppState, PauseState,
 };
 
-use super::{ButtonNodeBundle, ColoredNodeBundle, ImageNodeBundle, TextNodeBundle};
+use super::{ButtonNodeBundle, ImageNodeBundle, TextNodeBundle};
 
 // Setup
 pub const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
-pub const DBROWN: Color = Color::srgb(0.49, 0.29, 0.14);
 //pub const LBROWN: Color = Color::rgb(0.72, 0.53, 0.36);
 
 #[derive(Component, PartialEq)]
 pub enum ButtonTypes {
     Apply,
     Cancel,
-    Slider,
-    Increment,
-    Decrement,
 }
 
 #[derive(Component)]
 pub enum SettingsMenuElements {
     BackgroundImage,
     OptionsBox,
-    TabBox,
     Button,
-    IncrementButton,
-    DecrementButton,
     Text,
 }
 
-#[derive(Component, Copy, Clone)]
-pub enum CountingSliderKeys {
-    Handle = 0,
-    Fill = 1,
-    Value = 2,
-    Decrement = 3,
-    Increment = 4,
-    Back = 5,
-}
-
 #[derive(Component)]
-pub struct SliderKeyComponents {
-    pub array: [Option<Entity>; 6],
-}
-
-#[derive(Component, Clone, Copy)]
-pub struct FillReference(pub Entity);
-
-#[derive(Component, Clone, Copy)]
-pub struct ValueReference(pub Entity);
-
-#[derive(Component, Clone, Copy)]
-pub struct HandleReference(pub Entity);
+pub struct SettingsMenuUI;
 
-#[derive(Component, Clone, Copy)]
-pub struct BackReference(pub Entity);
+/// A slider or spinner setting the volume of some audio bus, out of 100.
+#[derive(Component)]
+pub struct VolumeControl(pub AudioBus);
 
+/// The toggle muting some audio bus.
 #[derive(Component)]
-pub struct SettingsMenuUI;
+pub struct MuteToggle(pub AudioBus);
 
-#[derive(Component, PartialEq)]
-pub enum AudioType {
-    Music,
-    SFX,
+/// A widget changing one of the display settings.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayControl {
+    WindowMode,
+    Resolution,
+    Vsync,
+    /// Sets the UI scale as a percentage.
+    UiScale,
+    PixelPerfect,
+    CameraZoom,
 }
 
-pub fn spawn_settings_menu(mut commands: Commands) {
+/// The resolutions offered by the resolution dropdown, in the order of its options.
+#[derive(Component)]
+pub struct ResolutionOptions(pub Vec<Resolution>);
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    camera_query: Query<&Camera>,
+    audio_buses: Res<AudioBuses>,
+    display_settings: Res<DisplaySettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+) {
     let ui_container = (
         ImageNodeBundle {
             node: Node {
@@ -85,6 +92,8 @@ pub fn spawn_settings_menu(mut commands: Commands) {
         },
         SettingsMenuUI,
         SettingsMenuElements::BackgroundImage,
+        // Keeps the menu on top of the level when opened from the pause menu.
+        ZIndex(20),
     );
 
     let top_third = Node {
@@ -122,60 +131,37 @@ pub fn spawn_settings_menu(mut commands: Commands) {
         ..default()
     };
 
-    let tabs_container = (
-        ButtonNodeBundle {
-            node: Node {
-                width: Val::Percent(18.82),
-                height: Val::Percent(9.434),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                padding: UiRect {
-                    left: Val::Percent(0.0),
-                    right: Val::Percent(0.0),
-                    top: Val::Percent(0.6),
-                    bottom: Val::Percent(0.0),
-                },
-                ..default()
-            },
-            ..default()
-        },
-        SettingsMenuElements::TabBox,
-    );
+    let tab_bar = TabBar::new(vec![String::from("Audio"), String::from("Display")]);
 
-    let tab_text = (
-        TextNodeBundle {
-            text: Text::new("Audio"),
-            font: TextFont {
-                font_size: 25.0,
+    let tab_bar_container = Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(9.434),
+        ..default()
+    };
 
-                ..default()
-            },
-            color: TextColor(WHITE),
+    let tab = Node {
+        width: Val::Percent(18.82),
+        height: Val::Percent(100.0),
+        padding: UiRect {
+            left: Val::Percent(0.0),
+            right: Val::Percent(0.0),
+            top: Val::Percent(0.6),
+            bottom: Val::Percent(0.0),
         },
-        SettingsMenuElements::Text,
-    );
+        ..default()
+    };
 
-    let options_container = (
-        ImageNodeBundle {
-            node: Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                flex_direction: FlexDirection::Column,
-                justify_content: JustifyContent::FlexStart,
-                align_items: AlignItems::Center,
-                padding: UiRect {
-                    left: Val::Percent(0.0),
-                    right: Val::Percent(0.0),
-                    top: Val::Percent(2.0),
-                    bottom: Val::Percent(0.0),
-                },
-                row_gap: Val::Percent(2.00),
-                ..default()
-            },
-            ..default()
-        },
-        SettingsMenuElements::OptionsBox,
-    );
+    // The resolutions offered are the ones the monitor can show, along with whatever
+    // size the window is at right now.
+    let current_resolution = display_settings
+        .get_resolution()
+        .or_else(|| windows.single().ok().map(get_window_resolution));
+    let resolutions = match current_resolution {
+        Some(current_resolution) => {
+            get_resolution_options(monitors.iter().next(), current_resolution)
+        }
+        None => Vec::new(),
+    };
 
     let bottom_third = Node {
         width: Val::Percent(66.0),
@@ -199,8 +185,10 @@ pub fn spawn_settings_menu(mut commands: Commands) {
     let cancel_button = create_button(ButtonTypes::Cancel);
     let cancel_text = create_button_text(String::from("Cancel"));
 
-    //Spawn UI Camera
-    commands.spawn((CameraBundle::default(), SettingsMenuUI));
+    //Spawn UI Camera, unless opened in-game where the level's camera is already there
+    if camera_query.is_empty() {
+        commands.spawn((CameraBundle::default(), SettingsMenuUI));
+    }
 
     //UI Construction
     commands.spawn(ui_container).with_children(|ui_container| {
@@ -210,16 +198,24 @@ pub fn spawn_settings_menu(mut commands: Commands) {
         ui_container
             .spawn(middle_third)
             .with_children(|middle_third| {
+                let tab_bar = spawn_tab_bar(middle_third, tab_bar, tab_bar_container, tab).id();
+
                 middle_third
-                    .spawn(tabs_container)
-                    .with_children(|tabs_container| {
-                        tabs_container.spawn(tab_text);
+                    .spawn((create_options_container(), TabPage::new(tab_bar, 0)))
+                    .with_children(|options_container| {
+                        for audio_bus in AudioBus::ALL {
+                            spawn_volume_row(options_container, audio_bus, &audio_buses);
+                        }
                     });
                 middle_third
-                    .spawn(options_container)
+                    .spawn((create_options_container(), TabPage::new(tab_bar, 1)))
                     .with_children(|options_container| {
-                        spawn_volume_slider(options_container, AudioType::Music);
-                        spawn_volume_slider(options_container, AudioType::SFX);
+                        spawn_display_rows(
+                            options_container,
+                            &display_settings,
+                            resolutions,
+                            current_resolution,
+                        );
                     });
             });
         ui_container
@@ -239,160 +235,38 @@ pub fn spawn_settings_menu(mut commands: Commands) {
     });
 }
 
-pub struct Slider {
-    pub back: (ColoredNodeBundle, CountingSliderKeys),
-    pub handle: (
-        ButtonNodeBundle,
-        ButtonTypes,
-        SettingsMenuElements,
-        CountingSliderKeys,
-    ),
-    pub fill: (ColoredNodeBundle, CountingSliderKeys),
-}
-
-pub struct Spinner {
-    pub value_container: Node,
-    pub buttons_container: Node,
-    pub value: (TextNodeBundle, SettingsMenuElements, CountingSliderKeys),
-    pub increment: (
-        ButtonNodeBundle,
-        ButtonTypes,
-        SettingsMenuElements,
-        CountingSliderKeys,
-    ),
-    pub decrement: (
-        ButtonNodeBundle,
-        ButtonTypes,
-        SettingsMenuElements,
-        CountingSliderKeys,
-    ),
-}
-
-pub struct CountingSlider {
-    barrier_keys: SliderKeyComponents,
-
-    label: (TextNodeBundle, SettingsMenuElements),
-    slider: Slider,
-    spinner: Spinner,
-}
-
-pub fn create_widget_label(text: String) -> (TextNodeBundle, SettingsMenuElements) {
+fn create_options_container() -> (ImageNodeBundle, SettingsMenuElements) {
     (
-        TextNodeBundle {
-            text: Text::new(text),
-            font: TextFont {
-                font_size: 20.0,
-                ..default()
-            },
-            color: TextColor(WHITE),
-        },
-        SettingsMenuElements::Text,
-    )
-}
-
-pub fn create_widget_slider() -> Slider {
-    Slider {
-        back: (
-            ColoredNodeBundle {
-                node: Node {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(20.0),
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                background_color: BackgroundColor(DBROWN),
-            },
-            CountingSliderKeys::Back,
-        ),
-        handle: (
-            ButtonNodeBundle {
-                node: Node {
-                    width: Val::Percent(5.00),
-                    //Handle does not clip outside widget container because it is
-                    //parented to fill and subsequently back which is 20% of the
-                    //widget containers height. [200% of 20% = 40%]
-                    height: Val::Percent(200.00),
-                    ..default()
+        ImageNodeBundle {
+            node: Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                padding: UiRect {
+                    left: Val::Percent(0.0),
+                    right: Val::Percent(0.0),
+                    top: Val::Percent(2.0),
+                    bottom: Val::Percent(0.0),
                 },
+                row_gap: Val::Percent(2.00),
                 ..default()
             },
-            ButtonTypes::Slider,
-            SettingsMenuElements::Button,
-            CountingSliderKeys::Handle,
-        ),
-        fill: (
-            ColoredNodeBundle {
-                node: Node {
-                    width: Val::Percent(0.0),
-                    height: Val::Percent(100.0),
-                    ..default()
-                },
-                background_color: BackgroundColor(WHITE),
-            },
-            CountingSliderKeys::Fill,
-        ),
-    }
-}
-
-pub fn create_widget_spinner() -> Spinner {
-    Spinner {
-        value_container: (Node {
-            width: Val::Percent(50.0),
-            height: Val::Percent(100.0),
-            align_items: AlignItems::Center,
-            justify_content: JustifyContent::Center,
             ..default()
-        }),
-        buttons_container: (Node {
-            width: Val::Percent(30.0),
-            height: Val::Percent(80.0),
-            flex_direction: FlexDirection::Column,
-            justify_content: JustifyContent::SpaceBetween,
-            ..default()
-        }),
-        value: (
-            TextNodeBundle {
-                text: Text::new("0"),
-                font: TextFont {
-                    font_size: 25.0,
-
-                    ..default()
-                },
-                color: TextColor(WHITE),
-            },
-            SettingsMenuElements::Text,
-            CountingSliderKeys::Value,
-        ),
-        increment: (
-            ButtonNodeBundle {
-                node: Node {
-                    width: Val::Percent(100.00),
-                    height: Val::Percent(45.00),
-                    ..default()
-                },
-                ..default()
-            },
-            ButtonTypes::Increment,
-            SettingsMenuElements::IncrementButton,
-            CountingSliderKeys::Increment,
-        ),
-        decrement: (
-            ButtonNodeBundle {
-                node: Node {
-                    width: Val::Percent(100.00),
-                    height: Val::Percent(45.00),
-                    ..default()
-                },
-                ..default()
-            },
-            ButtonTypes::Decrement,
-            SettingsMenuElements::DecrementButton,
-            CountingSliderKeys::Decrement,
-        ),
-    }
+        },
+        SettingsMenuElements::OptionsBox,
+    )
 }
 
-fn create_button(b_type: ButtonTypes) -> (ButtonNodeBundle, ButtonTypes, SettingsMenuElements) {
+fn create_button(
+    b_type: ButtonTypes,
+) -> (
+    ButtonNodeBundle,
+    ButtonTypes,
+    SettingsMenuElements,
+    Focusable,
+) {
     (
         ButtonNodeBundle {
             node: Node {
@@ -406,6 +280,7 @@ fn create_button(b_type: ButtonTypes) -> (ButtonNodeBundle, ButtonTypes, Setting
         },
         b_type,
         SettingsMenuElements::Button,
+        Focusable,
     )
 }
 
@@ -423,43 +298,29 @@ fn create_button_text(text: String) -> (TextNodeBundle, SettingsMenuElements) {
     )
 }
 
-fn create_widget_container(keys: SliderKeyComponents) -> (Node, SliderKeyComponents) {
-    (
-        Node {
-            width: Val::Percent(96.0),
-            height: Val::Percent(12.0),
-            justify_content: JustifyContent::SpaceBetween,
-            ..default()
-        },
-        keys,
-    )
+/// Returns the toggle muting some audio bus, switched on while it's muted.
+fn create_mute_toggle(is_muted: bool) -> Toggle {
+    Toggle::new(is_muted).with_labels("Muted", "Mute")
 }
 
-/// Creates a Counting Slider with a Label.
-fn create_counting_slider(label: String) -> CountingSlider {
-    let slider_widget_keys = SliderKeyComponents { array: [None; 6] };
-
-    let slider_widget_text = create_widget_label(label);
-    let slider = create_widget_slider();
-    let spinner = create_widget_spinner();
-
-    CountingSlider {
-        barrier_keys: slider_widget_keys,
-
-        label: slider_widget_text,
-        slider,
-        spinner,
-    }
-}
+/// Spawns a row setting the volume of some audio bus, with a slider and spinner
+/// kept in step with each other, along with a toggle muting it.
+fn spawn_volume_row(
+    ui_container: &mut ChildSpawnerCommands,
+    audio_bus: AudioBus,
+    audio_buses: &AudioBuses,
+) {
+    // Both start off at the volume the bus is already set to.
+    let volume_percentage = (audio_buses.get_volume(audio_bus) * 100.0).round();
 
-/// Spawns a Volume Slider at a given point in the UI.
-fn spawn_volume_slider(ui_container: &mut ChildSpawnerCommands, audio_type: AudioType) {
-    let volume_slider = match audio_type {
-        AudioType::Music => create_counting_slider(String::from("Music")),
-        AudioType::SFX => create_counting_slider(String::from("SFX")),
+    let row_container = Node {
+        width: Val::Percent(96.0),
+        height: Val::Percent(12.0),
+        justify_content: JustifyContent::SpaceBetween,
+        ..default()
     };
 
-    let slider_widget_label = Node {
+    let label_container = Node {
         width: Val::Percent(25.0),
         height: Val::Percent(100.0),
         align_items: AlignItems::Center,
@@ -467,7 +328,7 @@ fn spawn_volume_slider(ui_container: &mut ChildSpawnerCommands, audio_type: Audi
     };
 
     let slider_container = Node {
-        width: Val::Percent(60.0),
+        width: Val::Percent(48.0),
         height: Val::Percent(100.0),
         align_items: AlignItems::Center,
         ..default()
@@ -481,153 +342,321 @@ fn spawn_volume_slider(ui_container: &mut ChildSpawnerCommands, audio_type: Audi
         ..default()
     };
 
-    ui_container
-        .spawn(create_widget_container(volume_slider.barrier_keys))
-        .with_children(|widget_container| {
-            widget_container
-                .spawn(slider_widget_label)
-                .with_children(|widget_label| {
-                    widget_label.spawn(volume_slider.label);
-                });
-        })
-        .with_children(|widget_container| {
-            widget_container
-                .spawn(slider_container)
-                .with_children(|slider_container| {
-                    slider_container
-                        .spawn(volume_slider.slider.back)
-                        .with_children(|slider_back| {
-                            slider_back.spawn(volume_slider.slider.fill);
-                            slider_back.spawn(volume_slider.slider.handle);
-                        });
-                });
-        })
-        .with_children(|widget_container| {
-            widget_container
-                .spawn(spinner_container)
-                .with_children(|spinner_container| {
-                    spinner_container
-                        .spawn(volume_slider.spinner.value_container)
-                        .with_children(|spinner_value_container| {
-                            spinner_value_container
-                                .spawn((volume_slider.spinner.value, audio_type));
-                        });
-                })
-                .with_children(|spinner_container| {
-                    spinner_container
-                        .spawn(volume_slider.spinner.buttons_container)
-                        .with_children(|spinner_buttons_container| {
-                            spinner_buttons_container.spawn(volume_slider.spinner.increment);
-                            spinner_buttons_container.spawn(volume_slider.spinner.decrement);
-                        });
-                });
+    let mute_container = Node {
+        width: Val::Percent(12.0),
+        height: Val::Percent(100.0),
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        ..default()
+    };
+
+    let fill_container = Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        ..default()
+    };
+
+    ui_container.spawn(row_container).with_children(|row| {
+        row.spawn(label_container).with_children(|label_container| {
+            label_container.spawn(create_widget_text(audio_bus.get_name(), 20.0));
         });
+        row.spawn(slider_container)
+            .with_children(|slider_container| {
+                spawn_slider(
+                    slider_container,
+                    Slider::new(volume_percentage, 0.0, 100.0, 1.0),
+                    fill_container.clone(),
+                )
+                .insert(VolumeControl(audio_bus));
+            });
+        row.spawn(spinner_container)
+            .with_children(|spinner_container| {
+                spawn_spinner(
+                    spinner_container,
+                    Spinner::new(volume_percentage, 0.0, 100.0, 1.0),
+                    fill_container.clone(),
+                )
+                .insert(VolumeControl(audio_bus));
+            });
+        row.spawn(mute_container).with_children(|mute_container| {
+            spawn_toggle(
+                mute_container,
+                create_mute_toggle(audio_buses.is_muted(audio_bus)),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(80.0),
+                    ..default()
+                },
+            )
+            .insert(MuteToggle(audio_bus));
+        });
+    });
 }
 
-pub fn change_music_volume(
-    spinner_query: Query<(&Text, &AudioType), Changed<Text>>,
-    background_music: Res<AudioChannel<MusicChannel>>,
+/// Spawns a row with a label, leaving the rest of it to some widget.
+fn spawn_labelled_row(
+    ui_container: &mut ChildSpawnerCommands,
+    label: &str,
+    spawn_widget: impl FnOnce(&mut ChildSpawnerCommands),
 ) {
-    for (text, audio_type) in &spinner_query {
-        if *audio_type != AudioType::Music {
-            continue;
-        }
+    let row_container = Node {
+        width: Val::Percent(96.0),
+        height: Val::Percent(12.0),
+        justify_content: JustifyContent::FlexStart,
+        ..default()
+    };
+
+    let label_container = Node {
+        width: Val::Percent(25.0),
+        height: Val::Percent(100.0),
+        align_items: AlignItems::Center,
+        ..default()
+    };
 
-        let slider_percentage = get_percentage_from(text.clone()) as f32;
+    let widget_container = Node {
+        width: Val::Percent(36.0),
+        height: Val::Percent(100.0),
+        align_items: AlignItems::Center,
+        ..default()
+    };
 
-        background_music.set_volume(slider_percentage);
-    }
+    ui_container.spawn(row_container).with_children(|row| {
+        row.spawn(label_container).with_children(|label_container| {
+            label_container.spawn(create_widget_text(label, 20.0));
+        });
+        row.spawn(widget_container).with_children(spawn_widget);
+    });
 }
 
-pub fn change_sfx_volumes(
-    spinner_query: Query<(&Text, &AudioType), Changed<Text>>,
-    player_movement_sound: Res<AudioChannel<PlayerWalkChannel>>,
-    player_bump_sound: Res<AudioChannel<PlayerBumpChannel>>,
+/// Spawns the rows of the display tab, each starting off at the current setting.
+fn spawn_display_rows(
+    ui_container: &mut ChildSpawnerCommands,
+    display_settings: &DisplaySettings,
+    resolutions: Vec<Resolution>,
+    current_resolution: Option<Resolution>,
 ) {
-    for (text, slider_type) in &spinner_query {
-        if *slider_type != AudioType::SFX {
+    let widget_node = Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(80.0),
+        ..default()
+    };
+
+    let window_modes = WindowModeSetting::ALL
+        .iter()
+        .map(|window_mode| String::from(window_mode.get_name()))
+        .collect();
+    let selected_window_mode = WindowModeSetting::ALL
+        .iter()
+        .position(|window_mode| *window_mode == display_settings.get_window_mode())
+        .unwrap_or_default();
+
+    let resolution_names = resolutions
+        .iter()
+        .map(|resolution| resolution.get_name())
+        .collect();
+    let selected_resolution = current_resolution
+        .and_then(|current_resolution| {
+            resolutions
+                .iter()
+                .position(|resolution| *resolution == current_resolution)
+        })
+        .unwrap_or_default();
+
+    let (min_ui_scale, max_ui_scale) = DisplaySettings::UI_SCALE_RANGE;
+    let ui_scale_spinner = Spinner::new(
+        (display_settings.get_ui_scale() * 100.0).round(),
+        min_ui_scale * 100.0,
+        max_ui_scale * 100.0,
+        10.0,
+    );
+
+    let (min_camera_zoom, max_camera_zoom) = DisplaySettings::CAMERA_ZOOM_RANGE;
+    let camera_zoom_spinner = Spinner::new(
+        display_settings.get_camera_zoom() as f32,
+        min_camera_zoom as f32,
+        max_camera_zoom as f32,
+        1.0,
+    );
+
+    spawn_labelled_row(ui_container, "Window Mode", |widget_container| {
+        spawn_dropdown(
+            widget_container,
+            Dropdown::new(window_modes, selected_window_mode),
+            widget_node.clone(),
+        )
+        .insert(DisplayControl::WindowMode);
+    });
+    spawn_labelled_row(ui_container, "Resolution", |widget_container| {
+        spawn_dropdown(
+            widget_container,
+            Dropdown::new(resolution_names, selected_resolution),
+            widget_node.clone(),
+        )
+        .insert((DisplayControl::Resolution, ResolutionOptions(resolutions)));
+    });
+    spawn_labelled_row(ui_container, "VSync", |widget_container| {
+        spawn_toggle(
+            widget_container,
+            Toggle::new(display_settings.has_vsync()),
+            widget_node.clone(),
+        )
+        .insert(DisplayControl::Vsync);
+    });
+    spawn_labelled_row(ui_container, "UI Scale", |widget_container| {
+        spawn_spinner(widget_container, ui_scale_spinner, widget_node.clone())
+            .insert(DisplayControl::UiScale);
+    });
+    spawn_labelled_row(ui_container, "Pixel Perfect", |widget_container| {
+        spawn_toggle(
+            widget_container,
+            Toggle::new(display_settings.is_pixel_perfect()),
+            widget_node.clone(),
+        )
+        .insert(DisplayControl::PixelPerfect);
+    });
+    spawn_labelled_row(ui_container, "Camera Zoom", |widget_container| {
+        spawn_spinner(widget_container, camera_zoom_spinner, widget_node.clone())
+            .insert(DisplayControl::CameraZoom);
+    });
+}
+
+/// Changes the display settings whenever one of the widgets on the display tab is used,
+/// which then applies them to the window straight away.
+pub fn change_display_settings(
+    mut dropdown_changes: MessageReader<DropdownChanged>,
+    mut toggle_changes: MessageReader<ToggleChanged>,
+    mut spinner_changes: MessageReader<SpinnerChanged>,
+    display_controls: Query<(&DisplayControl, Option<&ResolutionOptions>)>,
+    mut display_settings: ResMut<DisplaySettings>,
+) {
+    for dropdown_change in dropdown_changes.read() {
+        let Ok((display_control, resolution_options)) =
+            display_controls.get(dropdown_change.get_dropdown())
+        else {
             continue;
-        }
+        };
 
-        let slider_percentage = get_percentage_from(text.clone()) as f32;
+        let selected = dropdown_change.get_selected();
 
-        player_movement_sound.set_volume(slider_percentage);
-        player_bump_sound.set_volume(slider_percentage);
+        match (display_control, resolution_options) {
+            (DisplayControl::WindowMode, _) => {
+                if let Some(window_mode) = WindowModeSetting::ALL.get(selected) {
+                    display_settings.set_window_mode(*window_mode);
+                }
+            }
+            (DisplayControl::Resolution, Some(ResolutionOptions(resolutions))) => {
+                if let Some(resolution) = resolutions.get(selected) {
+                    display_settings.set_resolution(*resolution);
+                }
+            }
+            _ => warn!("change_display_settings: A dropdown was changed that has no options."),
+        }
     }
-}
 
-pub fn get_percentage_from(spinner_value: Text) -> f64 {
-    let value = spinner_value.parse::<f64>().unwrap();
+    for toggle_change in toggle_changes.read() {
+        let Ok((display_control, _)) = display_controls.get(toggle_change.get_toggle()) else {
+            continue;
+        };
 
-    //Audio is 0-1 normalized so we convert to a decimal percentage
-    let percentage_value = value * 0.01;
+        match display_control {
+            DisplayControl::Vsync => display_settings.set_vsync(toggle_change.is_on()),
+            DisplayControl::PixelPerfect => {
+                display_settings.set_pixel_perfect(toggle_change.is_on())
+            }
+            _ => (),
+        }
+    }
 
-    return percentage_value;
-}
+    for spinner_change in spinner_changes.read() {
+        let Ok((display_control, _)) = display_controls.get(spinner_change.get_spinner()) else {
+            continue;
+        };
 
-pub fn set_keys(
-    entity_query: Query<(Entity, &CountingSliderKeys), Added<CountingSliderKeys>>,
-    parent_query: Query<&ChildOf>,
-    mut widget_containers_query: Query<&mut SliderKeyComponents>,
-) {
-    for (entity, key) in &entity_query {
-        for parent in parent_query.iter_ancestors(entity) {
-            if let Ok(mut counting_slider) = widget_containers_query.get_mut(parent) {
-                counting_slider.array[*key as usize] = Some(entity);
-                break;
+        match display_control {
+            DisplayControl::UiScale => {
+                display_settings.set_ui_scale(spinner_change.get_value() / 100.0)
             }
+            DisplayControl::CameraZoom => {
+                display_settings.set_camera_zoom(spinner_change.get_value().round() as u32)
+            }
+            _ => (),
         }
     }
 }
 
-pub fn add_widget_components(
-    key_components_query: Query<&SliderKeyComponents, Changed<SliderKeyComponents>>,
-    mut commands: Commands,
+/// Sets the volume of an audio bus whenever its slider or spinner is moved.
+pub fn change_bus_volumes(
+    mut slider_changes: MessageReader<SliderChanged>,
+    mut spinner_changes: MessageReader<SpinnerChanged>,
+    volume_controls: Query<&VolumeControl>,
+    mut audio_buses: ResMut<AudioBuses>,
 ) {
-    for key_components in key_components_query.iter() {
-        let is_not_full = key_components
-            .array
-            .iter()
-            .any(|key_component| key_component.is_none());
+    let slider_volumes = slider_changes
+        .read()
+        .map(|slider_change| (slider_change.get_slider(), slider_change.get_value()));
+    let spinner_volumes = spinner_changes
+        .read()
+        .map(|spinner_change| (spinner_change.get_spinner(), spinner_change.get_value()));
+
+    for (volume_control, volume_percentage) in slider_volumes.chain(spinner_volumes) {
+        let Ok(VolumeControl(audio_bus)) = volume_controls.get(volume_control) else {
+            continue;
+        };
+
+        //Audio is 0-1 normalized so we convert to a decimal percentage
+        audio_buses.set_volume(*audio_bus, volume_percentage / 100.0);
+    }
+}
 
-        if is_not_full {
+/// Mutes or unmutes an audio bus whenever its mute toggle is switched.
+pub fn mute_buses(
+    mut toggle_changes: MessageReader<ToggleChanged>,
+    mute_toggles: Query<&MuteToggle>,
+    mut audio_buses: ResMut<AudioBuses>,
+) {
+    for toggle_change in toggle_changes.read() {
+        let Ok(MuteToggle(audio_bus)) = mute_toggles.get(toggle_change.get_toggle()) else {
             continue;
+        };
+
+        audio_buses.set_muted(*audio_bus, toggle_change.is_on());
+    }
+}
+
+/// Keeps every slider, spinner and mute toggle showing the volume of its bus, so moving
+/// one of them moves the rest along with it.
+pub fn sync_volume_controls(
+    audio_buses: Res<AudioBuses>,
+    mut volume_sliders: Query<(&mut Slider, &VolumeControl)>,
+    mut volume_spinners: Query<(&mut Spinner, &VolumeControl)>,
+    mut mute_toggles: Query<(&mut Toggle, &MuteToggle)>,
+) {
+    if !audio_buses.is_changed() {
+        return;
+    }
+
+    for (mut slider, VolumeControl(audio_bus)) in &mut volume_sliders {
+        let volume_percentage = (audio_buses.get_volume(*audio_bus) * 100.0).round();
+
+        if slider.get_value() != volume_percentage {
+            slider.set_value(volume_percentage);
+        }
+    }
+
+    for (mut spinner, VolumeControl(audio_bus)) in &mut volume_spinners {
+        let volume_percentage = (audio_buses.get_volume(*audio_bus) * 100.0).round();
+
+        if spinner.get_value() != volume_percentage {
+            spinner.set_value(volume_percentage);
         }
+    }
 
-        let fill = FillReference(
-            key_components.array[1].expect("add_widget_components: Fill does not exist"),
-        );
-        let value = ValueReference(
-            key_components.array[2].expect("add_widget_components: Value does not exist"),
-        );
-        let handle_ref = HandleReference(
-            key_components.array[0].expect("add_widget_componenets: Handle does not exist"),
-        );
-        let back = BackReference(
-            key_components.array[5].expect("add_widget_components: Back does not exist"),
-        );
-
-        let handle = key_components.array[0];
-        let increment = key_components.array[4];
-        let decrement = key_components.array[3];
-
-        commands
-            .entity(handle.expect("add_widget_components: Handle does not exist"))
-            .insert(fill)
-            .insert(value)
-            .insert(back);
-
-        commands
-            .entity(increment.expect("add_widget_components: Increment does not exist"))
-            .insert(fill)
-            .insert(value)
-            .insert(handle_ref);
-
-        commands
-            .entity(decrement.expect("add_widget_components: Decrement does not exist"))
-            .insert(fill)
-            .insert(value)
-            .insert(handle_ref);
+    for (mut toggle, MuteToggle(audio_bus)) in &mut mute_toggles {
+        let is_muted = audio_buses.is_muted(*audio_bus);
+
+        if toggle.is_on() != is_muted {
+            toggle.set_on(is_muted);
+        }
     }
 }
 
@@ -657,19 +686,6 @@ pub fn load_box_image(
     }
 }
 
-pub fn load_tab_image(
-    asset_server: Res<AssetServer>,
-    mut element_query: Query<(&SettingsMenuElements, &mut ImageNode), Added<SettingsMenuElements>>,
-) {
-    for (element, mut image_node) in &mut element_query {
-        if let SettingsMenuElements::TabBox = element {
-            image_node.image = asset_server
-                .load("textures/settings_menu/tab-box.png")
-                .into()
-        }
-    }
-}
-
 pub fn load_button_image(
     asset_server: Res<AssetServer>,
     mut element_query: Query<(&SettingsMenuElements, &mut ImageNode), Added<SettingsMenuElements>>,
@@ -681,32 +697,6 @@ pub fn load_button_image(
     }
 }
 
-pub fn load_increment_button_image(
-    asset_server: Res<AssetServer>,
-    mut element_query: Query<(&SettingsMenuElements, &mut ImageNode), Added<SettingsMenuElements>>,
-) {
-    for (element, mut image_node) in &mut element_query {
-        if let SettingsMenuElements::IncrementButton = element {
-            image_node.image = asset_server
-                .load("textures/settings_menu/increment-button.png")
-                .into()
-        }
-    }
-}
-
-pub fn load_decrement_button_image(
-    asset_server: Res<AssetServer>,
-    mut element_query: Query<(&SettingsMenuElements, &mut ImageNode), Added<SettingsMenuElements>>,
-) {
-    for (element, mut image_node) in &mut element_query {
-        if let SettingsMenuElements::DecrementButton = element {
-            image_node.image = asset_server
-                .load("textures/settings_menu/decrement-button.png")
-                .into()
-        }
-    }
-}
-
 pub fn load_text_font(
     asset_server: Res<AssetServer>,
     mut element_query: Query<(&SettingsMenuElements, &mut TextFont), Added<SettingsMenuElements>>,
@@ -724,306 +714,45 @@ pub fn unload_settings_menu(mut commands: Commands, query: Query<Entity, With<Se
     }
 }
 
-// Behavior
-
-#[derive(Component, Clone, Copy)]
-pub struct BeingClicked {
-    original_x: f32,
-    original_val: f32,
-    handle_width: Val,
-}
-
-pub fn spinner_buttons_system(
-    mut spinner_button_query: Query<
-        (
-            &Interaction,
-            &ButtonTypes,
-            &ValueReference,
-            &FillReference,
-            &HandleReference,
-        ),
+/// Leaves the settings menu, going back to whichever menu it was opened from, and
+/// saves the settings when they're applied.
+pub fn save_button_system(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut save_settings_requests: MessageWriter<SaveSettings>,
+    pause_state: Option<Res<State<PauseState>>>,
+    next_pause_state: Option<ResMut<NextState<PauseState>>>,
+    mut interaction_query: Query<
+        (&Interaction, &ButtonTypes),
         (Changed<Interaction>, With<Button>),
     >,
-    mut spinner_value_query: Query<
-        &mut Text,
-        (With<SettingsMenuElements>, With<CountingSliderKeys>),
-    >,
-    mut spinner_fill_query: Query<&mut Node, (With<CountingSliderKeys>, Without<Button>)>,
-    spinner_handle_query: Query<&Node, (With<CountingSliderKeys>, With<Button>)>,
 ) {
-    for (interaction, button_type, value_reference, fill_reference, handle_reference) in
-        &mut spinner_button_query
-    {
-        let is_spinner_button =
-            *button_type == ButtonTypes::Increment || *button_type == ButtonTypes::Decrement;
-
-        if *interaction != Interaction::Pressed || !is_spinner_button {
-            continue;
-        }
-
-        let mut spinner_value = spinner_value_query
-            .get_mut(value_reference.0)
-            .expect("spinner_buttons_system: Spinner value should exist.");
-
-        let old_value = spinner_value.0.parse::<u32>().unwrap();
+    let mut leave_settings = false;
 
-        let trying_to_overflow = *button_type == ButtonTypes::Increment && old_value == 100;
-        let trying_to_underflow = *button_type == ButtonTypes::Decrement && old_value == 0;
-
-        if trying_to_overflow || trying_to_underflow {
+    for (interaction, button_type) in &mut interaction_query {
+        if *interaction != Interaction::Pressed {
             continue;
         }
 
-        let new_value = if *button_type == ButtonTypes::Increment {
-            old_value + 1
-        } else if *button_type == ButtonTypes::Decrement {
-            old_value - 1
-        } else {
-            panic!("spinner_buttons_system: ButtonTypes was not Increment or Drecement, which is impossible.")
-        };
-
-        spinner_value.0 = new_value.to_string();
-
-        let mut fill_value = spinner_fill_query
-            .get_mut(fill_reference.0)
-            .expect("spinner_buttons_system: Spinner fill value should exist.");
-
-        /*
-        We subtract half the handles width from the fill bar so that the values
-        position is visually accurate to the center of the handle, as is expected,
-        rather than the right edge of the fill bar
-        */
-        let handle_style = spinner_handle_query
-            .get(handle_reference.0)
-            .expect("spinner_buttons_system: Spinner handle should exist.");
-
-        let handle_width_percentage = if let Val::Percent(width_percentage) = handle_style.width {
-            width_percentage
-        } else {
-            panic!("spinner_buttons_system: Handle width should be a percentage.")
-        };
-
-        let new_fill_amount =
-            spinner_value.0.parse::<f32>().unwrap() - (handle_width_percentage / 2.0);
-
-        fill_value.width = Val::Percent(new_fill_amount);
-    }
-}
-
-pub fn get_handle_click_position(
-    mut slider_handle_query: Query<
-        (
-            &Interaction,
-            &ButtonTypes,
-            &ValueReference,
-            Entity,
-            &mut Node,
-        ),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut slider_value_query: Query<
-        &mut Text,
-        (With<SettingsMenuElements>, With<CountingSliderKeys>),
-    >,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    mut commands: Commands,
-) {
-    //Capture handle click
-    for (interaction, button_type, value_reference, handle, style) in &mut slider_handle_query {
-        if *interaction != Interaction::Pressed || *button_type != ButtonTypes::Slider {
-            commands.entity(handle).remove::<BeingClicked>();
-            continue;
+        match button_type {
+            ButtonTypes::Apply => {
+                save_settings_requests.write(SaveSettings::new());
+                leave_settings = true;
+            }
+            ButtonTypes::Cancel => leave_settings = true,
         }
-
-        //Capture mouse.x position at time of click
-        let original_x_position = if let Some(position) =
-            window_query.single().unwrap().cursor_position()
-        {
-            position.x
-        } else {
-            panic!("slider_handle_system: Handle was clicked while mouse was outside game window")
-        };
-
-        //Capture original spinner value at time of click
-        let spinner_value = slider_value_query
-            .get_mut(value_reference.0)
-            .expect("get_handle_click_position: Spinner value should exist.");
-
-        let original_spinner_value = spinner_value.0.parse::<f32>().unwrap();
-
-        //Attach original_mouse_x to Handle as component
-        let original_mouse_x_reference = BeingClicked {
-            original_x: original_x_position,
-            original_val: original_spinner_value,
-            handle_width: style.width,
-        };
-
-        commands.entity(handle).insert(original_mouse_x_reference);
     }
-}
 
-pub fn update_handle_position_on_hold(
-    slider_handle_query: Query<
-        (
-            &BeingClicked,
-            &FillReference,
-            &ValueReference,
-            &BackReference,
-        ),
-        (With<BeingClicked>, With<Button>),
-    >,
-    mut slider_value_query: Query<
-        &mut Text,
-        (With<SettingsMenuElements>, With<CountingSliderKeys>),
-    >,
-    mut width_query: Query<&mut Node>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<&Camera>,
-    parent_query: Query<&ChildOf>,
-    mouse_button_state: Res<ButtonInput<MouseButton>>,
-) {
-    //Detect if left mouse button is being held down
-    if !mouse_button_state.pressed(MouseButton::Left) {
+    if !leave_settings {
         return;
     }
 
-    //Get current mouse.x
-    let current_x_position =
-        if let Some(position) = window_query.single().unwrap().cursor_position() {
-            position.x
-        } else {
-            //Doesn't matter if mouse goes outside game window (values beyond slider extremes will be capped)
-            return;
-        };
-
-    //Get original mouse.x + spinner and fill references
-    if slider_handle_query.is_empty() {
-        return;
-    }
-
-    let handle_data_references = if let Ok(data) = slider_handle_query.single() {
-        data
-    } else {
-        panic!("update_handle_position_on_hold: Expected single cursor")
-    };
+    let opened_while_paused =
+        pause_state.is_some_and(|pause_state| *pause_state.get() == PauseState::Settings);
 
-    let original_values = handle_data_references.0;
-    let fill_reference = handle_data_references.1;
-    let spinner_value_reference = handle_data_references.2;
-    let back_reference = handle_data_references.3;
-
-    let read_only_width_query = width_query.as_readonly();
-
-    //Calculate change in mouse x movement as a percent
-    let back_width_in_px = get_node_width(
-        back_reference.0,
-        read_only_width_query,
-        camera_query,
-        parent_query,
-    );
-
-    let change_as_percent =
-        (((current_x_position - original_values.original_x) / back_width_in_px) * 100.00).trunc();
-
-    //Change spinner value
-    let mut spinner_value = slider_value_query
-        .get_mut(spinner_value_reference.0)
-        .expect("update_handle_position_on_hold: Spinner value should exist.");
-
-    let mut new_spinner_value = original_values.original_val + change_as_percent;
-
-    new_spinner_value = new_spinner_value.clamp(0.00, 100.00);
-
-    spinner_value.0 = new_spinner_value.to_string();
-
-    //Change fill bar width
-    let mut fill_value = width_query
-        .get_mut(fill_reference.0)
-        .expect("update_handle_position_on_hold: Slider fill value should exist.");
-
-    /*
-    We subtract half the handles width from the fill bar so that the values
-    position is visually accurate to the center of the handle, as is expected,
-    rather than the right edge of the fill bar
-    */
-    let handle_width_percentage =
-        if let Val::Percent(width_percentage) = original_values.handle_width {
-            width_percentage
-        } else {
-            panic!("spinner_buttons_system: Handle width should be a percentage.")
-        };
-
-    let new_fill_amount = spinner_value.0.parse::<f32>().unwrap() - (handle_width_percentage / 2.0);
-
-    fill_value.width = Val::Percent(new_fill_amount);
-}
-
-fn get_node_width(
-    node: Entity,
-    width_query: Query<&Node>,
-    camera_query: Query<&Camera>,
-    parent_query: Query<&ChildOf>,
-) -> f32 {
-    let mut value_stack = get_all_ancestors(node, parent_query, width_query);
-
-    let camera = camera_query.single().unwrap();
-
-    let viewport_size = camera.logical_viewport_size().unwrap();
-    let mut node_width = viewport_size.x;
-
-    while let Some(val) = value_stack.pop() {
-        node_width = val
-            .resolve(1.0, node_width, viewport_size)
-            .expect("get_node_width: Node width could not be resolved");
-    }
-
-    return node_width;
-}
-
-fn get_all_ancestors(
-    node: Entity,
-    parent_query: Query<&ChildOf>,
-    width_query: Query<&Node>,
-) -> Vec<bevy::ui::Val> {
-    let mut to_be_visited_nodes = Vec::new();
-    let mut seen_styles = Vec::new();
-
-    //Build stack
-    to_be_visited_nodes.push(node);
-
-    while let Some(current_node) = to_be_visited_nodes.pop() {
-        let current_node_value = width_query
-            .get(current_node)
-            .expect("get_all_ancestors: Could not get Style for node")
-            .width;
-
-        seen_styles.push(current_node_value);
-
-        if let Ok(parent) = parent_query.get(current_node) {
-            to_be_visited_nodes.push(parent.parent());
-        }
-    }
-
-    return seen_styles;
-}
-
-pub fn save_button_system(
-    mut next_state: ResMut<NextState<AppState>>,
-    mut interaction_query: Query<
-        (&Interaction, &ButtonTypes),
-        (Changed<Interaction>, With<Button>),
-    >,
-) {
-    for (interaction, button_type) in &mut interaction_query {
-        if *interaction != Interaction::Pressed {
-            continue;
-        }
-
-        match button_type {
-            ButtonTypes::Apply => next_state.set(AppState::MainMenu), //TO-DO Save player preference changes
-            ButtonTypes::Cancel => next_state.set(AppState::MainMenu),
-            ButtonTypes::Slider => (),
-            _ => continue,
+    match next_pause_state {
+        Some(mut next_pause_state) if opened_while_paused => {
+            next_pause_state.set(PauseState::Paused)
         }
+        _ => next_state.set(AppState::MainMenu),
     }
 }
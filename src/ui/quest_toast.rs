@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::narrative::quests::{ObjectiveCompleted, QuestCompleted};
+
+use super::menus::{ColoredNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const TOAST_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.85);
+const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// Identifies the short notice shown in the top corner when progress is made on a quest.
+#[derive(Component)]
+pub struct QuestToast {
+    timer: Timer,
+}
+
+impl QuestToast {
+    pub fn new() -> Self {
+        Self {
+            timer: Timer::new(TOAST_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+/// Identifies the text written inside of a QuestToast.
+#[derive(Component)]
+pub struct QuestToastText;
+
+/// Shows a notice for the latest completed objective or quest, replacing any notice already shown.
+pub fn spawn_quest_toast(
+    mut completed_objectives: MessageReader<ObjectiveCompleted>,
+    mut completed_quests: MessageReader<QuestCompleted>,
+    mut commands: Commands,
+    quest_toasts: Query<Entity, With<QuestToast>>,
+    asset_server: Res<AssetServer>,
+) {
+    if completed_objectives.is_empty() && completed_quests.is_empty() {
+        return;
+    }
+
+    // Finishing a quest is worth more than the objective that finished it.
+    let objective_text = completed_objectives
+        .read()
+        .last()
+        .map(|objective| format!("Objective complete: {}", objective.get_description()));
+    let quest_text = completed_quests
+        .read()
+        .last()
+        .map(|quest| format!("Quest complete: {}", quest.get_title()));
+
+    let toast_text = quest_text
+        .or(objective_text)
+        .expect("spawn_quest_toast: There is nothing to show.");
+
+    for quest_toast in &quest_toasts {
+        commands.entity(quest_toast).despawn();
+    }
+
+    let toast_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Percent(2.0),
+                top: Val::Percent(3.0),
+                max_width: Val::Percent(40.0),
+                padding: UiRect::all(Val::Px(15.0)),
+                ..default()
+            },
+            BackgroundColor(TOAST_BACKGROUND),
+        ),
+        ZIndex(15),
+        QuestToast::new(),
+    );
+
+    let toast_text = (
+        TextNodeBundle::from_nodes(
+            Text::new(toast_text),
+            TextFont {
+                font: asset_server.load("fonts/Untitled.ttf"),
+                font_size: 25.0,
+                ..default()
+            },
+            TextColor(WHITE),
+        ),
+        QuestToastText,
+    );
+
+    commands.spawn(toast_container).with_children(|parent| {
+        parent.spawn(toast_text);
+    });
+}
+
+/// Removes quest notices once they've been shown long enough.
+pub fn despawn_quest_toasts(
+    mut commands: Commands,
+    mut quest_toasts: Query<(Entity, &mut QuestToast)>,
+    time: Res<Time>,
+) {
+    for (toast_entity, mut quest_toast) in &mut quest_toasts {
+        quest_toast.timer.tick(time.delta());
+
+        if quest_toast.timer.is_finished() {
+            commands.entity(toast_entity).despawn();
+        }
+    }
+}
+
+/// Removes every quest notice right away, such as when leaving the scene.
+pub fn remove_quest_toasts(mut commands: Commands, quest_toasts: Query<Entity, With<QuestToast>>) {
+    for toast_entity in &quest_toasts {
+        commands.entity(toast_entity).despawn();
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use super::menus::{ColoredNodeBundle, TextNodeBundle};
+
+const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+const TEXT_BOX_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.1, 0.85);
+
+/// Sent to show some text to the player, such as what's written on a sign.
+#[derive(Message, Debug, Clone)]
+pub struct ShowTextBox {
+    text: String,
+}
+
+impl ShowTextBox {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: String::from(text),
+        }
+    }
+
+    pub fn get_text(&self) -> &String {
+        &self.text
+    }
+}
+
+/// Identifies the box of text shown at the bottom of the screen.
+#[derive(Component)]
+pub struct TextBox;
+
+/// Identifies the text written inside of a TextBox.
+#[derive(Component)]
+pub struct TextBoxText;
+
+/// Shows a box of text at the bottom of the screen, replacing any box already shown.
+pub fn spawn_text_box(
+    mut text_box_requests: MessageReader<ShowTextBox>,
+    mut commands: Commands,
+    text_boxes: Query<Entity, With<TextBox>>,
+    asset_server: Res<AssetServer>,
+) {
+    if text_box_requests.is_empty() {
+        return;
+    }
+
+    let text_box_request = text_box_requests
+        .read()
+        .last()
+        .expect("spawn_text_box: There are no requests to show text.");
+
+    for text_box in &text_boxes {
+        commands.entity(text_box).despawn();
+    }
+
+    let text_box_container = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(5.0),
+                right: Val::Percent(5.0),
+                bottom: Val::Percent(5.0),
+                min_height: Val::Percent(20.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            BackgroundColor(TEXT_BOX_BACKGROUND),
+        ),
+        ZIndex(10),
+        TextBox,
+    );
+
+    let text_box_text = (
+        TextNodeBundle::from_nodes(
+            Text::new(text_box_request.get_text()),
+            TextFont {
+                font: asset_server.load("fonts/Untitled.ttf"),
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(WHITE),
+        ),
+        TextBoxText,
+    );
+
+    commands.spawn(text_box_container).with_children(|parent| {
+        parent.spawn(text_box_text);
+    });
+}
+
+/// Closes the text box once the player has finished reading it.
+pub fn dismiss_text_box(
+    input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    text_boxes: Query<Entity, With<TextBox>>,
+) {
+    if text_boxes.is_empty() {
+        return;
+    }
+
+    if !input.any_just_pressed([KeyCode::KeyE, KeyCode::Enter, KeyCode::Space]) {
+        return;
+    }
+
+    for text_box in &text_boxes {
+        commands.entity(text_box).despawn();
+    }
+}
+
+/// Removes any text box still being shown, such as when leaving the scene.
+pub fn remove_text_boxes(mut commands: Commands, text_boxes: Query<Entity, With<TextBox>>) {
+    for text_box in &text_boxes {
+        commands.entity(text_box).despawn();
+    }
+}
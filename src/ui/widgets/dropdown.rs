@@ -0,0 +1,269 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::ColoredNodeBundle;
+
+use super::{
+    create_widget_button, create_widget_text,
+    focus::{FocusInput, Focusable, Focused},
+    WIDGET_DARK_BROWN,
+};
+
+/// A button showing one of a list of options, which opens the list to pick another one.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Dropdown {
+    options: Vec<String>,
+    selected: usize,
+    open: bool,
+}
+
+impl Dropdown {
+    pub fn new(options: Vec<String>, selected: usize) -> Self {
+        let selected = selected.min(options.len().saturating_sub(1));
+
+        Self {
+            options,
+            selected,
+            open: false,
+        }
+    }
+
+    pub fn get_options(&self) -> &Vec<String> {
+        &self.options
+    }
+
+    pub fn get_selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn get_selected_option(&self) -> Option<&String> {
+        self.options.get(self.selected)
+    }
+
+    /// Selects some option, if there is one. Returns whether the selection changed.
+    pub fn select(&mut self, selected: usize) -> bool {
+        if selected >= self.options.len() || selected == self.selected {
+            return false;
+        }
+
+        self.selected = selected;
+        true
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+    }
+}
+
+/// The text showing the selected option of some dropdown.
+#[derive(Component)]
+pub struct DropdownText(pub Entity);
+
+/// The list of options of some dropdown, only shown while it's open.
+#[derive(Component)]
+pub struct DropdownList(pub Entity);
+
+/// One of the options in the list of some dropdown.
+#[derive(Component)]
+pub struct DropdownOption {
+    dropdown: Entity,
+    index: usize,
+}
+
+impl DropdownOption {
+    pub fn new(dropdown: Entity, index: usize) -> Self {
+        Self { dropdown, index }
+    }
+}
+
+/// Sent whenever the player picks a different option in some dropdown.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct DropdownChanged {
+    dropdown: Entity,
+    selected: usize,
+}
+
+impl DropdownChanged {
+    pub fn new(dropdown: Entity, selected: usize) -> Self {
+        Self { dropdown, selected }
+    }
+
+    pub fn get_dropdown(&self) -> Entity {
+        self.dropdown
+    }
+
+    pub fn get_selected(&self) -> usize {
+        self.selected
+    }
+}
+
+/// Spawns a dropdown, laid out by the given node, and returns it.
+pub fn spawn_dropdown<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    dropdown: Dropdown,
+    node: Node,
+) -> EntityCommands<'a> {
+    let selected_text = create_widget_text(
+        dropdown.get_selected_option().cloned().unwrap_or_default(),
+        20.0,
+    );
+    let options = dropdown.get_options().clone();
+
+    let mut dropdown_commands = parent.spawn((
+        create_widget_button(Node {
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..node
+        }),
+        dropdown,
+        Focusable,
+    ));
+    let dropdown_entity = dropdown_commands.id();
+
+    // The list hangs below the dropdown, over whatever else is there.
+    let option_list = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(WIDGET_DARK_BROWN),
+        ),
+        DropdownList(dropdown_entity),
+        ZIndex(10),
+    );
+
+    dropdown_commands.with_children(|dropdown| {
+        dropdown.spawn((selected_text, DropdownText(dropdown_entity)));
+        dropdown.spawn(option_list).with_children(|option_list| {
+            for (index, option) in options.into_iter().enumerate() {
+                option_list
+                    .spawn((
+                        create_widget_button(Node {
+                            width: Val::Percent(100.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::vertical(Val::Px(4.0)),
+                            ..default()
+                        }),
+                        DropdownOption::new(dropdown_entity, index),
+                        Focusable,
+                    ))
+                    .with_children(|option_button| {
+                        option_button.spawn(create_widget_text(option, 20.0));
+                    });
+            }
+        });
+    });
+
+    dropdown_commands
+}
+
+/// Opens each dropdown that was pressed, or closes it if it was already open.
+pub fn press_dropdowns(mut dropdowns: Query<(&Interaction, &mut Dropdown), Changed<Interaction>>) {
+    for (interaction, mut dropdown) in &mut dropdowns {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let open = !dropdown.is_open();
+        dropdown.set_open(open);
+    }
+}
+
+/// Selects whichever option was pressed, closing its dropdown and focusing it again.
+pub fn choose_dropdown_options(
+    dropdown_options: Query<(Entity, &Interaction, &DropdownOption), Changed<Interaction>>,
+    mut dropdowns: Query<&mut Dropdown>,
+    mut dropdown_changes: MessageWriter<DropdownChanged>,
+    mut commands: Commands,
+) {
+    for (option_entity, interaction, dropdown_option) in &dropdown_options {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut dropdown) = dropdowns.get_mut(dropdown_option.dropdown) else {
+            warn!("choose_dropdown_options: An option was chosen whose dropdown is gone.");
+            continue;
+        };
+
+        if dropdown.select(dropdown_option.index) {
+            dropdown_changes.write(DropdownChanged::new(
+                dropdown_option.dropdown,
+                dropdown_option.index,
+            ));
+        }
+
+        dropdown.set_open(false);
+
+        commands.entity(option_entity).remove::<Focused>();
+        commands.entity(dropdown_option.dropdown).insert(Focused);
+    }
+}
+
+/// Moves the focused dropdown on to the next option when turned up, or back when turned down.
+pub fn step_focused_dropdowns(
+    mut focus_inputs: MessageReader<FocusInput>,
+    mut focused_dropdowns: Query<(Entity, &mut Dropdown), With<Focused>>,
+    mut dropdown_changes: MessageWriter<DropdownChanged>,
+) {
+    for focus_input in focus_inputs.read() {
+        for (dropdown_entity, mut dropdown) in &mut focused_dropdowns {
+            let selected = dropdown.get_selected();
+
+            let new_selected = match focus_input {
+                FocusInput::Increase => selected + 1,
+                FocusInput::Decrease if selected > 0 => selected - 1,
+                _ => continue,
+            };
+
+            if dropdown.select(new_selected) {
+                dropdown_changes.write(DropdownChanged::new(dropdown_entity, new_selected));
+            }
+        }
+    }
+}
+
+/// Keeps the text of each dropdown showing its selected option, and its list shown only
+/// while it's open.
+pub fn update_dropdowns(
+    dropdowns: Query<Ref<Dropdown>>,
+    mut dropdown_texts: Query<(&DropdownText, &mut Text)>,
+    mut dropdown_lists: Query<(&DropdownList, &mut Node)>,
+) {
+    for (dropdown_text, mut text) in &mut dropdown_texts {
+        let Ok(dropdown) = dropdowns.get(dropdown_text.0) else {
+            continue;
+        };
+
+        if !dropdown.is_changed() {
+            continue;
+        }
+
+        text.0 = dropdown.get_selected_option().cloned().unwrap_or_default();
+    }
+
+    for (dropdown_list, mut node) in &mut dropdown_lists {
+        let Ok(dropdown) = dropdowns.get(dropdown_list.0) else {
+            continue;
+        };
+
+        let display = if dropdown.is_open() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
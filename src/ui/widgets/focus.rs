@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+
+use super::WIDGET_FOCUS;
+
+/// A widget that can be moved to with the keyboard or a controller.
+#[derive(Component, Default)]
+pub struct Focusable;
+
+/// The widget the keyboard or controller is currently working on. Only one widget has it at a time.
+#[derive(Component)]
+pub struct Focused;
+
+/// A button pressed by activating it while focused, which is let go of the frame after.
+#[derive(Component)]
+pub struct PressedByFocus;
+
+/// Something the player asked of the focused widget, or of the focus itself.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusInput {
+    /// Moves the focus to the next widget.
+    Next,
+    /// Moves the focus to the previous widget.
+    Previous,
+    /// Turns the value of the focused widget up.
+    Increase,
+    /// Turns the value of the focused widget down.
+    Decrease,
+    /// Presses the focused widget, as though it was clicked.
+    Activate,
+}
+
+/// Turns keyboard and controller presses into focus inputs.
+///
+/// Tab or down moves to the next widget, shift and tab or up moves to the previous one,
+/// left and right change values, while enter, space or the bottom face button press it.
+pub fn read_focus_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut focus_inputs: MessageWriter<FocusInput>,
+) {
+    let shift_held = keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let gamepad_pressed =
+        |button: GamepadButton| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        if shift_held {
+            focus_inputs.write(FocusInput::Previous);
+        } else {
+            focus_inputs.write(FocusInput::Next);
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowDown) || gamepad_pressed(GamepadButton::DPadDown) {
+        focus_inputs.write(FocusInput::Next);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) || gamepad_pressed(GamepadButton::DPadUp) {
+        focus_inputs.write(FocusInput::Previous);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) || gamepad_pressed(GamepadButton::DPadRight)
+    {
+        focus_inputs.write(FocusInput::Increase);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) || gamepad_pressed(GamepadButton::DPadLeft) {
+        focus_inputs.write(FocusInput::Decrease);
+    }
+
+    if keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::Space])
+        || gamepad_pressed(GamepadButton::South)
+    {
+        focus_inputs.write(FocusInput::Activate);
+    }
+}
+
+/// Moves the focus between widgets in the order they appear in the UI, skipping any
+/// that are hidden, and presses the focused widget when it's activated.
+pub fn navigate_focus(
+    mut focus_inputs: MessageReader<FocusInput>,
+    focusables: Query<(Entity, Has<Focused>), With<Focusable>>,
+    mut interactions: Query<&mut Interaction>,
+    nodes: Query<&Node>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    mut commands: Commands,
+) {
+    let mut current_focus = focusables
+        .iter()
+        .find_map(|(focusable, is_focused)| is_focused.then_some(focusable));
+
+    for focus_input in focus_inputs.read() {
+        match focus_input {
+            FocusInput::Next | FocusInput::Previous => {
+                let mut ordered_focusables: Vec<(Vec<u64>, Entity)> = focusables
+                    .iter()
+                    .map(|(focusable, _)| focusable)
+                    .filter(|&focusable| !is_hidden(focusable, &nodes, &parents))
+                    .map(|focusable| (get_tree_path(focusable, &parents, &children), focusable))
+                    .collect();
+
+                if ordered_focusables.is_empty() {
+                    continue;
+                }
+
+                ordered_focusables.sort();
+
+                let number_of_focusables = ordered_focusables.len();
+                let current_position = current_focus.and_then(|current_focus| {
+                    ordered_focusables
+                        .iter()
+                        .position(|&(_, focusable)| focusable == current_focus)
+                });
+
+                let new_position = match (focus_input, current_position) {
+                    (FocusInput::Next, Some(position)) => (position + 1) % number_of_focusables,
+                    (FocusInput::Next, None) => 0,
+                    (_, Some(position)) => {
+                        (position + number_of_focusables - 1) % number_of_focusables
+                    }
+                    (_, None) => number_of_focusables - 1,
+                };
+
+                let new_focus = ordered_focusables[new_position].1;
+                move_focus(&mut commands, current_focus, new_focus);
+                current_focus = Some(new_focus);
+            }
+            FocusInput::Activate => {
+                let Some(current_focus) = current_focus else {
+                    continue;
+                };
+
+                if let Ok(mut interaction) = interactions.get_mut(current_focus) {
+                    *interaction = Interaction::Pressed;
+                    commands.entity(current_focus).insert(PressedByFocus);
+                }
+            }
+            // These are up to whichever widget is focused.
+            FocusInput::Increase | FocusInput::Decrease => (),
+        }
+    }
+}
+
+/// Lets go of buttons that were pressed through the focus the frame before.
+pub fn release_focus_presses(
+    mut pressed_buttons: Query<(Entity, &mut Interaction), With<PressedByFocus>>,
+    mut commands: Commands,
+) {
+    for (button, mut interaction) in &mut pressed_buttons {
+        if *interaction == Interaction::Pressed {
+            *interaction = Interaction::None;
+        }
+
+        commands.entity(button).remove::<PressedByFocus>();
+    }
+}
+
+/// Focuses whichever widget was just clicked, so the keyboard carries on from there.
+pub fn focus_clicked_widgets(
+    clicked_buttons: Query<(Entity, &Interaction), (Changed<Interaction>, Without<PressedByFocus>)>,
+    focusables: Query<(), With<Focusable>>,
+    focused: Query<Entity, With<Focused>>,
+    parents: Query<&ChildOf>,
+    mut commands: Commands,
+) {
+    for (button, interaction) in &clicked_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // Parts of a widget, such as the buttons of a spinner, focus the widget they're part of.
+        let clicked_widget = std::iter::once(button)
+            .chain(parents.iter_ancestors(button))
+            .find(|&entity| focusables.contains(entity));
+
+        let Some(clicked_widget) = clicked_widget else {
+            continue;
+        };
+
+        if focused.contains(clicked_widget) {
+            continue;
+        }
+
+        move_focus(&mut commands, focused.iter().next(), clicked_widget);
+    }
+}
+
+/// Outlines the focused widget, so the player can see where they are.
+pub fn outline_focused_widget(
+    newly_focused: Query<Entity, Added<Focused>>,
+    mut unfocused: RemovedComponents<Focused>,
+    mut commands: Commands,
+) {
+    for widget in unfocused.read() {
+        if let Ok(mut widget_commands) = commands.get_entity(widget) {
+            widget_commands.try_remove::<Outline>();
+        }
+    }
+
+    for widget in &newly_focused {
+        commands
+            .entity(widget)
+            .insert(Outline::new(Val::Px(2.0), Val::Px(2.0), WIDGET_FOCUS));
+    }
+}
+
+fn move_focus(commands: &mut Commands, old_focus: Option<Entity>, new_focus: Entity) {
+    if old_focus == Some(new_focus) {
+        return;
+    }
+
+    if let Some(old_focus) = old_focus {
+        commands.entity(old_focus).remove::<Focused>();
+    }
+
+    commands.entity(new_focus).insert(Focused);
+}
+
+/// Returns whether a node, or any node it's in, isn't being displayed.
+fn is_hidden(entity: Entity, nodes: &Query<&Node>, parents: &Query<&ChildOf>) -> bool {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .any(|entity| {
+            nodes
+                .get(entity)
+                .is_ok_and(|node| node.display == Display::None)
+        })
+}
+
+/// Returns where an entity sits in the UI, as the position of each of its ancestors among
+/// their siblings, so that sorting these puts entities in the order they're laid out.
+fn get_tree_path(
+    entity: Entity,
+    parents: &Query<&ChildOf>,
+    children: &Query<&Children>,
+) -> Vec<u64> {
+    let mut tree_path = Vec::new();
+    let mut current = entity;
+
+    while let Ok(child_of) = parents.get(current) {
+        let parent = child_of.parent();
+        let position = children
+            .get(parent)
+            .ok()
+            .and_then(|siblings| siblings.iter().position(|sibling| sibling == current))
+            .unwrap_or_default();
+
+        tree_path.push(position as u64);
+        current = parent;
+    }
+
+    // Separate UI trees are kept in the order they were spawned.
+    tree_path.push(current.to_bits());
+    tree_path.reverse();
+
+    tree_path
+}
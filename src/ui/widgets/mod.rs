@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::{ButtonNodeBundle, ImageNodeBundle, TextNodeBundle};
+
+pub mod dropdown;
+pub mod focus;
+pub mod slider;
+pub mod spinner;
+pub mod tab_bar;
+pub mod toggle;
+
+pub const WIDGET_WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const WIDGET_DARK_BROWN: Color = Color::srgb(0.49, 0.29, 0.14);
+/// The color of the outline around whichever widget has focus.
+pub const WIDGET_FOCUS: Color = Color::srgb(1.0, 0.85, 0.4);
+
+/// Text shown on a widget, which is given the menu font once spawned.
+#[derive(Component)]
+pub struct WidgetText;
+
+/// Part of a widget drawn with the menu button image, which is loaded once spawned.
+#[derive(Component)]
+pub struct WidgetButtonImage;
+
+/// A number kept between a minimum and maximum, which only moves in steps of some size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundedValue {
+    value: f32,
+    min: f32,
+    max: f32,
+    /// Values are snapped to the nearest step from the minimum. A step of 0 doesn't snap.
+    step: f32,
+}
+
+impl BoundedValue {
+    pub fn new(value: f32, min: f32, max: f32, step: f32) -> Self {
+        let mut bounded_value = Self {
+            value: min,
+            min,
+            max: max.max(min),
+            step: step.max(0.0),
+        };
+        bounded_value.set_value(value);
+
+        bounded_value
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn get_min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn get_max(&self) -> f32 {
+        self.max
+    }
+
+    pub fn get_step(&self) -> f32 {
+        self.step
+    }
+
+    /// Returns how far along the value is, from 0 at the minimum to 1 at the maximum.
+    pub fn get_fraction(&self) -> f32 {
+        if self.max == self.min {
+            return 0.0;
+        }
+
+        (self.value - self.min) / (self.max - self.min)
+    }
+
+    /// Sets the value, snapped to a step and kept in bounds. Returns whether it changed.
+    pub fn set_value(&mut self, value: f32) -> bool {
+        let snapped_value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        let new_value = snapped_value.clamp(self.min, self.max);
+
+        if new_value == self.value {
+            return false;
+        }
+
+        self.value = new_value;
+        true
+    }
+
+    /// Sets the value to how far along it should be, from 0 at the minimum to 1 at the maximum.
+    /// Returns whether it changed.
+    pub fn set_fraction(&mut self, fraction: f32) -> bool {
+        let fraction = fraction.clamp(0.0, 1.0);
+
+        self.set_value(self.min + fraction * (self.max - self.min))
+    }
+
+    /// Moves the value some number of steps up, or down when negative. Returns whether it changed.
+    pub fn step_by(&mut self, steps: f32) -> bool {
+        self.set_value(self.value + steps * self.step)
+    }
+}
+
+/// Returns some text for a widget, in the same style as the rest of the menus.
+pub fn create_widget_text(text: impl Into<String>, font_size: f32) -> (TextNodeBundle, WidgetText) {
+    (
+        TextNodeBundle::from_nodes(
+            Text::new(text),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(WIDGET_WHITE),
+        ),
+        WidgetText,
+    )
+}
+
+/// Returns a node drawn with the menu button image, laid out by the given node.
+pub fn create_widget_image(node: Node) -> (ImageNodeBundle, WidgetButtonImage) {
+    (
+        ImageNodeBundle::from_nodes(node, ImageNode::default()),
+        WidgetButtonImage,
+    )
+}
+
+/// Returns a button drawn with the menu button image, laid out by the given node.
+pub fn create_widget_button(node: Node) -> (ButtonNodeBundle, WidgetButtonImage) {
+    (
+        ButtonNodeBundle::from_nodes(node, ImageNode::default()),
+        WidgetButtonImage,
+    )
+}
+
+pub fn load_widget_font(
+    asset_server: Res<AssetServer>,
+    mut widget_texts: Query<&mut TextFont, Added<WidgetText>>,
+) {
+    for mut text_font in &mut widget_texts {
+        text_font.font = asset_server.load("fonts/Untitled.ttf");
+    }
+}
+
+pub fn load_widget_button_image(
+    asset_server: Res<AssetServer>,
+    mut widget_images: Query<&mut ImageNode, Added<WidgetButtonImage>>,
+) {
+    for mut image_node in &mut widget_images {
+        image_node.image = asset_server.load("textures/main_menu/button.png");
+    }
+}
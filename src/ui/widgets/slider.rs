@@ -0,0 +1,196 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::ui::menus::ColoredNodeBundle;
+
+use super::{
+    create_widget_image,
+    focus::{FocusInput, Focusable, Focused},
+    BoundedValue, WIDGET_DARK_BROWN, WIDGET_WHITE,
+};
+
+/// How wide the handle is, as a percentage of the slider.
+const HANDLE_WIDTH_PERCENTAGE: f32 = 5.0;
+
+/// A bar that can be dragged along, or stepped with the keyboard, to pick a number.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Slider {
+    bounded_value: BoundedValue,
+}
+
+impl Slider {
+    pub fn new(value: f32, min: f32, max: f32, step: f32) -> Self {
+        Self {
+            bounded_value: BoundedValue::new(value, min, max, step),
+        }
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.bounded_value.get_value()
+    }
+
+    /// Sets the value, snapped to a step and kept in bounds. Returns whether it changed.
+    pub fn set_value(&mut self, value: f32) -> bool {
+        self.bounded_value.set_value(value)
+    }
+
+    pub fn get_bounded_value(&self) -> &BoundedValue {
+        &self.bounded_value
+    }
+}
+
+/// The filled in part of a slider, up to its value.
+#[derive(Component)]
+pub struct SliderFill(pub Entity);
+
+/// Sent whenever the player moves some slider to a new value.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SliderChanged {
+    slider: Entity,
+    value: f32,
+}
+
+impl SliderChanged {
+    pub fn new(slider: Entity, value: f32) -> Self {
+        Self { slider, value }
+    }
+
+    pub fn get_slider(&self) -> Entity {
+        self.slider
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
+}
+
+/// Spawns a slider, laid out by the given node, and returns it.
+pub fn spawn_slider<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    slider: Slider,
+    node: Node,
+) -> EntityCommands<'a> {
+    let fill_percentage = get_fill_percentage(&slider);
+
+    let mut slider_commands = parent.spawn((
+        Node {
+            align_items: AlignItems::Center,
+            ..node
+        },
+        slider,
+        Button,
+        RelativeCursorPosition::default(),
+        Focusable,
+    ));
+    let slider_entity = slider_commands.id();
+
+    let track = ColoredNodeBundle::from_nodes(
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(20.0),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(WIDGET_DARK_BROWN),
+    );
+
+    let fill = (
+        ColoredNodeBundle::from_nodes(
+            Node {
+                width: Val::Percent(fill_percentage),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(WIDGET_WHITE),
+        ),
+        SliderFill(slider_entity),
+    );
+
+    let handle = create_widget_image(Node {
+        width: Val::Percent(HANDLE_WIDTH_PERCENTAGE),
+        // The handle sticks out of the track, which is 20% of the slider's height,
+        // without going outside of the slider. [200% of 20% = 40%]
+        height: Val::Percent(200.0),
+        ..default()
+    });
+
+    slider_commands.with_children(|slider| {
+        slider.spawn(track).with_children(|track| {
+            track.spawn(fill);
+            track.spawn(handle);
+        });
+    });
+
+    slider_commands
+}
+
+/// Returns how wide the fill of a slider should be, as a percentage of the slider.
+///
+/// Half the handle's width is taken off so that the value lines up with the center of
+/// the handle, rather than the edge of the fill.
+fn get_fill_percentage(slider: &Slider) -> f32 {
+    let value_percentage = slider.get_bounded_value().get_fraction() * 100.0;
+
+    (value_percentage - HANDLE_WIDTH_PERCENTAGE / 2.0).max(0.0)
+}
+
+/// Moves the focused slider a step up or down.
+pub fn step_focused_sliders(
+    mut focus_inputs: MessageReader<FocusInput>,
+    mut focused_sliders: Query<(Entity, &mut Slider), With<Focused>>,
+    mut slider_changes: MessageWriter<SliderChanged>,
+) {
+    for focus_input in focus_inputs.read() {
+        let steps = match focus_input {
+            FocusInput::Increase => 1.0,
+            FocusInput::Decrease => -1.0,
+            _ => continue,
+        };
+
+        for (slider_entity, mut slider) in &mut focused_sliders {
+            if slider.bounded_value.step_by(steps) {
+                slider_changes.write(SliderChanged::new(slider_entity, slider.get_value()));
+            }
+        }
+    }
+}
+
+/// Moves each slider being held down to wherever along it the cursor is.
+pub fn drag_sliders(
+    mut sliders: Query<(Entity, &mut Slider, &Interaction, &RelativeCursorPosition)>,
+    mut slider_changes: MessageWriter<SliderChanged>,
+) {
+    for (slider_entity, mut slider, interaction, relative_cursor_position) in &mut sliders {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // The cursor position is measured from the center of the slider.
+        let Some(cursor_position) = relative_cursor_position.normalized else {
+            continue;
+        };
+
+        let mut dragged_value = slider.bounded_value;
+        if dragged_value.set_fraction(cursor_position.x + 0.5) {
+            slider.bounded_value = dragged_value;
+            slider_changes.write(SliderChanged::new(slider_entity, slider.get_value()));
+        }
+    }
+}
+
+/// Keeps the fill of each slider up to its value.
+pub fn update_slider_fills(
+    sliders: Query<Ref<Slider>>,
+    mut slider_fills: Query<(&SliderFill, &mut Node)>,
+) {
+    for (slider_fill, mut node) in &mut slider_fills {
+        let Ok(slider) = sliders.get(slider_fill.0) else {
+            continue;
+        };
+
+        if !slider.is_changed() {
+            continue;
+        }
+
+        node.width = Val::Percent(get_fill_percentage(&slider));
+    }
+}
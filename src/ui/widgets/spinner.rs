@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::ButtonNodeBundle;
+
+use super::{
+    create_widget_text,
+    focus::{FocusInput, Focusable, Focused},
+    BoundedValue,
+};
+
+/// A number with buttons beside it to count it up and down.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Spinner {
+    bounded_value: BoundedValue,
+}
+
+impl Spinner {
+    pub fn new(value: f32, min: f32, max: f32, step: f32) -> Self {
+        Self {
+            bounded_value: BoundedValue::new(value, min, max, step),
+        }
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.bounded_value.get_value()
+    }
+
+    /// Sets the value, snapped to a step and kept in bounds. Returns whether it changed.
+    pub fn set_value(&mut self, value: f32) -> bool {
+        self.bounded_value.set_value(value)
+    }
+
+    pub fn get_bounded_value(&self) -> &BoundedValue {
+        &self.bounded_value
+    }
+}
+
+/// Which way a spinner button counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpinDirection {
+    Increment,
+    Decrement,
+}
+
+/// One of the buttons counting some spinner up or down.
+#[derive(Component)]
+pub struct SpinnerButton {
+    spinner: Entity,
+    direction: SpinDirection,
+}
+
+impl SpinnerButton {
+    pub fn new(spinner: Entity, direction: SpinDirection) -> Self {
+        Self { spinner, direction }
+    }
+}
+
+/// The text showing the value of some spinner.
+#[derive(Component)]
+pub struct SpinnerText(pub Entity);
+
+/// Sent whenever the player counts some spinner to a new value.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SpinnerChanged {
+    spinner: Entity,
+    value: f32,
+}
+
+impl SpinnerChanged {
+    pub fn new(spinner: Entity, value: f32) -> Self {
+        Self { spinner, value }
+    }
+
+    pub fn get_spinner(&self) -> Entity {
+        self.spinner
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
+}
+
+/// Spawns a spinner, laid out by the given node, and returns it.
+pub fn spawn_spinner<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    spinner: Spinner,
+    node: Node,
+) -> EntityCommands<'a> {
+    let value_text = create_widget_text(get_spinner_text(&spinner), 25.0);
+
+    let mut spinner_commands = parent.spawn((
+        Node {
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..node
+        },
+        spinner,
+        Focusable,
+    ));
+    let spinner_entity = spinner_commands.id();
+
+    let value_container = Node {
+        width: Val::Percent(50.0),
+        height: Val::Percent(100.0),
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        ..default()
+    };
+
+    let buttons_container = Node {
+        width: Val::Percent(30.0),
+        height: Val::Percent(80.0),
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::SpaceBetween,
+        ..default()
+    };
+
+    let create_spinner_button = |direction: SpinDirection| {
+        (
+            ButtonNodeBundle::from_nodes(
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(45.0),
+                    ..default()
+                },
+                ImageNode::default(),
+            ),
+            SpinnerButton::new(spinner_entity, direction),
+        )
+    };
+
+    spinner_commands.with_children(|spinner| {
+        spinner
+            .spawn(value_container)
+            .with_children(|value_container| {
+                value_container.spawn((value_text, SpinnerText(spinner_entity)));
+            });
+        spinner
+            .spawn(buttons_container)
+            .with_children(|buttons_container| {
+                buttons_container.spawn(create_spinner_button(SpinDirection::Increment));
+                buttons_container.spawn(create_spinner_button(SpinDirection::Decrement));
+            });
+    });
+
+    spinner_commands
+}
+
+fn get_spinner_text(spinner: &Spinner) -> String {
+    spinner.get_value().to_string()
+}
+
+/// Counts the focused spinner a step up or down.
+pub fn step_focused_spinners(
+    mut focus_inputs: MessageReader<FocusInput>,
+    mut focused_spinners: Query<(Entity, &mut Spinner), With<Focused>>,
+    mut spinner_changes: MessageWriter<SpinnerChanged>,
+) {
+    for focus_input in focus_inputs.read() {
+        let steps = match focus_input {
+            FocusInput::Increase => 1.0,
+            FocusInput::Decrease => -1.0,
+            _ => continue,
+        };
+
+        for (spinner_entity, mut spinner) in &mut focused_spinners {
+            if spinner.bounded_value.step_by(steps) {
+                spinner_changes.write(SpinnerChanged::new(spinner_entity, spinner.get_value()));
+            }
+        }
+    }
+}
+
+/// Counts a spinner up or down whenever one of its buttons is clicked.
+pub fn spinner_buttons_system(
+    spinner_buttons: Query<(&Interaction, &SpinnerButton), Changed<Interaction>>,
+    mut spinners: Query<&mut Spinner>,
+    mut spinner_changes: MessageWriter<SpinnerChanged>,
+) {
+    for (interaction, spinner_button) in &spinner_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut spinner) = spinners.get_mut(spinner_button.spinner) else {
+            warn!("spinner_buttons_system: A spinner button was clicked whose spinner is gone.");
+            continue;
+        };
+
+        let steps = match spinner_button.direction {
+            SpinDirection::Increment => 1.0,
+            SpinDirection::Decrement => -1.0,
+        };
+
+        if spinner.bounded_value.step_by(steps) {
+            spinner_changes.write(SpinnerChanged::new(
+                spinner_button.spinner,
+                spinner.get_value(),
+            ));
+        }
+    }
+}
+
+/// Keeps the text of each spinner showing its value.
+pub fn update_spinner_texts(
+    spinners: Query<Ref<Spinner>>,
+    mut spinner_texts: Query<(&SpinnerText, &mut Text)>,
+) {
+    for (spinner_text, mut text) in &mut spinner_texts {
+        let Ok(spinner) = spinners.get(spinner_text.0) else {
+            continue;
+        };
+
+        if !spinner.is_changed() {
+            continue;
+        }
+
+        text.0 = get_spinner_text(&spinner);
+    }
+}
+
+pub fn load_spinner_button_images(
+    asset_server: Res<AssetServer>,
+    mut spinner_buttons: Query<(&SpinnerButton, &mut ImageNode), Added<SpinnerButton>>,
+) {
+    for (spinner_button, mut image_node) in &mut spinner_buttons {
+        image_node.image = match spinner_button.direction {
+            SpinDirection::Increment => {
+                asset_server.load("textures/settings_menu/increment-button.png")
+            }
+            SpinDirection::Decrement => {
+                asset_server.load("textures/settings_menu/decrement-button.png")
+            }
+        };
+    }
+}
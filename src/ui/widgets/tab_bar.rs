@@ -0,0 +1,243 @@
+use bevy::prelude::*;
+
+use crate::ui::menus::ButtonNodeBundle;
+
+use super::{
+    create_widget_text,
+    focus::{FocusInput, Focusable, Focused},
+};
+
+/// How the tabs that aren't selected are tinted.
+const UNSELECTED_TAB_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+
+/// A row of tabs, where only the page of the selected tab is shown.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TabBar {
+    tabs: Vec<String>,
+    selected: usize,
+}
+
+impl TabBar {
+    pub fn new(tabs: Vec<String>) -> Self {
+        Self { tabs, selected: 0 }
+    }
+
+    pub fn get_tabs(&self) -> &Vec<String> {
+        &self.tabs
+    }
+
+    pub fn get_selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects some tab, if there is one. Returns whether the selection changed.
+    pub fn select(&mut self, selected: usize) -> bool {
+        if selected >= self.tabs.len() || selected == self.selected {
+            return false;
+        }
+
+        self.selected = selected;
+        true
+    }
+}
+
+/// The button of one of the tabs in some tab bar.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TabButton {
+    tab_bar: Entity,
+    index: usize,
+}
+
+impl TabButton {
+    pub fn new(tab_bar: Entity, index: usize) -> Self {
+        Self { tab_bar, index }
+    }
+}
+
+/// The contents of one of the tabs in some tab bar, only shown while that tab is selected.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TabPage {
+    tab_bar: Entity,
+    index: usize,
+}
+
+impl TabPage {
+    pub fn new(tab_bar: Entity, index: usize) -> Self {
+        Self { tab_bar, index }
+    }
+}
+
+/// Sent whenever the player selects a different tab in some tab bar.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct TabChanged {
+    tab_bar: Entity,
+    selected: usize,
+}
+
+impl TabChanged {
+    pub fn new(tab_bar: Entity, selected: usize) -> Self {
+        Self { tab_bar, selected }
+    }
+
+    pub fn get_tab_bar(&self) -> Entity {
+        self.tab_bar
+    }
+
+    pub fn get_selected(&self) -> usize {
+        self.selected
+    }
+}
+
+/// Spawns a tab bar, laid out by the given node with each tab laid out by the tab node,
+/// and returns it. Pages are spawned separately with a TabPage pointing back to it.
+pub fn spawn_tab_bar<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    tab_bar: TabBar,
+    node: Node,
+    tab_node: Node,
+) -> EntityCommands<'a> {
+    let tabs = tab_bar.get_tabs().clone();
+
+    let mut tab_bar_commands = parent.spawn((
+        Node {
+            flex_direction: FlexDirection::Row,
+            ..node
+        },
+        tab_bar,
+    ));
+    let tab_bar_entity = tab_bar_commands.id();
+
+    tab_bar_commands.with_children(|tab_bar| {
+        for (index, tab) in tabs.into_iter().enumerate() {
+            tab_bar
+                .spawn((
+                    ButtonNodeBundle::from_nodes(
+                        Node {
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..tab_node.clone()
+                        },
+                        ImageNode::default(),
+                    ),
+                    TabButton::new(tab_bar_entity, index),
+                    Focusable,
+                ))
+                .with_children(|tab_button| {
+                    tab_button.spawn(create_widget_text(tab, 25.0));
+                });
+        }
+    });
+
+    tab_bar_commands
+}
+
+/// Selects the tab of each tab button that was pressed.
+pub fn press_tab_buttons(
+    tab_buttons: Query<(&Interaction, &TabButton), Changed<Interaction>>,
+    mut tab_bars: Query<&mut TabBar>,
+    mut tab_changes: MessageWriter<TabChanged>,
+) {
+    for (interaction, tab_button) in &tab_buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut tab_bar) = tab_bars.get_mut(tab_button.tab_bar) else {
+            warn!("press_tab_buttons: A tab was pressed whose tab bar is gone.");
+            continue;
+        };
+
+        if tab_bar.select(tab_button.index) {
+            tab_changes.write(TabChanged::new(tab_button.tab_bar, tab_button.index));
+        }
+    }
+}
+
+/// Selects the next tab along when the focused tab is turned up, or the one before it when
+/// turned down, taking the focus along with it.
+pub fn step_focused_tabs(
+    mut focus_inputs: MessageReader<FocusInput>,
+    focused_tab_buttons: Query<(Entity, &TabButton), With<Focused>>,
+    tab_buttons: Query<(Entity, &TabButton)>,
+    mut tab_bars: Query<&mut TabBar>,
+    mut tab_changes: MessageWriter<TabChanged>,
+    mut commands: Commands,
+) {
+    for focus_input in focus_inputs.read() {
+        for (focused_button, tab_button) in &focused_tab_buttons {
+            let new_selected = match focus_input {
+                FocusInput::Increase => tab_button.index + 1,
+                FocusInput::Decrease if tab_button.index > 0 => tab_button.index - 1,
+                _ => continue,
+            };
+
+            let Ok(mut tab_bar) = tab_bars.get_mut(tab_button.tab_bar) else {
+                continue;
+            };
+
+            if !tab_bar.select(new_selected) {
+                continue;
+            }
+
+            tab_changes.write(TabChanged::new(tab_button.tab_bar, new_selected));
+
+            let new_tab_button = tab_buttons.iter().find(|(_, other_button)| {
+                **other_button == TabButton::new(tab_button.tab_bar, new_selected)
+            });
+
+            if let Some((new_focused_button, _)) = new_tab_button {
+                commands.entity(focused_button).remove::<Focused>();
+                commands.entity(new_focused_button).insert(Focused);
+            }
+        }
+    }
+}
+
+/// Shows the page of the selected tab of each tab bar, hiding the rest, and tints the tabs
+/// that aren't selected.
+pub fn show_selected_tabs(
+    tab_bars: Query<Ref<TabBar>>,
+    mut tab_pages: Query<(&TabPage, &mut Node)>,
+    mut tab_buttons: Query<(&TabButton, &mut ImageNode)>,
+) {
+    for (tab_page, mut node) in &mut tab_pages {
+        let Ok(tab_bar) = tab_bars.get(tab_page.tab_bar) else {
+            continue;
+        };
+
+        let display = if tab_bar.get_selected() == tab_page.index {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    for (tab_button, mut image_node) in &mut tab_buttons {
+        let Ok(tab_bar) = tab_bars.get(tab_button.tab_bar) else {
+            continue;
+        };
+
+        if !tab_bar.is_changed() {
+            continue;
+        }
+
+        image_node.color = if tab_bar.get_selected() == tab_button.index {
+            Color::WHITE
+        } else {
+            UNSELECTED_TAB_COLOR
+        };
+    }
+}
+
+pub fn load_tab_button_images(
+    asset_server: Res<AssetServer>,
+    mut tab_buttons: Query<&mut ImageNode, Added<TabButton>>,
+) {
+    for mut image_node in &mut tab_buttons {
+        image_node.image = asset_server.load("textures/settings_menu/tab-box.png");
+    }
+}
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use super::{
+    create_widget_button, create_widget_text,
+    focus::{FocusInput, Focusable, Focused},
+};
+
+/// A button that's switched on and off each time it's pressed.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Toggle {
+    on: bool,
+    /// What the toggle reads while switched on.
+    on_label: String,
+    /// What the toggle reads while switched off.
+    off_label: String,
+}
+
+impl Toggle {
+    pub fn new(on: bool) -> Self {
+        Self {
+            on,
+            on_label: String::from("On"),
+            off_label: String::from("Off"),
+        }
+    }
+
+    /// Returns the toggle, reading the given labels instead.
+    pub fn with_labels(
+        mut self,
+        on_label: impl Into<String>,
+        off_label: impl Into<String>,
+    ) -> Self {
+        self.on_label = on_label.into();
+        self.off_label = off_label.into();
+        self
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    pub fn get_label(&self) -> &str {
+        if self.on {
+            &self.on_label
+        } else {
+            &self.off_label
+        }
+    }
+}
+
+/// The text showing the label of some toggle.
+#[derive(Component)]
+pub struct ToggleText(pub Entity);
+
+/// Sent whenever the player switches some toggle on or off.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ToggleChanged {
+    toggle: Entity,
+    on: bool,
+}
+
+impl ToggleChanged {
+    pub fn new(toggle: Entity, on: bool) -> Self {
+        Self { toggle, on }
+    }
+
+    pub fn get_toggle(&self) -> Entity {
+        self.toggle
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.on
+    }
+}
+
+/// Spawns a toggle, laid out by the given node, and returns it.
+pub fn spawn_toggle<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    toggle: Toggle,
+    node: Node,
+) -> EntityCommands<'a> {
+    let label_text = create_widget_text(toggle.get_label(), 20.0);
+
+    let mut toggle_commands = parent.spawn((
+        create_widget_button(Node {
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..node
+        }),
+        toggle,
+        Focusable,
+    ));
+    let toggle_entity = toggle_commands.id();
+
+    toggle_commands.with_children(|toggle| {
+        toggle.spawn((label_text, ToggleText(toggle_entity)));
+    });
+
+    toggle_commands
+}
+
+/// Switches each toggle that was pressed.
+pub fn press_toggles(
+    mut toggles: Query<(Entity, &Interaction, &mut Toggle), Changed<Interaction>>,
+    mut toggle_changes: MessageWriter<ToggleChanged>,
+) {
+    for (toggle_entity, interaction, mut toggle) in &mut toggles {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let on = !toggle.is_on();
+        toggle.set_on(on);
+        toggle_changes.write(ToggleChanged::new(toggle_entity, on));
+    }
+}
+
+/// Switches the focused toggle on when turned up, or off when turned down.
+pub fn switch_focused_toggles(
+    mut focus_inputs: MessageReader<FocusInput>,
+    mut focused_toggles: Query<(Entity, &mut Toggle), With<Focused>>,
+    mut toggle_changes: MessageWriter<ToggleChanged>,
+) {
+    for focus_input in focus_inputs.read() {
+        let on = match focus_input {
+            FocusInput::Increase => true,
+            FocusInput::Decrease => false,
+            _ => continue,
+        };
+
+        for (toggle_entity, mut toggle) in &mut focused_toggles {
+            if toggle.is_on() != on {
+                toggle.set_on(on);
+                toggle_changes.write(ToggleChanged::new(toggle_entity, on));
+            }
+        }
+    }
+}
+
+/// Keeps the text of each toggle showing whether it's on.
+pub fn update_toggle_texts(
+    toggles: Query<Ref<Toggle>>,
+    mut toggle_texts: Query<(&ToggleText, &mut Text)>,
+) {
+    for (toggle_text, mut text) in &mut toggle_texts {
+        let Ok(toggle) = toggles.get(toggle_text.0) else {
+            continue;
+        };
+
+        if toggle.is_changed() && text.0 != toggle.get_label() {
+            text.0 = String::from(toggle.get_label());
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use cucumber::{given, then, when, World};
-use helping_hand::narrative::acts::*;
+use helping_hand::narrative::{acts::*, story_flags::StoryFlag};
 use std::{path::PathBuf, time::Duration};
 
 #[derive(Debug, World)]
@@ -222,6 +222,132 @@ fn verify_scene_connection_exists(
     assert!(connection_has_expected_scene);
 }
 
+#[then(regex = r"the act's scene called '(.+)' sets the story flag (.+) to (.+)[,.]")]
+fn verify_flag_on_entry(
+    game: &mut GameWorld,
+    scene_title: String,
+    flag_name: String,
+    flag_value: String,
+) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let found_flag = actual_scene
+        .get_flags_on_entry()
+        .iter()
+        .find(|(actual_flag_name, _)| *actual_flag_name == flag_name)
+        .expect("verify_flag_on_entry: The scene doesn't set the given story flag.");
+
+    assert_eq!(StoryFlag::parse_from(&flag_value), found_flag.1);
+}
+
+#[then(regex = r"the act's scene called '(.+)' sets ([0-9]+) story flags.")]
+fn verify_number_of_flags_on_entry(game: &mut GameWorld, scene_title: String, num_flags: usize) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    assert_eq!(num_flags, actual_scene.get_flags_on_entry().len());
+}
+
+#[then(regex = r"the act's scene called '(.+)' transitions in with a (.+) transition[,.]")]
+fn verify_image_transition(game: &mut GameWorld, scene_title: String, transition_name: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let expected_transition = ImageTransition::parse_from(&transition_name)
+        .expect("verify_image_transition: Unknown image transition.");
+    let actual_transition = actual_scene.get_image_cutscene_settings().get_transition();
+
+    assert_eq!(expected_transition, actual_transition);
+}
+
+#[then(regex = r"the act's scene called '(.+)' takes ([0-9.]+) seconds to transition in[,.]")]
+fn verify_transition_duration(game: &mut GameWorld, scene_title: String, seconds: f32) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let actual_duration = actual_scene
+        .get_image_cutscene_settings()
+        .get_transition_duration();
+
+    assert_eq!(Some(Duration::from_secs_f32(seconds)), actual_duration);
+}
+
+#[then(regex = r"the act's scene called '(.+)' takes the default time to transition in[,.]")]
+fn verify_default_transition_duration(game: &mut GameWorld, scene_title: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let actual_duration = actual_scene
+        .get_image_cutscene_settings()
+        .get_transition_duration();
+
+    assert_eq!(None, actual_duration);
+}
+
+#[then(regex = r"the act's scene called '(.+)' advances on its own after ([0-9.]+) seconds[,.]")]
+fn verify_auto_advance_delay(game: &mut GameWorld, scene_title: String, seconds: f32) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let actual_delay = actual_scene
+        .get_image_cutscene_settings()
+        .get_auto_advance_delay();
+
+    assert_eq!(Some(Duration::from_secs_f32(seconds)), actual_delay);
+}
+
+#[then(regex = r"the act's scene called '(.+)' waits for the player to advance[,.]")]
+fn verify_no_auto_advance(game: &mut GameWorld, scene_title: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let actual_delay = actual_scene
+        .get_image_cutscene_settings()
+        .get_auto_advance_delay();
+
+    assert_eq!(None, actual_delay);
+}
+
+#[then(regex = r"^the act's scene called '(.+)' (can|can't) be skipped[,.]$")]
+fn verify_skippable(game: &mut GameWorld, scene_title: String, can_skip: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    let expected_skippable = can_skip == "can";
+    let actual_skippable = actual_scene.get_image_cutscene_settings().is_skippable();
+
+    assert_eq!(expected_skippable, actual_skippable);
+}
+
+#[then(regex = r"the act's scene called '(.+)' plays the track (.+)[,.]")]
+fn verify_scene_music(game: &mut GameWorld, scene_title: String, expected_track: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    assert_eq!(
+        Some(&PathBuf::from(expected_track)),
+        actual_scene.get_music()
+    );
+}
+
+#[then(regex = r"the act's scene called '(.+)' plays no music of its own[,.]")]
+fn verify_no_scene_music(game: &mut GameWorld, scene_title: String) {
+    let act = &game.current_act;
+    let actual_scene = act.get_scene_by_title(&scene_title);
+
+    assert_eq!(None, actual_scene.get_music());
+}
+
+#[then(regex = r"a transition written as '(.+)' is a (.+) transition[,.]")]
+fn verify_transition_name(_game: &mut GameWorld, written_name: String, transition_name: String) {
+    let expected_transition = ImageTransition::parse_from(&transition_name);
+    let actual_transition = ImageTransition::parse_from(&written_name);
+
+    assert!(expected_transition.is_some());
+    assert_eq!(expected_transition, actual_transition);
+}
+
 fn main() {
     futures::executor::block_on(GameWorld::run("tests/feature_files/in-theory/acts.feature"));
 }
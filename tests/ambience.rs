@@ -0,0 +1,102 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use helping_hand::{
+    map::{
+        ambient_sounds::{AmbientMix, AmbientSound},
+        interactions::map_changing::{ChangeLevel, LoadLevel},
+        movement::grid_based_movement::{MovementDirection, Target},
+    },
+    plugins::levels::CoreLevelsPlugin,
+};
+use std::path::PathBuf;
+
+const AMBIENCE_MAP_FOLDER: &str = "tests/test_assets/maps/ambience";
+
+/// Returns how the ambient sound playing some file is currently heard.
+fn get_mix_of(game: &mut Game, file: &str) -> AmbientMix {
+    let file = PathBuf::from(file);
+
+    let ambient_sound = game
+        .get_all_cloned_of::<AmbientSound, AmbientMix>()
+        .into_iter()
+        .find(|ambient_sound| ambient_sound.get_file() == &file)
+        .expect("get_mix_of: No ambient sound plays that file.");
+
+    game.find_containing::<AmbientMix, AmbientSound>(&ambient_sound)
+        .unwrap()
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+
+    let map_path = format!("{}/{}", AMBIENCE_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+    game.tick();
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: usize, tile_y: usize) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y, actual_player_tile_coordinate.get_y());
+}
+
+#[when(regex = r"the Player moves (left|right),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = match movement_direction.as_str() {
+        "left" => MovementDirection::Left,
+        _ => MovementDirection::Right,
+    };
+    game.write_message(movement_direction_event);
+
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+    game.tick();
+}
+
+#[when(regex = r"the level changes to (.+),")]
+fn change_level(game: &mut Game, tiled_map_name: String) {
+    let map_path = format!("{}/{}", AMBIENCE_MAP_FOLDER, tiled_map_name);
+    game.write_message(ChangeLevel::new(&map_path));
+    game.tick();
+}
+
+#[then(regex = r"there (?:is|are) ([0-9]+) ambient sounds? on the map[,.]$")]
+fn verify_number_of_ambient_sounds(game: &mut Game, expected_amount: usize) {
+    assert_eq!(expected_amount, game.get_number_of::<AmbientSound>());
+}
+
+#[then(regex = r"(.+) is heard at a volume of ([0-9.]+), panned to (-?[0-9.]+)[,.]$")]
+fn verify_ambient_mix(game: &mut Game, file: String, volume: f32, panning: f32) {
+    let ambient_mix = get_mix_of(game, &file);
+
+    assert!(
+        (ambient_mix.get_volume() - volume).abs() < 0.001,
+        "Expected a volume of {}, but found {}",
+        volume,
+        ambient_mix.get_volume()
+    );
+    assert!(
+        (ambient_mix.get_panning() - panning).abs() < 0.001,
+        "Expected a panning of {}, but found {}",
+        panning,
+        ambient_mix.get_panning()
+    );
+}
+
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/ambience.feature",
+    ));
+}
@@ -0,0 +1,134 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    audio::buses::{AudioBus, AudioBuses},
+    plugins::{settings_menu::CoreSettingsPlugin, widgets::TogglePlugin},
+    settings::SaveSettings,
+    ui::{
+        menus::settings_menu::{mute_buses, MuteToggle},
+        widgets::toggle::{press_toggles, Toggle},
+    },
+};
+use std::{fs, path::PathBuf};
+
+/// Lets mute toggles be clicked without the rest of the settings menu.
+struct MuteTogglesPlugin;
+
+impl Plugin for MuteTogglesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TogglePlugin)
+            .add_systems(Update, mute_buses.after(press_toggles));
+    }
+}
+
+/// Where settings are saved to and loaded back from.
+fn get_settings_file_path() -> PathBuf {
+    std::env::temp_dir().join("helping_hand_tests/audio_buses_settings.json")
+}
+
+/// Where settings are loaded from, but never saved to.
+fn get_unsaved_settings_file_path() -> PathBuf {
+    std::env::temp_dir().join("helping_hand_tests/unsaved_settings.json")
+}
+
+/// Returns the bus with some name, as shown in the settings menu.
+fn get_bus_named(bus_name: &str) -> AudioBus {
+    AudioBus::ALL
+        .into_iter()
+        .find(|audio_bus| audio_bus.get_name() == bus_name)
+        .expect("get_bus_named: There is no bus with that name.")
+}
+
+#[given("settings that were never saved,")]
+fn given_unsaved_settings(game: &mut Game) {
+    game.add_plugin(CoreSettingsPlugin::new(get_unsaved_settings_file_path()));
+    game.add_plugin(MuteTogglesPlugin);
+}
+
+#[given("settings that are saved between plays,")]
+fn given_saved_settings(game: &mut Game) {
+    let _ = fs::remove_file(get_settings_file_path());
+
+    game.add_plugin(CoreSettingsPlugin::new(get_settings_file_path()));
+}
+
+#[given(regex = r"the (.+) volume is set to ([0-9.]+),")]
+fn set_bus_volume(game: &mut Game, bus_name: String, volume: f32) {
+    game.get_res_mut::<AudioBuses>()
+        .set_volume(get_bus_named(&bus_name), volume);
+}
+
+#[given(regex = r"the (.+) bus is muted,")]
+#[when(regex = r"the (.+) bus is muted,")]
+fn mute_bus(game: &mut Game, bus_name: String) {
+    game.get_res_mut::<AudioBuses>()
+        .set_muted(get_bus_named(&bus_name), true);
+}
+
+#[when(regex = r"the mute button of the (.+) bus is clicked,")]
+fn click_mute_button(game: &mut Game, bus_name: String) {
+    let audio_bus = get_bus_named(&bus_name);
+    let is_muted = game.get_res::<AudioBuses>().is_muted(audio_bus);
+
+    game.spawn((
+        Button,
+        Interaction::Pressed,
+        Toggle::new(is_muted),
+        MuteToggle(audio_bus),
+    ));
+    game.tick();
+}
+
+#[when("the settings are saved,")]
+fn save_settings(game: &mut Game) {
+    game.write_message(SaveSettings::new());
+}
+
+#[when("the game is started again,")]
+fn restart_game(game: &mut Game) {
+    *game = Game::new();
+    game.add_plugin(CoreSettingsPlugin::new(get_settings_file_path()));
+}
+
+#[then(regex = r"the (.+) bus plays at a volume of ([0-9.]+)[,.]$")]
+fn verify_effective_volume(game: &mut Game, bus_name: String, expected_volume: f32) {
+    let audio_buses = game.get_res::<AudioBuses>();
+    let effective_volume = audio_buses.get_effective_volume(get_bus_named(&bus_name));
+
+    assert!(
+        (effective_volume - expected_volume).abs() < 0.001,
+        "Expected a volume of {}, but found {}",
+        expected_volume,
+        effective_volume
+    );
+}
+
+#[then(regex = r"the (.+) volume is set to ([0-9.]+)[,.]$")]
+fn verify_bus_volume(game: &mut Game, bus_name: String, expected_volume: f32) {
+    let audio_buses = game.get_res::<AudioBuses>();
+
+    assert_eq!(
+        expected_volume,
+        audio_buses.get_volume(get_bus_named(&bus_name))
+    );
+}
+
+#[then(regex = r"the (.+) bus is (muted|not muted)[,.]$")]
+fn verify_bus_muted(game: &mut Game, bus_name: String, muted: String) {
+    let audio_buses = game.get_res::<AudioBuses>();
+
+    assert_eq!(
+        muted == "muted",
+        audio_buses.is_muted(get_bus_named(&bus_name))
+    );
+}
+
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/audio_buses.feature",
+    ));
+}
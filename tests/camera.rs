@@ -1,15 +1,24 @@
 mod mock_game;
 
+use std::time::Duration;
+
 use bevy::prelude::*;
 use cucumber::{given, then, when, World};
 use helping_hand::{
-    map::{interactions::map_changing::LoadLevel, movement::grid_based_movement::*, *},
-    plugins::levels::CoreLevelsPlugin,
+    display::DisplaySettings,
+    map::{
+        camera::{CameraController, CameraFollow, PanCamera, PanTarget, ShakeCamera},
+        interactions::map_changing::LoadLevel,
+        movement::grid_based_movement::*,
+        *,
+    },
+    plugins::{display::DisplayPlugin, levels::CoreLevelsPlugin},
 };
 
 use mock_game::Game;
 
 const MAX_NUM_ATTEMPTS: usize = 255;
+const TIME_STEP: Duration = Duration::from_millis(5);
 
 /// Converts a string into a MovementDirection
 fn convert_string_to_movement_direction(movement_string: String) -> MovementDirection {
@@ -30,6 +39,7 @@ fn convert_string_to_movement_direction(movement_string: String) -> MovementDire
 #[given(regex = r"a Tiled map called (.+),")]
 fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
     game.add_plugin(CoreLevelsPlugin);
+    game.set_time_step(TIME_STEP);
 
     let map_path = format!("tests/test_assets/maps/camera/{}", tiled_map_name);
     game.write_message(LoadLevel::new(&map_path));
@@ -40,6 +50,19 @@ fn given_some_window_resolution(game: &mut Game, window_width: u32, window_heigh
     game.set_window_resolution(window_width, window_height);
 }
 
+#[given(regex = r"a camera zoom of ([0-9]+),")]
+fn given_some_camera_zoom(game: &mut Game, camera_zoom: u32) {
+    game.add_plugin(DisplayPlugin);
+    game.get_res_mut::<DisplaySettings>()
+        .set_camera_zoom(camera_zoom);
+}
+
+#[given(regex = r"each level's camera is given a dead zone of ([0-9]+)px by ([0-9]+)px,")]
+fn given_camera_follow_dead_zone(game: &mut Game, dead_zone_width: f32, dead_zone_height: f32) {
+    *game.get_res_mut::<CameraFollow>() =
+        CameraFollow::new().with_dead_zone(Vec2::new(dead_zone_width, dead_zone_height));
+}
+
 #[when("the map is spawned,")]
 fn when_map_spawned(game: &mut Game) {
     for _i in 0..MAX_NUM_ATTEMPTS {
@@ -66,6 +89,47 @@ fn when_map_spawned(game: &mut Game) {
     }
 }
 
+#[when("the camera is set to smooth its movement,")]
+fn set_camera_smoothing(game: &mut Game) {
+    *game.get_mut::<CameraController>() = CameraController::new().with_smoothing(5.0);
+}
+
+#[when(regex = r"the camera is given a dead zone of ([0-9]+)px by ([0-9]+)px,")]
+fn set_camera_dead_zone(game: &mut Game, dead_zone_width: f32, dead_zone_height: f32) {
+    *game.get_mut::<CameraController>() =
+        CameraController::new().with_dead_zone(Vec2::new(dead_zone_width, dead_zone_height));
+}
+
+#[when(regex = r"the camera shakes by ([0-9]+)px for ([0-9.]+) seconds,")]
+fn shake_camera(game: &mut Game, intensity: f32, duration: f32) {
+    game.write_message(ShakeCamera::new(
+        intensity,
+        Duration::from_secs_f32(duration),
+    ));
+}
+
+#[when(
+    regex = r"the camera pans to tile ([0-9]+), ([0-9]+) over ([0-9.]+) seconds, holding for ([0-9.]+) seconds,"
+)]
+fn pan_camera_to_tile(game: &mut Game, grid_x: usize, grid_y: usize, duration: f32, hold: f32) {
+    let pan_target = PanTarget::Tile(GridCords2D::new(grid_x, grid_y));
+    game.write_message(
+        PanCamera::new(pan_target, Duration::from_secs_f32(duration))
+            .with_hold(Duration::from_secs_f32(hold)),
+    );
+}
+
+#[when(
+    regex = r"the camera pans to ([a-zA-Z]+) over ([0-9.]+) seconds, holding for ([0-9.]+) seconds,"
+)]
+fn pan_camera_to_character(game: &mut Game, character_name: String, duration: f32, hold: f32) {
+    let pan_target = PanTarget::Character(character_name);
+    game.write_message(
+        PanCamera::new(pan_target, Duration::from_secs_f32(duration))
+            .with_hold(Duration::from_secs_f32(hold)),
+    );
+}
+
 #[when(regex = r"the player is requested to move ([a-zA-Z]+),")]
 fn request_player_to_move(game: &mut Game, movement_direction: String) {
     let movement_direction_event = convert_string_to_movement_direction(movement_direction);
@@ -86,6 +150,22 @@ fn move_player_in_direction(game: &mut Game, movement_direction: String) {
     }
 }
 
+#[when(regex = r"the camera zoom is set to ([0-9]+),")]
+fn set_camera_zoom(game: &mut Game, camera_zoom: u32) {
+    game.get_res_mut::<DisplaySettings>()
+        .set_camera_zoom(camera_zoom);
+
+    // The camera is only clamped to its new view of the map the frame after it's zoomed.
+    for _i in 0..3 {
+        game.tick();
+    }
+}
+
+#[when("the zoom in key is pressed,")]
+fn press_zoom_in_key(game: &mut Game) {
+    game.press_key(KeyCode::Equal);
+}
+
 #[then(regex = r"the player's x and y positions should be ([0-9]+)px, ([0-9]+)px.")]
 fn verify_player_x_y_position(game: &mut Game, expected_player_x: f32, expected_player_y: f32) {
     let actual_player_position = game.get_player_position();
@@ -129,6 +209,69 @@ fn verify_player_center_location(game: &mut Game, expected_player_x: f32, expect
     assert_eq!(expected_player_y, actual_player_y);
 }
 
+#[then(regex = r"the camera is on its way from ([0-9]+)px to ([0-9]+)px.")]
+fn verify_camera_on_its_way(game: &mut Game, from_x: f32, to_x: f32) {
+    let actual_camera_x = game.get_of::<Transform, Camera2d>().translation.x;
+
+    assert!(
+        from_x < actual_camera_x && actual_camera_x < to_x,
+        "Expected the camera to be between {}px and {}px, but it was at {}px.",
+        from_x,
+        to_x,
+        actual_camera_x
+    );
+}
+
+#[then(regex = r"the camera settles at ([0-9]+)px, ([0-9]+)px[,.]$")]
+fn verify_camera_settles(game: &mut Game, expected_camera_x: f32, expected_camera_y: f32) {
+    let expected_camera_position = Vec2::new(expected_camera_x, expected_camera_y);
+
+    let has_settled = game.tick_until(MAX_NUM_ATTEMPTS, |game| {
+        game.get_of::<Transform, Camera2d>().translation.truncate() == expected_camera_position
+    });
+
+    assert!(
+        has_settled,
+        "Expected the camera to settle at {}, but it was at {}",
+        expected_camera_position,
+        game.get_of::<Transform, Camera2d>().translation.truncate()
+    );
+}
+
+#[then(
+    regex = r"the camera is shaken away from ([0-9]+)px, ([0-9]+)px by no more than ([0-9]+)px."
+)]
+fn verify_camera_shaken(game: &mut Game, resting_x: f32, resting_y: f32, intensity: f32) {
+    let resting_position = Vec2::new(resting_x, resting_y);
+
+    for _ in 0..MAX_NUM_ATTEMPTS {
+        game.tick();
+
+        let shake_offset =
+            game.get_of::<Transform, Camera2d>().translation.truncate() - resting_position;
+        if shake_offset == Vec2::ZERO {
+            continue;
+        }
+
+        assert!(
+            shake_offset.abs().max_element() <= intensity,
+            "The camera was shaken {} away, further than {}px.",
+            shake_offset,
+            intensity
+        );
+        return;
+    }
+
+    panic!("The camera was never shaken.");
+}
+
+#[then("the camera goes back to following the player.")]
+fn verify_camera_following(game: &mut Game) {
+    assert!(game
+        .get_cloned_of::<CameraController, Camera2d>()
+        .is_following());
+}
+
 #[then(
     regex = r"the camera's position and player tile's center position are both ([0-9]+)px, ([0-9]+)px."
 )]
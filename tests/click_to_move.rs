@@ -0,0 +1,188 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+
+use bevy::prelude::*;
+use cucumber::{given, then, when, World};
+
+use helping_hand::{
+    map::{
+        interactions::map_changing::LoadLevel,
+        movement::{click_to_move::*, collision::CollisionCollection, grid_based_movement::Target},
+        GridCords3D, GridDimensions, PxDimensions,
+    },
+    plugins::levels::CoreLevelsPlugin,
+};
+
+/// Converts a string into the key pressed to move in that direction
+fn convert_string_to_key_code(movement_string: String) -> KeyCode {
+    let key_code = match movement_string.as_str() {
+        "left" => KeyCode::KeyA,
+        "right" => KeyCode::KeyD,
+        "up" => KeyCode::KeyW,
+        "down" => KeyCode::KeyS,
+        _ => panic!(
+            "convert_string_to_key_code: Invalid direction given: {}",
+            movement_string
+        ),
+    };
+
+    key_code
+}
+
+/// Returns the grid coordinates found at some position in the world of the loaded map.
+fn find_grid_cords_at(game: &mut Game, world_x: f32, world_y: f32) -> Option<GridCords3D> {
+    let map_px_dimensions = game.get_of::<PxDimensions, CollisionCollection>();
+    let map_grid_dimensions = game.get_of::<GridDimensions, CollisionCollection>();
+
+    get_grid_cords_from_world_position(
+        Vec2::new(world_x, world_y),
+        &map_px_dimensions,
+        &map_grid_dimensions,
+        1,
+    )
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+
+    let map_path = format!("tests/test_assets/maps/click_to_move/{}", tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32, tile_z: usize) {
+    let expected_player_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+    assert_eq!(
+        expected_player_tile_coordinate,
+        actual_player_tile_coordinate
+    );
+}
+
+#[when(regex = r"the Player clicks on tile ([0-9]+),([0-9]+),")]
+fn click_on_tile(game: &mut Game, tile_x: usize, tile_y: usize) {
+    let player_layer = game.find_coordinates_of_player().get_z();
+    game.write_message(WalkToTile::new(GridCords3D::new(
+        tile_x,
+        tile_y,
+        player_layer,
+    )));
+
+    for _i in 0..255 {
+        game.tick();
+
+        let has_traveled =
+            game.get_number_of::<QueuedPath>() == 0 && game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+
+    // Gives any interaction at the end of the path time to take place.
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the Player starts walking to tile ([0-9]+),([0-9]+),")]
+fn start_walking_to_tile(game: &mut Game, tile_x: usize, tile_y: usize) {
+    let player_layer = game.find_coordinates_of_player().get_z();
+    game.write_message(WalkToTile::new(GridCords3D::new(
+        tile_x,
+        tile_y,
+        player_layer,
+    )));
+}
+
+#[when(regex = r"the Player presses ([a-zA-Z]+),")]
+fn press_direction_key(game: &mut Game, movement_direction: String) {
+    let key_code = convert_string_to_key_code(movement_direction);
+
+    game.get_res_mut::<ButtonInput<KeyCode>>().press(key_code);
+    game.tick();
+    game.get_res_mut::<ButtonInput<KeyCode>>().release(key_code);
+
+    for _i in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+}
+
+#[then(regex = r"the world position (-?[0-9]+),(-?[0-9]+) is on tile ([0-9]+),([0-9]+).")]
+fn verify_world_position_on_tile(
+    game: &mut Game,
+    world_x: f32,
+    world_y: f32,
+    tile_x: usize,
+    tile_y: usize,
+) {
+    let expected_grid_cords = GridCords3D::new(tile_x, tile_y, 1);
+    let actual_grid_cords = find_grid_cords_at(game, world_x, world_y);
+
+    assert_eq!(Some(expected_grid_cords), actual_grid_cords);
+}
+
+#[then(regex = r"the world position (-?[0-9]+),(-?[0-9]+) is off the map.")]
+fn verify_world_position_off_map(game: &mut Game, world_x: f32, world_y: f32) {
+    let actual_grid_cords = find_grid_cords_at(game, world_x, world_y);
+
+    assert_eq!(None, actual_grid_cords);
+}
+
+#[then("the Player is not following a path.")]
+fn verify_no_queued_path(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<QueuedPath>());
+}
+
+#[then(regex = r"the Player's grid coordinates are set to tile ([0-9]+),([0-9]+),([0-9]+).")]
+fn verify_player_at_tile_grid_coordinates(
+    game: &mut Game,
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: usize,
+) {
+    let expected_player_tile_grid_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let actual_player_tile_grid_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(
+        expected_player_tile_grid_coordinate,
+        actual_player_tile_grid_coordinate
+    );
+}
+
+#[then(regex = r"the Player's grid coordinates are not set to tile ([0-9]+),([0-9]+),([0-9]+).")]
+fn verify_player_not_at_tile_grid_coordinates(
+    game: &mut Game,
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: usize,
+) {
+    let unexpected_player_tile_grid_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let actual_player_tile_grid_coordinate = game.find_coordinates_of_player();
+
+    assert_ne!(
+        unexpected_player_tile_grid_coordinate,
+        actual_player_tile_grid_coordinate
+    );
+}
+
+#[then(regex = r"the map size should be ([0-9]+) x ([0-9]+) tiles.")]
+fn verify_map_size(game: &mut Game, expected_columns: u32, expected_rows: u32) {
+    let map_size = game.get_map_size();
+
+    assert_eq!(expected_columns, map_size.get_columns());
+    assert_eq!(expected_rows, map_size.get_rows());
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/click_to_move.feature",
+    ));
+}
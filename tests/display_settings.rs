@@ -0,0 +1,201 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::{
+    prelude::*,
+    window::{PresentMode, WindowMode},
+};
+use helping_hand::{
+    display::{get_window_resolution, DisplaySettings, Resolution, WindowModeSetting},
+    plugins::{display::DisplayPlugin, settings_menu::CoreSettingsPlugin, widgets::WidgetsPlugin},
+    settings::SaveSettings,
+    ui::{
+        menus::settings_menu::{change_display_settings, DisplayControl},
+        widgets::toggle::{press_toggles, Toggle},
+    },
+};
+use std::{fs, path::PathBuf};
+
+/// Lets the widgets of the display tab be used without the rest of the settings menu.
+struct DisplayControlsPlugin;
+
+impl Plugin for DisplayControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(WidgetsPlugin)
+            .add_systems(Update, change_display_settings.after(press_toggles));
+    }
+}
+
+/// Where settings are saved to and loaded back from.
+fn get_settings_file_path() -> PathBuf {
+    std::env::temp_dir().join("helping_hand_tests/display_settings.json")
+}
+
+/// Where settings are loaded from, but never saved to.
+fn get_unsaved_settings_file_path() -> PathBuf {
+    std::env::temp_dir().join("helping_hand_tests/unsaved_display_settings.json")
+}
+
+fn get_window_mode_named(window_mode_name: &str) -> WindowModeSetting {
+    WindowModeSetting::ALL
+        .into_iter()
+        .find(|window_mode| window_mode.get_name() == window_mode_name)
+        .expect("get_window_mode_named: There is no window mode with that name.")
+}
+
+fn get_display_control_named(display_control_name: &str) -> DisplayControl {
+    match display_control_name {
+        "vsync" => DisplayControl::Vsync,
+        "pixel-perfect" => DisplayControl::PixelPerfect,
+        _ => panic!(
+            "get_display_control_named: There is no toggle named {}.",
+            display_control_name
+        ),
+    }
+}
+
+#[given("display settings that were never saved,")]
+fn given_unsaved_settings(game: &mut Game) {
+    game.add_plugin(CoreSettingsPlugin::new(get_unsaved_settings_file_path()));
+    game.add_plugin(DisplayPlugin);
+    game.add_plugin(DisplayControlsPlugin);
+    game.tick();
+}
+
+#[given("display settings that are saved between plays,")]
+fn given_saved_settings(game: &mut Game) {
+    let _ = fs::remove_file(get_settings_file_path());
+
+    game.add_plugin(CoreSettingsPlugin::new(get_settings_file_path()));
+    game.add_plugin(DisplayPlugin);
+    game.tick();
+}
+
+#[given(regex = r"a screen scaled by ([0-9.]+),")]
+fn given_screen_scale(game: &mut Game, scale_factor: f32) {
+    game.get_mut::<Window>()
+        .resolution
+        .set_scale_factor_override(Some(scale_factor));
+}
+
+#[given("a camera,")]
+fn given_camera(game: &mut Game) {
+    game.spawn(Camera2d);
+    game.tick();
+}
+
+#[given(regex = r"the window mode is set to (.+),")]
+#[when(regex = r"the window mode is set to (.+),")]
+fn set_window_mode(game: &mut Game, window_mode_name: String) {
+    game.get_res_mut::<DisplaySettings>()
+        .set_window_mode(get_window_mode_named(&window_mode_name));
+    game.tick();
+}
+
+#[given(regex = r"the resolution is set to ([0-9]+) x ([0-9]+),")]
+#[when(regex = r"the resolution is set to ([0-9]+) x ([0-9]+),")]
+fn set_resolution(game: &mut Game, width: u32, height: u32) {
+    game.get_res_mut::<DisplaySettings>()
+        .set_resolution(Resolution::new(width, height));
+    game.tick();
+}
+
+#[given(regex = r"the UI scale is set to ([0-9.]+),")]
+#[when(regex = r"the UI scale is set to ([0-9.]+),")]
+fn set_ui_scale(game: &mut Game, ui_scale: f32) {
+    game.get_res_mut::<DisplaySettings>().set_ui_scale(ui_scale);
+    game.tick();
+}
+
+#[when(regex = r"the (.+) toggle is switched (on|off),")]
+fn switch_toggle(game: &mut Game, display_control_name: String, state: String) {
+    // The toggle is pressed while it's in the other state, which switches it over.
+    game.spawn((
+        Button,
+        Interaction::Pressed,
+        Toggle::new(state != "on"),
+        get_display_control_named(&display_control_name),
+    ));
+    game.tick();
+    game.tick();
+}
+
+#[when("the settings are saved,")]
+fn save_settings(game: &mut Game) {
+    game.write_message(SaveSettings::new());
+}
+
+#[when("the game is started again,")]
+fn restart_game(game: &mut Game) {
+    *game = Game::new();
+    game.add_plugin(CoreSettingsPlugin::new(get_settings_file_path()));
+    game.add_plugin(DisplayPlugin);
+    game.tick();
+}
+
+#[then(regex = r"the window is (windowed|borderless|fullscreen)[,.]$")]
+fn verify_window_mode(game: &mut Game, expected_mode: String) {
+    let window_mode = game.get_mut::<Window>().mode;
+
+    let is_expected_mode = match expected_mode.as_str() {
+        "windowed" => window_mode == WindowMode::Windowed,
+        "borderless" => matches!(window_mode, WindowMode::BorderlessFullscreen(_)),
+        _ => matches!(window_mode, WindowMode::Fullscreen(_, _)),
+    };
+
+    assert!(
+        is_expected_mode,
+        "Expected the window to be {}, but found {:?}",
+        expected_mode, window_mode
+    );
+}
+
+#[then(regex = r"the window (has|does not have) vsync[,.]$")]
+fn verify_vsync(game: &mut Game, has_vsync: String) {
+    let present_mode = game.get_mut::<Window>().present_mode;
+
+    let expected_present_mode = if has_vsync == "has" {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+
+    assert_eq!(expected_present_mode, present_mode);
+}
+
+#[then(regex = r"the window is ([0-9]+) x ([0-9]+)[,.]$")]
+fn verify_resolution(game: &mut Game, width: u32, height: u32) {
+    let window_resolution = get_window_resolution(&game.get_mut::<Window>());
+
+    assert_eq!(Resolution::new(width, height), window_resolution);
+}
+
+#[then(regex = r"the UI is scaled by ([0-9.]+)[,.]$")]
+fn verify_ui_scale(game: &mut Game, expected_ui_scale: f32) {
+    assert_eq!(expected_ui_scale, game.get_res::<UiScale>().0);
+}
+
+#[then(regex = r"each pixel of the tile art covers ([0-9.]+) screen pixels?[,.]$")]
+fn verify_screen_pixels_per_art_pixel(game: &mut Game, expected_screen_pixels: f32) {
+    let scale_factor = game.get_mut::<Window>().scale_factor();
+
+    let camera_scale = match *game.get_mut::<Projection>() {
+        Projection::Orthographic(ref orthographic) => orthographic.scale,
+        _ => panic!("verify_screen_pixels_per_art_pixel: The camera isn't orthographic."),
+    };
+
+    assert!(
+        (scale_factor / camera_scale - expected_screen_pixels).abs() < 0.001,
+        "Expected {} screen pixels, but found {}",
+        expected_screen_pixels,
+        scale_factor / camera_scale
+    );
+}
+
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/display_settings.feature",
+    ));
+}
@@ -0,0 +1,126 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use helping_hand::{
+    audio::{
+        buses::{AudioBus, AudioBuses},
+        ducking::DuckingSettings,
+        voice::VoicePlayback,
+    },
+    narrative::acts::{Act, Scene, SceneContents, SceneType},
+    plugins::{levels::CoreLevelsPlugin, music::CoreMusicPlugin},
+    ui::text_box::TextBox,
+};
+use std::{path::PathBuf, time::Duration};
+
+const TIME_STEP: Duration = Duration::from_millis(5);
+
+/// Returns a scene of some type, with nothing in particular in it.
+fn create_scene(title: &str, scene_type: SceneType) -> Scene {
+    let scene_contents = match scene_type {
+        SceneType::ImageCutscene => SceneContents::ImageCutscene(PathBuf::from("PI1.png")),
+        SceneType::MapCutscene => {
+            SceneContents::MapCutscene(PathBuf::from("test_map.tmx"), Vec::new())
+        }
+    };
+
+    Scene::make_scene(String::from(title), scene_type, scene_contents)
+}
+
+fn get_music_volume(game: &mut Game) -> f32 {
+    game.get_res::<AudioBuses>()
+        .get_effective_volume(AudioBus::Music)
+}
+
+#[given(regex = r"the music is ducked by ([0-9.]+) over ([0-9.]+) seconds?,")]
+fn given_ducking_settings(game: &mut Game, amount: f32, ramp_seconds: f32) {
+    let ducking_settings = DuckingSettings::new(amount, Duration::from_secs_f32(ramp_seconds));
+
+    game.set_time_step(TIME_STEP);
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(CoreMusicPlugin::new(Duration::from_secs(0)).with_ducking(ducking_settings));
+}
+
+#[given("an act starting with an image cutscene and then a map cutscene,")]
+fn given_image_then_map_act(game: &mut Game) {
+    let mut act = Act::new();
+    act.add_scene(create_scene("Intro", SceneType::ImageCutscene));
+    act.add_scene(create_scene("Village", SceneType::MapCutscene));
+
+    game.spawn(act);
+}
+
+#[given("an act with only a map cutscene,")]
+fn given_map_act(game: &mut Game) {
+    let mut act = Act::new();
+    act.add_scene(create_scene("Village", SceneType::MapCutscene));
+
+    game.spawn(act);
+}
+
+#[when("the act moves on to the next scene,")]
+fn move_to_next_scene(game: &mut Game) {
+    game.get_mut::<Act>().move_to_next_scene();
+}
+
+#[when("some dialogue is shown,")]
+fn show_dialogue(game: &mut Game) {
+    game.spawn(TextBox);
+}
+
+#[when("a voice line starts playing,")]
+fn start_voice_line(game: &mut Game) {
+    *game.get_res_mut::<VoicePlayback>() = VoicePlayback::new(true);
+}
+
+#[when("the voice line finishes,")]
+fn finish_voice_line(game: &mut Game) {
+    *game.get_res_mut::<VoicePlayback>() = VoicePlayback::new(false);
+}
+
+#[when("a moment passes,")]
+fn wait_a_moment(game: &mut Game) {
+    for _ in 0..4 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the music settles at a volume of ([0-9.]+),")]
+#[then(regex = r"the music settles at a volume of ([0-9.]+)[,.]$")]
+fn verify_settled_music_volume(game: &mut Game, expected_volume: f32) {
+    let has_settled = game.tick_until(255, |game| {
+        (get_music_volume(game) - expected_volume).abs() < 0.001
+    });
+
+    assert!(
+        has_settled,
+        "Expected the music to settle at a volume of {}, but it was at {}",
+        expected_volume,
+        get_music_volume(game)
+    );
+}
+
+#[then(regex = r"the music is on its way from ([0-9.]+) to ([0-9.]+)[,.]$")]
+fn verify_ramping_music_volume(game: &mut Game, from_volume: f32, to_volume: f32) {
+    let music_volume = get_music_volume(game);
+
+    let (lowest_volume, highest_volume) = if from_volume < to_volume {
+        (from_volume, to_volume)
+    } else {
+        (to_volume, from_volume)
+    };
+
+    assert!(
+        lowest_volume < music_volume && music_volume < highest_volume,
+        "Expected the music to be between {} and {}, but it was at {}",
+        lowest_volume,
+        highest_volume,
+        music_volume
+    );
+}
+
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/ducking.feature"));
+}
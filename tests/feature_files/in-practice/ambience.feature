@@ -0,0 +1,26 @@
+Feature: Ambient sounds placed on the map get louder the closer the player is to them.
+    Scenario: Ambient sounds are placed from the map.
+        Given a Tiled map called ambient_sounds.tmx,
+        Then there are 2 ambient sounds on the map.
+
+    Scenario: Ambient sounds are quieter the further away the player is.
+        Given a Tiled map called ambient_sounds.tmx,
+        And the Player is at 1,1,
+        Then audio/ambience/wind.wav is heard at a volume of 0.25, panned to -0.5.
+
+    Scenario: Ambient sounds out of their radius can't be heard.
+        Given a Tiled map called ambient_sounds.tmx,
+        And the Player is at 1,1,
+        Then audio/ambience/stream.wav is heard at a volume of 0, panned to 1.
+
+    Scenario: Ambient sounds follow the player as they move.
+        Given a Tiled map called ambient_sounds.tmx,
+        And the Player is at 1,1,
+        When the Player moves right,
+        Then audio/ambience/stream.wav is heard at a volume of 0.2, panned to 0.75,
+        And audio/ambience/wind.wav is heard at a volume of 0, panned to -1.
+
+    Scenario: Ambient sounds are removed along with their level.
+        Given a Tiled map called ambient_sounds.tmx,
+        When the level changes to quiet_map.tmx,
+        Then there are 0 ambient sounds on the map.
@@ -0,0 +1,44 @@
+Feature: Audio is played through buses which are turned up, down or muted together.
+    Scenario: Buses are at full volume until they're set.
+        Given settings that were never saved,
+        Then the Music bus plays at a volume of 1.
+
+    Scenario: Buses play at their own volume scaled by the master volume.
+        Given settings that were never saved,
+        And the Master volume is set to 0.5,
+        And the Music volume is set to 0.8,
+        Then the Music bus plays at a volume of 0.4,
+        And the SFX bus plays at a volume of 0.5.
+
+    Scenario: Muting a bus silences only that bus.
+        Given settings that were never saved,
+        When the SFX bus is muted,
+        Then the SFX bus plays at a volume of 0,
+        And the Ambient bus plays at a volume of 1.
+
+    Scenario: Muting the master bus silences every bus.
+        Given settings that were never saved,
+        When the Master bus is muted,
+        Then the Voice bus plays at a volume of 0,
+        And the Voice bus is not muted.
+
+    Scenario: Clicking the mute button of a bus mutes it.
+        Given settings that were never saved,
+        When the mute button of the Voice bus is clicked,
+        Then the Voice bus is muted.
+
+    Scenario: Clicking the mute button of a muted bus unmutes it.
+        Given settings that were never saved,
+        And the Ambient bus is muted,
+        When the mute button of the Ambient bus is clicked,
+        Then the Ambient bus is not muted.
+
+    Scenario: Bus volumes and mutes are kept between plays once saved.
+        Given settings that are saved between plays,
+        And the Music volume is set to 0.3,
+        And the Ambient bus is muted,
+        When the settings are saved,
+        And the game is started again,
+        Then the Music volume is set to 0.3,
+        And the Ambient bus is muted,
+        And the SFX bus is not muted.
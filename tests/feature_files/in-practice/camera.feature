@@ -111,3 +111,128 @@ Feature: Camera
         When the player moves down,
         Then the player's center x and y positions should be 736px, 416px.
         And the camera's x and y positions should be 736px, 448px.
+
+    ##################################################################################
+    #                          Tests for a zoomed in camera                          #
+    ##################################################################################
+
+    Scenario: When zoomed in, the camera can follow the player closer to the edge of the map.
+        Given a custom game resolution of 1280 x 720,
+        And a camera zoom of 2,
+        And a Tiled map called follow_player_test.tmx,
+        When the player moves left,
+        And the player moves left,
+        And the player moves left,
+        Then the camera's position and player tile's center position are both 544px, 480px.
+
+    Scenario: When zoomed in, the camera follows the player across a map that was narrower than it.
+        Given a custom game resolution of 2560 x 720,
+        And a camera zoom of 2,
+        And a Tiled map called follow_player_test.tmx,
+        When the player moves left,
+        Then the camera's position and player tile's center position are both 672px, 480px.
+
+    Scenario: When zoomed in, the camera follows the player right across a map that was narrower than it.
+        Given a custom game resolution of 2560 x 720,
+        And a camera zoom of 2,
+        And a Tiled map called follow_player_test.tmx,
+        When the player moves right,
+        Then the camera's position and player tile's center position are both 800px, 480px.
+
+    Scenario: When zoomed in, the camera follows the player up a map that was shorter than it.
+        Given a custom game resolution of 1280 x 1440,
+        And a camera zoom of 3,
+        And a Tiled map called follow_player_test.tmx,
+        When the player moves up,
+        Then the camera's position and player tile's center position are both 736px, 544px.
+
+    Scenario: When zoomed back out, the camera is kept within the edges of the map again.
+        Given a custom game resolution of 1280 x 720,
+        And a camera zoom of 2,
+        And a Tiled map called follow_player_test.tmx,
+        When the player moves left,
+        And the player moves left,
+        And the player moves left,
+        And the camera zoom is set to 1,
+        Then the player's center x and y positions should be 544px, 480px.
+        And the camera's x and y positions should be 608px, 480px.
+
+    Scenario: The zoom in key zooms the camera in a step.
+        Given a custom game resolution of 1280 x 720,
+        And a camera zoom of 1,
+        And a Tiled map called follow_player_test.tmx,
+        When the zoom in key is pressed,
+        And the player moves left,
+        And the player moves left,
+        And the player moves left,
+        Then the camera's position and player tile's center position are both 544px, 480px.
+
+    ##################################################################################
+    #                        Tests for the camera controller                         #
+    ##################################################################################
+
+    Scenario: A smoothed camera catches up with the player rather than snapping onto them.
+        Given a custom game resolution of 1280 x 720,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera is set to smooth its movement,
+        And the player moves right,
+        Then the camera is on its way from 736px to 800px.
+        And the camera settles at 800px, 480px.
+
+    Scenario: The camera doesn't follow the player while they're within its dead zone.
+        Given a custom game resolution of 640 x 360,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera is given a dead zone of 128px by 128px,
+        And the player moves right,
+        Then the camera settles at 736px, 480px.
+
+    Scenario: The camera follows the player once they leave its dead zone.
+        Given a custom game resolution of 640 x 360,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera is given a dead zone of 128px by 128px,
+        And the player moves right,
+        And the player moves right,
+        Then the camera settles at 800px, 480px.
+
+    Scenario: Levels are loaded with the camera following the player the way the game sets it to.
+        Given a custom game resolution of 640 x 360,
+        And a Tiled map called follow_player_test.tmx,
+        And each level's camera is given a dead zone of 128px by 128px,
+        When the map is spawned,
+        And the player moves right,
+        Then the camera settles at 736px, 480px.
+
+    Scenario: A shaken camera is thrown around, then settles back where it was.
+        Given a custom game resolution of 1280 x 720,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera shakes by 16px for 0.2 seconds,
+        Then the camera is shaken away from 736px, 480px by no more than 16px.
+        And the camera settles at 736px, 480px.
+
+    Scenario: The camera can pan over to a tile, then go back to following the player.
+        Given a custom game resolution of 640 x 360,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera pans to tile 15, 3 over 0.1 seconds, holding for 0.5 seconds,
+        Then the camera settles at 960px, 704px.
+        And the camera settles at 736px, 480px.
+        And the camera goes back to following the player.
+
+    Scenario: The camera can pan over to a character on the map.
+        Given a custom game resolution of 640 x 360,
+        And a Tiled map called camera_pan_test.tmx,
+        When the map is spawned,
+        And the camera pans to Iye over 0.1 seconds, holding for 0.5 seconds,
+        Then the camera settles at 352px, 672px.
+        And the camera settles at 736px, 480px.
+
+    Scenario: A pan is kept within the edges of the map.
+        Given a custom game resolution of 1280 x 720,
+        And a Tiled map called follow_player_test.tmx,
+        When the map is spawned,
+        And the camera pans to tile 0, 0 over 0.1 seconds, holding for 0.5 seconds,
+        Then the camera settles at 608px, 568px.
@@ -0,0 +1,37 @@
+Feature: Click to Move
+    Scenario: A clicked position is found on the map.
+        Given a Tiled map called click_to_move_test.tmx,
+        Then the world position 100,20 is on tile 2,2.
+        And the world position -40,0 is off the map.
+
+    Scenario: A Player walks to a clicked tile.
+        Given a Tiled map called click_to_move_test.tmx,
+        And the Player is at 0,0,1,
+        When the Player clicks on tile 0,2,
+        Then the Player's grid coordinates are set to tile 0,2,1.
+
+    Scenario: A Player walks around collision to a clicked tile.
+        Given a Tiled map called click_to_move_test.tmx,
+        And the Player is at 0,0,1,
+        When the Player clicks on tile 2,1,
+        Then the Player's grid coordinates are set to tile 2,1,1.
+
+    Scenario: A Player does not walk to a clicked collision tile.
+        Given a Tiled map called click_to_move_test.tmx,
+        And the Player is at 0,0,1,
+        When the Player clicks on tile 1,1,
+        Then the Player's grid coordinates are set to tile 0,0,1.
+
+    Scenario: A Player stops walking when a direction key is pressed.
+        Given a Tiled map called click_to_move_test.tmx,
+        And the Player is at 0,0,1,
+        When the Player starts walking to tile 0,2,
+        And the Player presses right,
+        Then the Player is not following a path.
+        And the Player's grid coordinates are not set to tile 0,2,1.
+
+    Scenario: A Player walks up to a clicked marker and interacts with it.
+        Given a Tiled map called click_to_move_test.tmx,
+        And the Player is at 0,0,1,
+        When the Player clicks on tile 2,2,
+        Then the map size should be 16 x 11 tiles.
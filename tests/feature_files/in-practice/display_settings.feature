@@ -0,0 +1,69 @@
+Feature: The display settings are applied to the window as soon as they change, and kept between plays.
+    Scenario: The window is left windowed with vsync until the display settings are changed.
+        Given display settings that were never saved,
+        Then the window is windowed,
+        And the window has vsync.
+
+    Scenario: Choosing borderless makes the window cover the screen.
+        Given display settings that were never saved,
+        When the window mode is set to Borderless,
+        Then the window is borderless.
+
+    Scenario: Choosing fullscreen takes over the monitor.
+        Given display settings that were never saved,
+        When the window mode is set to Fullscreen,
+        Then the window is fullscreen.
+
+    Scenario: Choosing a resolution resizes the window.
+        Given display settings that were never saved,
+        When the resolution is set to 1600 x 900,
+        Then the window is 1600 x 900.
+
+    Scenario: Turning vsync off applies to the window.
+        Given display settings that were never saved,
+        When the vsync toggle is switched off,
+        Then the window does not have vsync.
+
+    Scenario: The UI scale is applied to the UI.
+        Given display settings that were never saved,
+        When the UI scale is set to 1.5,
+        Then the UI is scaled by 1.5.
+
+    Scenario: Without pixel-perfect scaling, the tile art follows the screen's own scaling.
+        Given display settings that were never saved,
+        And a screen scaled by 2.5,
+        And a camera,
+        Then each pixel of the tile art covers 2.5 screen pixels.
+
+    Scenario: Pixel-perfect scaling keeps the tile art at a whole number of screen pixels.
+        Given display settings that were never saved,
+        And a screen scaled by 2.5,
+        And the resolution is set to 3200 x 1800,
+        And a camera,
+        When the pixel-perfect toggle is switched on,
+        Then each pixel of the tile art covers 2 screen pixels.
+
+    Scenario: Pixel-perfect scaling scales the tile art up as far as the window fits it.
+        Given display settings that were never saved,
+        And a camera,
+        When the resolution is set to 2560 x 1440,
+        And the pixel-perfect toggle is switched on,
+        Then each pixel of the tile art covers 2 screen pixels.
+
+    Scenario: Pixel-perfect scaling never scales the tile art down past its own size.
+        Given display settings that were never saved,
+        And a camera,
+        When the resolution is set to 800 x 600,
+        And the pixel-perfect toggle is switched on,
+        Then each pixel of the tile art covers 1 screen pixel.
+
+    Scenario: Display settings are kept between plays once saved.
+        Given display settings that are saved between plays,
+        And the window mode is set to Borderless,
+        And the resolution is set to 1600 x 900,
+        And the UI scale is set to 1.5,
+        When the settings are saved,
+        And the game is started again,
+        Then the window is borderless,
+        And the window is 1600 x 900,
+        And the UI is scaled by 1.5.
@@ -0,0 +1,44 @@
+Feature: The music is turned down while cutscenes and dialogue play over it.
+    Scenario: The music is ducked during image cutscenes.
+        Given the music is ducked by 0.5 over 0.1 seconds,
+        And an act starting with an image cutscene and then a map cutscene,
+        Then the music settles at a volume of 0.5.
+
+    Scenario: The music isn't ducked during map cutscenes.
+        Given the music is ducked by 0.5 over 0.1 seconds,
+        And an act with only a map cutscene,
+        When a moment passes,
+        Then the music settles at a volume of 1.
+
+    Scenario: The music is ducked while dialogue is shown.
+        Given the music is ducked by 0.4 over 0.1 seconds,
+        And an act with only a map cutscene,
+        When some dialogue is shown,
+        Then the music settles at a volume of 0.6.
+
+    Scenario: The music is ducked while a voice line plays.
+        Given the music is ducked by 0.5 over 0.1 seconds,
+        And an act with only a map cutscene,
+        When a voice line starts playing,
+        Then the music settles at a volume of 0.5.
+
+    Scenario: The music comes back up once the voice line ends.
+        Given the music is ducked by 0.5 over 0.1 seconds,
+        And an act with only a map cutscene,
+        When a voice line starts playing,
+        And the music settles at a volume of 0.5,
+        And the voice line finishes,
+        Then the music settles at a volume of 1.
+
+    Scenario: The music comes back up once the ducked scene ends.
+        Given the music is ducked by 0.5 over 0.1 seconds,
+        And an act starting with an image cutscene and then a map cutscene,
+        When the music settles at a volume of 0.5,
+        And the act moves on to the next scene,
+        Then the music settles at a volume of 1.
+
+    Scenario: The music is ducked smoothly instead of all at once.
+        Given the music is ducked by 0.5 over 60 seconds,
+        And an act starting with an image cutscene and then a map cutscene,
+        When a moment passes,
+        Then the music is on its way from 1 to 0.5.
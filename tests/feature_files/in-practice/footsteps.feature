@@ -0,0 +1,36 @@
+Feature: Footsteps sound like the ground the player walks on.
+    Scenario: Footsteps use the material of the tile's layer.
+        Given the footstep sounds in tests/test_assets/audio/footsteps.json,
+        And a Tiled map called material_test.tmx,
+        And the Player is at 1,1,
+        When the Player moves left,
+        Then the footstep played is audio/sfx/stone_step.wav.
+
+    Scenario: Tiles on higher layers cover up the materials below them.
+        Given the footstep sounds in tests/test_assets/audio/footsteps.json,
+        And a Tiled map called material_test.tmx,
+        And the Player is at 1,1,
+        When the Player moves right,
+        Then the footstep played is audio/sfx/wood_step.wav.
+
+    Scenario: A tile's own material wins over the material of its layer.
+        Given the footstep sounds in tests/test_assets/audio/footsteps.json,
+        And a Tiled map called material_test.tmx,
+        And the Player is at 1,1,
+        When the Player moves left,
+        And the Player moves right,
+        Then the footstep played is audio/sfx/grass_step.wav.
+
+    Scenario: Tiles without a material use the default footstep.
+        Given the footstep sounds in tests/test_assets/audio/footsteps.json,
+        And a Tiled map called no_material_test.tmx,
+        And the Player is at 1,1,
+        When the Player moves left,
+        Then the footstep played is audio/sfx/default_step.wav.
+
+    Scenario: Every footstep is pitched a little differently.
+        Given the footstep sounds in tests/test_assets/audio/footsteps.json,
+        And a Tiled map called material_test.tmx,
+        And the Player is at 1,1,
+        When the Player moves left,
+        Then the footstep is pitched close to the sound itself.
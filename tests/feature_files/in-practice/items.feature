@@ -0,0 +1,61 @@
+Feature: Items
+    Scenario: A player picks up an item
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        And there are 2 items on the map,
+        When the player interacts with the tile ahead of them,
+        Then the Player carries 3 Acorn,
+        And there is 1 item on the map.
+
+    Scenario: Picking up more of the same item stacks them
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        When the player interacts with the tile ahead of them,
+        And the Player moves up,
+        And the player interacts with the tile ahead of them,
+        Then the Player carries 5 Acorn,
+        And there are 0 items on the map.
+
+    Scenario: Items are in the way of the player
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        When the Player moves left,
+        Then the Player should be at 2,1.
+
+    Scenario: Picked up items don't come back
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        When the player interacts with the tile ahead of them,
+        And the level is entered again,
+        Then there is 1 item on the map,
+        And the Player carries 3 Acorn.
+
+    Scenario: Only one item is picked up from a spot at a time
+        Given a Tiled map called item_stack_test.tmx,
+        And the Player is at 2,1,
+        And there are 2 items on the map,
+        When the player interacts with the tile ahead of them,
+        Then the Player carries 3 Acorn,
+        And there is 1 item on the map.
+
+    Scenario: Items sharing a spot are each remembered as picked up
+        Given a Tiled map called item_stack_test.tmx,
+        And the Player is at 2,1,
+        When the player interacts with the tile ahead of them,
+        And the level is entered again,
+        Then there is 1 item on the map,
+        And the Player carries 3 Acorn.
+
+    Scenario: The inventory shows the items the player carries
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        When the player interacts with the tile ahead of them,
+        And the player presses the inventory key,
+        Then the inventory shows 'Acorn x3'.
+
+    Scenario: The inventory closes again
+        Given a Tiled map called item_test.tmx,
+        And the Player is at 2,1,
+        When the player presses the inventory key,
+        And the player presses the inventory key,
+        Then the inventory is closed.
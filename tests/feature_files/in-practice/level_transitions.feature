@@ -0,0 +1,59 @@
+Feature: Level Transitions
+    Scenario: A transition marker without an effect cuts to the next level
+        Given a Tiled map called transition_test.tmx from the map changing tests,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        Then there is no level transition running,
+        And the map size should be 16 x 11 tiles.
+
+    Scenario: A fading transition covers the screen before changing the level
+        Given a Tiled map called fade_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        Then a Fade level transition is running,
+        And the map size should be 3 x 3 tiles.
+
+    Scenario: A fading transition changes the level once it's done
+        Given a Tiled map called fade_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the level transition finishes,
+        Then there is no level transition running,
+        And the map size should be 16 x 11 tiles,
+        And the Player should be at 7,9.
+
+    Scenario: A fading transition into a level that isn't loaded yet shows the loading screen over it
+        Given levels are loaded behind a loading screen,
+        And a Tiled map called fade_test.tmx,
+        And everything has finished loading,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the level transition covers the screen,
+        Then the loading screen is drawn over the level transition,
+        And the map size should be 3 x 3 tiles.
+
+    Scenario: A fading transition into a level that isn't loaded yet finishes once it has loaded
+        Given levels are loaded behind a loading screen,
+        And a Tiled map called fade_test.tmx,
+        And everything has finished loading,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the level transition covers the screen,
+        And everything has finished loading,
+        And the level transition finishes,
+        Then there is no level transition running,
+        And the map size should be 16 x 11 tiles,
+        And the Player should be at 7,9.
+
+    Scenario: A wiping transition goes in the direction set by its marker
+        Given a Tiled map called wipe_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        Then a WipeLeft level transition is running.
+
+    Scenario: The Player can't walk around while a level transition runs
+        Given a Tiled map called fade_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the player presses the key to walk down,
+        Then the Player should be at 2,2.
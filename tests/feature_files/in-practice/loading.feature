@@ -0,0 +1,44 @@
+Feature: Loading Screen
+    Scenario: Loading an act shows the loading screen
+        Given the game is capable of loading acts,
+        When the act called 'image_cutscene_act.json' starts loading,
+        Then the game is loading,
+        And the loading screen is shown.
+
+    Scenario: The images of an image cutscene act are loaded
+        Given the game is capable of loading acts,
+        When the act called 'image_cutscene_act.json' starts loading,
+        Then the image 'acts/images/PI1.png' is needed,
+        And the image 'acts/images/PI2.png' is needed.
+
+    Scenario: The tilesets and music of a map cutscene act are loaded
+        Given the game is capable of loading acts,
+        When the act called 'map_cutscene_act.json' starts loading,
+        Then the image 'textures/environments/!CL_DEMO_64.png' is needed,
+        And the image 'textures/characters/duck-Sheet.png' is needed,
+        And the music 'audio/music/path_line.wav' is needed.
+
+    Scenario: Each asset is only loaded once
+        Given the game is capable of loading acts,
+        When the act called 'map_cutscene_act.json' starts loading,
+        Then the image 'textures/environments/!CL_DEMO_64.png' is needed once.
+
+    Scenario: The scene is entered once everything has loaded
+        Given the game is capable of loading acts,
+        When the act called 'image_cutscene_act.json' starts loading,
+        And everything has finished loading,
+        Then the game is in the scene,
+        And the loading screen is closed.
+
+    Scenario: Changing levels waits on the loading screen
+        Given the game is capable of loading levels,
+        When the level 'path_line.tmx' is changed to,
+        Then the game is loading,
+        And the level is not built yet.
+
+    Scenario: A level changed to is built once its assets have loaded
+        Given the game is capable of loading levels,
+        When the level 'path_line.tmx' is changed to,
+        And everything has finished loading,
+        Then the game is in the scene,
+        And the level is built.
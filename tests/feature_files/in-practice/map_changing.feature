@@ -14,3 +14,26 @@ Feature: Map Changing
         When the player interacts with the tile ahead of them,
         Then the map size should be 3 x 3 tiles,
         And the Player should be at 2,1.
+
+    Scenario: A player reads a sign instead of transitioning
+        Given a Tiled map called sign_test.tmx,
+        And a map size of 3 x 3 tiles,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        Then the map size should be 3 x 3 tiles,
+        And a text box reads 'Keep out of the cabin.'.
+
+    Scenario: A player closes the text box of a sign
+        Given a Tiled map called sign_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the player presses the interact key,
+        Then there is no text box.
+
+    Scenario: A player steps on a trigger that changes the map
+        Given a Tiled map called trigger_test.tmx,
+        And a map size of 3 x 3 tiles,
+        And the Player is at 2,2,
+        When the Player moves left,
+        Then the map size should be 16 x 11 tiles,
+        And the Player should be at 7,9.
@@ -0,0 +1,58 @@
+Feature: Music is chosen by the level and act scene being played.
+    Scenario: Levels play the track set by their Music property.
+        Given the game can play music,
+        When the level overworld_a.tmx is loaded,
+        Then the track audio/music/Level_1_overworld.wav is playing.
+        And the music changed tracks.
+
+    Scenario: Levels without a Music property play the track named after their file.
+        Given the game can play music,
+        When the level test_map_with_collision.tmx is loaded,
+        Then the track audio/music/test_map_with_collision.wav is playing.
+
+    Scenario: Levels without any music found play nothing.
+        Given the game can play music,
+        When the level silent_map.tmx is loaded,
+        Then no music is playing.
+
+    Scenario: Music set to a track that doesn't exist isn't played.
+        Given the game can play music,
+        When the level missing_music.tmx is loaded,
+        Then no music is playing.
+
+    Scenario: Levels sharing a track keep it playing.
+        Given the game can play music,
+        When the level overworld_a.tmx is loaded,
+        And the level overworld_b.tmx is loaded,
+        Then the track audio/music/Level_1_overworld.wav is playing.
+        And the music didn't change tracks.
+
+    Scenario: Levels with different tracks change the music.
+        Given the game can play music,
+        When the level overworld_a.tmx is loaded,
+        And the level other_overworld.tmx is loaded,
+        Then the track audio/music/Level_2_overworld.wav is playing.
+        And the music changed tracks.
+
+    Scenario: Act scenes can play their own music.
+        Given the game can play music,
+        And the game is capable of handling acts,
+        When the act called 'image_cutscene_settings_act.json' is loaded,
+        And the game transitions to the next scene,
+        Then the track audio/music/Level_2_overworld.wav is playing.
+
+    Scenario: Act scene music plays over the music of the level.
+        Given the game can play music,
+        And the game is capable of handling acts,
+        When the act called 'image_cutscene_settings_act.json' is loaded,
+        And the game transitions to the next scene,
+        And the level overworld_a.tmx is loaded,
+        Then the track audio/music/Level_2_overworld.wav is playing.
+
+    Scenario: The level's music comes back once act scenes stop playing their own.
+        Given the game can play music,
+        And the game is capable of handling acts,
+        When the level overworld_a.tmx is loaded,
+        And the act called 'scene_music_act.json' is loaded,
+        And the game transitions to the next scene,
+        Then the track audio/music/Level_1_overworld.wav is playing.
@@ -0,0 +1,42 @@
+Feature: Non-playable Characters
+    Scenario: A character is spawned from the Tiled map.
+        Given a Tiled map called npc_test.tmx,
+        Then there is 1 character on the map.
+        And the character 'Iye' is at 2,1,1.
+        And the character 'Iye' is facing up.
+
+    Scenario: A character with properties that aren't recognized still spawns, facing down and standing still.
+        Given a Tiled map called npc_unknown_properties.tmx,
+        Then there is 1 character on the map.
+        And the character 'Iye' is at 2,1,1.
+        And the character 'Iye' is facing down.
+
+    Scenario: A Player cannot walk through a character.
+        Given a Tiled map called npc_test.tmx,
+        And the Player is at 1,1,1,
+        When the Player moves right,
+        Then the Player's grid coordinates are set to tile 1,1,1.
+        And the Player is facing right.
+
+    Scenario: A character turns to face the Player when talked to.
+        Given a Tiled map called npc_test.tmx,
+        And the Player is at 1,1,1,
+        When the Player moves right,
+        And the Player interacts with the tile ahead of them,
+        Then the character 'Iye' is facing left.
+
+    Scenario: A character patrols back and forth along a path.
+        Given a Tiled map called npc_patrol.tmx,
+        Then the character 'Linsey' is at 0,2,1.
+        And the character 'Linsey' eventually reaches 2,2,1.
+        And the character 'Linsey' is facing right.
+        And the character 'Linsey' eventually reaches 0,2,1.
+        And the character 'Linsey' is facing left.
+
+    Scenario: A character turns to watch the Player.
+        Given a Tiled map called npc_face_player.tmx,
+        And the Player is at 1,1,1,
+        When the Player moves left,
+        Then the character 'Oma' is facing down.
+        When the Player moves right,
+        Then the character 'Oma' is facing right.
@@ -0,0 +1,68 @@
+Feature: Pause Menu
+    Scenario: Pressing Escape pauses the game
+        Given a Tiled map called pause_test.tmx,
+        When the player presses Escape,
+        Then the game is paused,
+        And the pause menu is shown,
+        And time stands still.
+
+    Scenario: Pressing Escape again resumes the game
+        Given a Tiled map called pause_test.tmx,
+        When the player presses Escape,
+        And the player presses Escape,
+        Then the game is running,
+        And the pause menu is closed,
+        And time passes.
+
+    Scenario: The Player can't walk around while the game is paused
+        Given a Tiled map called pause_test.tmx,
+        And the Player is at 1,1,
+        When the player presses Escape,
+        And the player holds the key to walk right,
+        Then the Player is at 1,1.
+
+    Scenario: Backing out of the settings menu returns to the pause menu
+        Given a Tiled map called pause_test.tmx,
+        When the player presses Escape,
+        And the settings menu is opened from the pause menu,
+        And the player presses Escape,
+        Then the game is paused,
+        And time stands still.
+
+    Scenario: Time stays frozen in the settings menu opened from the pause menu
+        Given a Tiled map called pause_test.tmx,
+        When the player presses Escape,
+        And the settings menu is opened from the pause menu,
+        Then time stands still.
+
+    Scenario: Time stays frozen while the inventory is open
+        Given a Tiled map called pause_test.tmx,
+        When the inventory is opened,
+        Then time stands still.
+
+    Scenario: Time stays frozen while the quest log is open
+        Given a Tiled map called pause_test.tmx,
+        When the quest log is opened,
+        Then time stands still.
+
+    Scenario: Saving writes the current level and story flags to the save file
+        Given a Tiled map called pause_test.tmx,
+        And the story flag 'met_oma' is set,
+        When the game is saved,
+        Then the save file has the level pause_test.tmx,
+        And the save file has the story flag 'met_oma'.
+
+    Scenario: Saving writes where the Player is standing and tells the player it's saved
+        Given a Tiled map called pause_test.tmx,
+        And the Player is at 1,1,
+        When the player presses Escape,
+        And the game is saved,
+        Then the save file has the Player at 1,1,
+        And the pause menu reads 'Game saved.'.
+
+    Scenario: Quitting to the title screen unloads the level
+        Given a Tiled map called pause_test.tmx,
+        When the player presses Escape,
+        And the player quits to the title screen,
+        Then the level is unloaded,
+        And time passes.
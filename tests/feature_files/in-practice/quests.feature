@@ -0,0 +1,54 @@
+Feature: Quests
+    Scenario: Talking to a character completes an objective
+        Given a Tiled map called quest_test.tmx,
+        And the Player is at 2,2,
+        And the quest 'help_oma' has 0 of 2 objectives completed,
+        When the player interacts with the tile ahead of them,
+        Then the quest 'help_oma' has 1 of 2 objectives completed,
+        And a toast reads 'Objective complete: Talk to Oma'.
+
+    Scenario: Picking up items makes progress on an objective
+        Given a Tiled map called quest_test.tmx,
+        And the Player is at 2,2,
+        When the Player moves up,
+        And the player interacts with the tile ahead of them,
+        Then objective 2 of the quest 'help_oma' has a progress of 2,
+        And the quest 'help_oma' has 0 of 2 objectives completed.
+
+    Scenario: Completing every objective completes the quest
+        Given a Tiled map called quest_test.tmx,
+        And the Player is at 2,2,
+        When the Player moves up,
+        And the player interacts with the tile ahead of them,
+        Then the quest 'first_acorns' is completed,
+        And a toast reads 'Quest complete: First Acorns'.
+
+    Scenario: Loading into a map completes an objective
+        Given a Tiled map called quest_test.tmx,
+        When the level has loaded,
+        Then the quest 'explore' has 1 of 2 objectives completed.
+
+    Scenario: Entering a map completes an objective
+        Given a Tiled map called quest_test.tmx,
+        When the level is entered again,
+        Then the quest 'explore' has 1 of 2 objectives completed.
+
+    Scenario: Reaching an act scene completes an objective
+        Given a Tiled map called quest_test.tmx,
+        When the level is entered again,
+        And the scene 'Climbing Tree' is reached,
+        Then the quest 'explore' is completed.
+
+    Scenario: The quest log shows the progress of each objective
+        Given a Tiled map called quest_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        And the player presses the quest log key,
+        Then the quest log shows '[x] Talk to Oma',
+        And the quest log shows '[ ] Gather acorns 0/3'.
+
+    Scenario: The quest log closes again
+        Given a Tiled map called quest_test.tmx,
+        When the player presses the quest log key,
+        And the player presses the quest log key,
+        Then the quest log is closed.
@@ -28,6 +28,52 @@ Feature: An Act's Scenes can be traversed in the game.
         And the image at 'acts/images/PI2.png' is displayed on the screen.
         And the loaded image's opacity is 100%.
 
+    Scenario: Image cutscenes are layered with the images their layout declares.
+        Given the game is capable of handling acts,
+        When the act called 'layered/image_cutscene_act.json' is loaded,
+        Then the image is layered with 2 images.
+
+    Scenario: Image cutscenes pan and zoom along their layout.
+        Given the game is capable of handling acts,
+        When the act called 'layered/image_cutscene_act.json' is loaded,
+        And 2 seconds of the scene have passed,
+        Then the image has been panned and zoomed in.
+        And the layers further back have been zoomed in less than the image.
+
+    Scenario: The player can move on from an image cutscene.
+        Given the game is capable of handling acts,
+        When the act called 'image_cutscene_act.json' is loaded,
+        And the player presses a key,
+        Then the title of the current scene loaded is called 'Intro Image 2'.
+
+    Scenario: Pressing a key mid-transition finishes the transition instead of moving on.
+        Given the game is capable of handling acts with a 10 second fade,
+        When the act called 'image_cutscene_act.json' is loaded,
+        And the player presses a key,
+        Then the title of the current scene loaded is called 'Intro Image 1'.
+        And the image has finished transitioning in.
+
+    Scenario: Image cutscenes can move on once a skipped transition has finished.
+        Given the game is capable of handling acts with a 10 second fade,
+        When the act called 'image_cutscene_act.json' is loaded,
+        And the player presses a key,
+        And the player presses a key,
+        Then the title of the current scene loaded is called 'Intro Image 2'.
+
+    Scenario: Image cutscenes can advance on their own.
+        Given the game is capable of handling acts,
+        When the act called 'image_cutscene_settings_act.json' is loaded,
+        And the act advances on its own,
+        Then the title of the current scene loaded is called 'Intro Image 2'.
+
+    Scenario: Transitions that can't be skipped ignore player input.
+        Given the game is capable of handling acts,
+        When the act called 'image_cutscene_settings_act.json' is loaded,
+        And the act advances on its own,
+        And the player presses a key,
+        Then the title of the current scene loaded is called 'Intro Image 2'.
+        And the image is still transitioning in.
+
     ##################################################################################
     #                              Map Cutscene Tests                                #
     ##################################################################################
@@ -38,6 +84,11 @@ Feature: An Act's Scenes can be traversed in the game.
         Then the title of the current scene loaded is called 'Placement Test'.
         And the map size should be 2 x 2 tiles.
 
+    Scenario: Map cutscenes pan the camera over to where their action starts.
+        Given the game is capable of handling acts,
+        When the act called 'map_cutscene_act.json' is loaded,
+        Then the camera pans away from the player.
+
     Scenario: The character and tile is found properly in a placement.
         Given the game is capable of handling acts,
         When the act called 'map_cutscene_act.json' is loaded,
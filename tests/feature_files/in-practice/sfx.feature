@@ -0,0 +1,47 @@
+Feature: Sound effects are played on what happens in the game, as written in the SFX manifest.
+    Scenario: Bumping into a wall plays its sound effect.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the Player bumps into a wall,
+        Then audio/sfx/bump.wav is played at a volume of 0.5.
+
+    Scenario: Sound effects are played at full volume unless the manifest says otherwise.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the Player interacts,
+        Then audio/sfx/interact.wav is played at a volume of 1.
+
+    Scenario: Changing levels plays its sound effect.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the level changes to tests/test_assets/maps/sfx/nowhere.tmx,
+        Then audio/sfx/door.wav is played at a volume of 0.75.
+
+    Scenario: Clicking a button plays its sound effect.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When a button is clicked,
+        Then audio/sfx/click.wav is played at a volume of 1.
+
+    Scenario: Any cue named in the manifest plays its sound effect.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the 'Splash' cue is set off,
+        Then audio/sfx/splash.wav is played at a volume of 0.25.
+
+    Scenario: Sound effects don't play again while cooling down.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the Player bumps into a wall,
+        And the Player bumps into a wall,
+        Then 1 sound effect is played.
+
+    Scenario: Sound effects without a cooldown play every time.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the Player interacts,
+        And the Player interacts,
+        Then 2 sound effects are played.
+
+    Scenario: Walking leaves the footsteps to play on their own.
+        Given the sound effects in tests/test_assets/audio/sfx.json,
+        When the Player walks,
+        Then 0 sound effects are played.
+
+    Scenario: Cues missing from the manifest are silent.
+        Given the sound effects in tests/test_assets/audio/empty_sfx.json,
+        When the Player bumps into a wall,
+        Then 0 sound effects are played.
@@ -0,0 +1,57 @@
+Feature: Story Flags
+    Scenario: A transition stays locked without its story flag
+        Given a Tiled map called flag_test.tmx,
+        And the Player is at 2,2,
+        When the player interacts with the tile ahead of them,
+        Then the map size should be 3 x 3 tiles,
+        And the story flag 'used_door' is not set.
+
+    Scenario: A transition unlocks once its story flag is set
+        Given a Tiled map called flag_test.tmx,
+        And the Player is at 2,2,
+        And the story flag 'has_key' is set,
+        When the player interacts with the tile ahead of them,
+        Then the map size should be 16 x 11 tiles,
+        And the story flag 'used_door' is set.
+
+    Scenario: Characters are swapped by story flags
+        Given a Tiled map called flag_test.tmx,
+        And the story flag 'met_oma' is set,
+        When the level is entered again,
+        Then the character 'Cheerful Oma' is on the map,
+        And the character 'Gruff Oma' is not on the map.
+
+    Scenario: Characters that need a story flag are left out without it
+        Given a Tiled map called flag_test.tmx,
+        Then the character 'Gruff Oma' is on the map,
+        And the character 'Cheerful Oma' is not on the map.
+
+    Scenario: Items stay hidden until their story flag is set
+        Given a Tiled map called flag_test.tmx,
+        And the story flag 'tree_shaken' is set,
+        Then there are 0 items on the map,
+        When the level is entered again,
+        Then there is 1 item on the map.
+
+    Scenario: Triggers and items set story flags
+        Given a Tiled map called flag_test.tmx,
+        And the story flag 'tree_shaken' is set,
+        When the level is entered again,
+        And the Player moves up,
+        And the player interacts with the tile ahead of them,
+        Then the story flag 'entered_garden' is set,
+        And the story flag 'took_acorn' is set,
+        And there are 0 items on the map.
+
+    Scenario: Story flags can be queried
+        Given a Tiled map called flag_test.tmx,
+        And the story flag 'acorns_found' is set to 3,
+        When the story flag 'acorns_found' is queried,
+        Then the story flag 'acorns_found' is reported as 3.
+
+    Scenario: Story flags survive being saved and loaded
+        Given a Tiled map called flag_test.tmx,
+        And the story flag 'has_key' is set,
+        When the story flags are saved and loaded again,
+        Then the story flag 'has_key' is set,
+        And the story flag 'met_oma' is not set.
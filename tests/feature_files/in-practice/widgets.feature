@@ -0,0 +1,95 @@
+Feature: Menu widgets can be moved between and used with the keyboard or a controller.
+    Scenario: Tab focuses the first widget.
+        Given the menu widgets,
+        And 3 toggles one after another,
+        When the Tab key is pressed,
+        Then toggle 1 is focused.
+
+    Scenario: Pressing down moves the focus on to the next widget.
+        Given the menu widgets,
+        And 3 toggles one after another,
+        When the Tab key is pressed,
+        And the Down key is pressed,
+        Then toggle 2 is focused.
+
+    Scenario: The focus wraps back around to the first widget after the last one.
+        Given the menu widgets,
+        And 3 toggles one after another,
+        When the Tab key is pressed 4 times,
+        Then toggle 1 is focused.
+
+    Scenario: Pressing up with nothing focused focuses the last widget.
+        Given the menu widgets,
+        And 3 toggles one after another,
+        When the Up key is pressed,
+        Then toggle 3 is focused.
+
+    Scenario: Hidden widgets are skipped over.
+        Given the menu widgets,
+        And 3 toggles one after another where toggle 2 is hidden,
+        When the Tab key is pressed 2 times,
+        Then toggle 3 is focused.
+
+    Scenario: Pressing enter switches the focused toggle.
+        Given the menu widgets,
+        And 1 toggle,
+        When the Tab key is pressed,
+        And the Enter key is pressed,
+        Then the toggle is on,
+        And the toggle was switched on.
+
+    Scenario: Pressing right moves the focused slider up a step.
+        Given the menu widgets,
+        And a slider at 50 from 0 to 100 in steps of 10,
+        When the Tab key is pressed,
+        And the Right key is pressed,
+        Then the slider is at 60,
+        And the slider was changed to 60.
+
+    Scenario: A slider can't be moved past its maximum.
+        Given the menu widgets,
+        And a slider at 100 from 0 to 100 in steps of 10,
+        When the Tab key is pressed,
+        And the Right key is pressed,
+        Then the slider is at 100,
+        And the slider was not changed.
+
+    Scenario: Pressing left counts the focused spinner down a step.
+        Given the menu widgets,
+        And a spinner at 5 from 0 to 10 in steps of 1,
+        When the Tab key is pressed,
+        And the Left key is pressed,
+        Then the spinner is at 4,
+        And the spinner was changed to 4.
+
+    Scenario: Pressing right moves the focused dropdown on to the next option.
+        Given the menu widgets,
+        And a dropdown of Windowed, Borderless and Fullscreen,
+        When the Tab key is pressed,
+        And the Right key is pressed,
+        Then the dropdown shows Borderless,
+        And the dropdown was changed to option 1.
+
+    Scenario: Pressing enter opens the focused dropdown.
+        Given the menu widgets,
+        And a dropdown of Windowed, Borderless and Fullscreen,
+        When the Tab key is pressed,
+        And the Enter key is pressed,
+        Then the dropdown is open.
+
+    Scenario: Pressing right on a focused tab selects the next tab and shows its page.
+        Given the menu widgets,
+        And a tab bar with the tabs Audio and Display,
+        When the Tab key is pressed,
+        And the Right key is pressed,
+        Then the Display tab is selected,
+        And only the page of the Display tab is shown,
+        And tab 2 is focused.
+
+    Scenario: Pressing enter on a focused tab selects it.
+        Given the menu widgets,
+        And a tab bar with the tabs Audio and Display,
+        When the Tab key is pressed 2 times,
+        And the Enter key is pressed,
+        Then the Display tab is selected,
+        And only the page of the Display tab is shown.
@@ -20,3 +20,44 @@ Feature: Acts
         When the act is read from the act file,
         Then the act's scene called 'Intro Image 1' is an Image Cutscene pointing to the image PI1.png.
         And the act's scene called 'Intro Image 2' is an Image Cutscene pointing to the image PI2.png.
+
+    Scenario: Scenes set story flags when entered
+        Given an act file called image_cutscene_act.json,
+        When the act is read from the act file,
+        Then the act's scene called 'Intro Image 2' sets the story flag saw_intro to true,
+        And the act's scene called 'Intro Image 2' sets the story flag intro_views to 1,
+        And the act's scene called 'Intro Image 2' sets the story flag mood to hopeful.
+
+    Scenario: Scenes without flags don't set any
+        Given an act file called image_cutscene_act.json,
+        When the act is read from the act file,
+        Then the act's scene called 'Intro Image 1' sets 0 story flags.
+
+    Scenario: Image Cutscenes crossfade by default
+        Given an act file called image_cutscene_act.json,
+        When the act is read from the act file,
+        Then the act's scene called 'Intro Image 1' transitions in with a Crossfade transition,
+        And the act's scene called 'Intro Image 1' takes the default time to transition in,
+        And the act's scene called 'Intro Image 1' waits for the player to advance,
+        And the act's scene called 'Intro Image 1' can be skipped.
+
+    Scenario: Image Cutscenes can set how they play out
+        Given an act file called image_cutscene_settings_act.json,
+        When the act is read from the act file,
+        Then the act's scene called 'Intro Image 1' transitions in with a Cut transition,
+        And the act's scene called 'Intro Image 1' advances on its own after 0.1 seconds,
+        And the act's scene called 'Intro Image 1' can't be skipped,
+        And the act's scene called 'Intro Image 2' transitions in with a Slide transition,
+        And the act's scene called 'Intro Image 2' takes 10 seconds to transition in,
+        And the act's scene called 'Intro Image 2' waits for the player to advance.
+
+    Scenario: Transition names can be written loosely
+        Then a transition written as 'Fade Through Black' is a FadeThroughBlack transition.
+        And a transition written as 'fade-through-black' is a FadeThroughBlack transition.
+        And a transition written as 'crossFade' is a Crossfade transition.
+
+    Scenario: Scenes can play their own music
+        Given an act file called image_cutscene_settings_act.json,
+        When the act is read from the act file,
+        Then the act's scene called 'Intro Image 2' plays the track audio/music/Level_2_overworld.wav.
+        And the act's scene called 'Intro Image 1' plays no music of its own.
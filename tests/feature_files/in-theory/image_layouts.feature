@@ -0,0 +1,35 @@
+Feature: Image cutscenes can be layered, panned and zoomed.
+    Scenario: Image cutscenes find their layout next to their image.
+        Given an image cutscene showing the image PI1.png from the folder assets/acts/images,
+        Then its layout would be found at assets/acts/images/PI1.json.
+
+    Scenario: Layouts that can't be read are left out.
+        Given the image layout called broken_scene.json,
+        Then there is no layout.
+
+    Scenario: Layouts list the images layered over an image cutscene.
+        Given the image layout called layered_scene.json,
+        Then the layout has 2 layers.
+        And layer 1 of the layout shows PI2.png with a parallax of 0.5.
+        And layer 2 of the layout shows PI3.png with a parallax of 1.
+
+    Scenario: Keyframes are played in order of time.
+        Given the image layout called layered_scene.json,
+        Then keyframe 1 of the layout is at 0 seconds.
+        And keyframe 2 of the layout is at 4 seconds.
+
+    Scenario: Image cutscenes ease between keyframes.
+        Given the image layout called layered_scene.json,
+        When 2 seconds of the scene have passed,
+        Then the image is panned by -10, 5 with a zoom of 1.25.
+
+    Scenario: Image cutscenes hold still after the last keyframe.
+        Given the image layout called layered_scene.json,
+        When 10 seconds of the scene have passed,
+        Then the image is panned by -20, 10 with a zoom of 1.5.
+
+    Scenario: Image cutscenes without keyframes don't move.
+        Given the image layout called still_scene.json,
+        When 3 seconds of the scene have passed,
+        Then the layout has 0 layers.
+        And the image is panned by 0, 0 with a zoom of 1.
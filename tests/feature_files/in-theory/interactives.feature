@@ -4,10 +4,10 @@ Feature: Interactives
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
         Then there are 2 interactive markers in the collection.
-        And marker 1 has a position of 0,0,1.
-        And marker 1 has a size of 64x64.
-        And marker 2 has a position of 64,128,1.
-        And marker 2 has a size of 128x64.
+        And marker 1 has a position of 64,128,1.
+        And marker 1 has a size of 128x64.
+        And marker 2 has a position of 0,0,1.
+        And marker 2 has a size of 64x64.
 
     Scenario: A marker has a given interactive type
         Given a Tiled map called multiple_marker_test.tmx,
@@ -18,49 +18,48 @@ Feature: Interactives
         Then the marker has the type Transition.
         And the Transition marker has a path of cabin_interior.tmx.
 
-    ##################################################################################
-    #                              Proximity Tests                                   #
-    ##################################################################################
-
-    Scenario: A position left of the marker reports as lower.
-        Given a Tiled map called marker_test.tmx,
-        And a position of 0,63,
+    Scenario: A sign is found as an Inspect marker
+        Given a Tiled map called inspect_marker_test.tmx,
+        And a position of 0,0,
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
-        Then there is 1 interactive marker in the collection.
-        And the position reports lower on the marker.
+        And a marker is requested for the position,
+        Then the marker has the type Inspect.
+        And the Inspect marker has the text 'Welcome to the breeding center!'.
 
-    Scenario: A position right of the marker reports as higher.
-        Given a Tiled map called marker_test.tmx,
-        And a position of 128,64,
+    Scenario: Markers missing the properties they need are skipped
+        Given a Tiled map called inspect_marker_test.tmx,
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
-        Then there is 1 interactive marker in the collection.
-        And the position reports higher on the marker.
+        Then there are 2 interactive markers in the collection.
 
-    Scenario: A position above the marker reports as lower.
-        Given a Tiled map called marker_test.tmx,
-        And a position of 63,0,
+    Scenario: A trigger is found with its settings
+        Given a Tiled map called trigger_marker_test.tmx,
+        And a position of 0,0,
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
-        Then there is 1 interactive marker in the collection.
-        And the position reports lower on the marker.
+        And a marker is requested for the position,
+        Then the marker has the type Trigger.
+        And the trigger changes the level to cabin_interior.tmx.
+        And the trigger fires on exit.
+        And the trigger fires once with a cooldown of 2.5 seconds.
 
-    Scenario: A position below the marker reports as higher.
-        Given a Tiled map called marker_test.tmx,
-        And a position of 64,128,
+    Scenario: A trigger fires on enter by default
+        Given a Tiled map called trigger_marker_test.tmx,
+        And a position of 128,0,
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
-        Then there is 1 interactive marker in the collection.
-        And the position reports higher on the marker.
+        And a marker is requested for the position,
+        Then the marker has the type Trigger.
+        And the trigger starts the act acts/act_2.ron.
+        And the trigger fires on enter.
+        And the trigger fires repeatedly with a cooldown of 0 seconds.
 
-    Scenario: A position on the marker reports as match.
-        Given a Tiled map called marker_test.tmx,
-        And a position of 64,64,
+    Scenario: Triggers with an invalid action are skipped
+        Given a Tiled map called trigger_marker_test.tmx,
         When the Tiled map is loaded,
         And an Interactive Collection is extracted from the Tiled map,
-        Then there is 1 interactive marker in the collection.
-        And the position reports match on the marker.
+        Then there are 2 interactive markers in the collection.
 
     ##################################################################################
     #                                Lookup Tests                                    #
@@ -109,3 +108,49 @@ Feature: Interactives
         And a marker is requested for the position,
         Then the marker has a position of 128,128,1.
         And the marker has a size of 64x64.
+
+    ##################################################################################
+    #                               Overlap Tests                                    #
+    ##################################################################################
+
+    Scenario: Every marker containing a position is found.
+        Given a Tiled map called overlapping_marker_test.tmx,
+        And a position of 96,96 on layer 1,
+        When the Tiled map is loaded,
+        And an Interactive Collection is extracted from the Tiled map,
+        And the markers containing the position are requested,
+        Then 2 markers contain the position.
+
+    Scenario: A marker on another layer isn't found for a position.
+        Given a Tiled map called multiple_marker_test.tmx,
+        And a position of 96,96 on layer 0,
+        When the Tiled map is loaded,
+        And an Interactive Collection is extracted from the Tiled map,
+        And a marker is requested for the position,
+        Then it reported there is no marker.
+
+    Scenario: Only markers on the layer of the position are found.
+        Given a Tiled map called overlapping_marker_test.tmx,
+        And a position of 96,96 on layer 2,
+        When the Tiled map is loaded,
+        And an Interactive Collection is extracted from the Tiled map,
+        And the markers containing the position are requested,
+        Then 1 marker contains the position.
+        And the found marker has the text 'A bee.'.
+
+    Scenario: A marker smaller than a tile is found.
+        Given a Tiled map called overlapping_marker_test.tmx,
+        And a position of 110,160 on layer 1,
+        When the Tiled map is loaded,
+        And an Interactive Collection is extracted from the Tiled map,
+        And the markers containing the position are requested,
+        Then 1 marker contains the position.
+        And the found marker has the text 'A lost button.'.
+
+    Scenario: Positions next to a marker smaller than a tile find nothing.
+        Given a Tiled map called overlapping_marker_test.tmx,
+        And a position of 140,160 on layer 1,
+        When the Tiled map is loaded,
+        And an Interactive Collection is extracted from the Tiled map,
+        And the markers containing the position are requested,
+        Then 0 markers contain the position.
@@ -0,0 +1,33 @@
+Feature: Pathfinding
+    Scenario: A path is found across an open map.
+        Given a map that is 3x3 tiles,
+        When a path is requested from 0,0 to 2,0,
+        Then the path is 2 tiles long.
+        And the path ends at 2,0.
+
+    Scenario: A path walks around collision tiles.
+        Given a map that is 5x5 tiles,
+        And collision tiles at 2,0 2,1 2,2 2,3,
+        When a path is requested from 0,0 to 4,0,
+        Then the path is 12 tiles long.
+        And the path goes through 2,4.
+        And the path ends at 4,0.
+
+    Scenario: A path walks around occupied tiles.
+        Given a map that is 3x3 tiles,
+        And occupied tiles at 1,0,
+        When a path is requested from 0,0 to 2,0,
+        Then the path is 4 tiles long.
+        And the path goes through 1,1.
+
+    Scenario: No path is found to a tile that cannot be reached.
+        Given a map that is 3x3 tiles,
+        And collision tiles at 1,0 1,1 1,2,
+        When a path is requested from 0,0 to 2,2,
+        Then there is no path.
+
+    Scenario: No path is found to an occupied tile.
+        Given a map that is 3x1 tiles,
+        And occupied tiles at 2,0,
+        When a path is requested from 0,0 to 2,0,
+        Then there is no path.
@@ -0,0 +1,90 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::ecs::message::Messages;
+use helping_hand::{
+    audio::footsteps::*,
+    map::{
+        interactions::map_changing::LoadLevel,
+        movement::grid_based_movement::{MovementDirection, Target},
+    },
+    plugins::levels::CoreLevelsPlugin,
+};
+use std::path::PathBuf;
+
+const FOOTSTEP_MAP_FOLDER: &str = "tests/test_assets/maps/footsteps";
+
+/// Returns the last footstep the game asked to play.
+fn get_last_footstep(game: &mut Game) -> PlayFootstep {
+    let footsteps = game.get_res::<Messages<PlayFootstep>>();
+    let mut footstep_cursor = footsteps.get_cursor();
+
+    footstep_cursor
+        .read(footsteps)
+        .last()
+        .expect("get_last_footstep: No footstep was played.")
+        .clone()
+}
+
+#[given(regex = r"the footstep sounds in (.+),")]
+fn given_footstep_sounds(game: &mut Game, footstep_file: String) {
+    *game.get_res_mut::<FootstepSounds>() = FootstepSounds::load_from(PathBuf::from(footstep_file));
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+
+    let map_path = format!("{}/{}", FOOTSTEP_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: usize, tile_y: usize) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y, actual_player_tile_coordinate.get_y());
+}
+
+#[when(regex = r"the Player moves (left|right),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = match movement_direction.as_str() {
+        "left" => MovementDirection::Left,
+        _ => MovementDirection::Right,
+    };
+    game.write_message(movement_direction_event);
+
+    // Footsteps are only kept for a couple of frames, so this stops as soon as the step is taken.
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+}
+
+#[then(regex = r"the footstep played is (.+)[,.]$")]
+fn verify_footstep_sound(game: &mut Game, expected_sound: String) {
+    let footstep = get_last_footstep(game);
+
+    assert_eq!(&PathBuf::from(expected_sound), footstep.get_sound());
+}
+
+#[then("the footstep is pitched close to the sound itself.")]
+fn verify_footstep_pitch(game: &mut Game) {
+    let footstep = get_last_footstep(game);
+
+    let playback_rate = footstep.get_playback_rate();
+    assert!((0.9..=1.1).contains(&playback_rate));
+}
+
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/footsteps.feature",
+    ));
+}
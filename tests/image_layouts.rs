@@ -0,0 +1,91 @@
+use bevy::math::Vec2;
+use cucumber::{given, then, when, World};
+use helping_hand::narrative::image_layouts::*;
+use std::{path::PathBuf, time::Duration};
+
+const LAYOUTS_FOLDER: &str = "tests/test_assets/acts/layouts";
+
+#[derive(Debug, Default, World)]
+struct LayoutWorld {
+    images_folder: PathBuf,
+    image_path: PathBuf,
+    layout: Option<ImageCutsceneLayout>,
+    elapsed: Duration,
+}
+
+fn get_layout(world: &LayoutWorld) -> &ImageCutsceneLayout {
+    world
+        .layout
+        .as_ref()
+        .expect("get_layout: The image layout couldn't be read.")
+}
+
+#[given(regex = r"an image cutscene showing the image (.+) from the folder (.+),")]
+fn given_image_cutscene(world: &mut LayoutWorld, image_path: String, images_folder: String) {
+    world.image_path = PathBuf::from(image_path);
+    world.images_folder = PathBuf::from(images_folder);
+}
+
+#[given(regex = r"the image layout called (.+),")]
+fn given_image_layout(world: &mut LayoutWorld, layout_file_name: String) {
+    let layout_path = PathBuf::from(LAYOUTS_FOLDER).join(layout_file_name);
+
+    world.layout = ImageCutsceneLayout::load_from(layout_path);
+}
+
+#[when(regex = r"([0-9.]+) seconds of the scene have passed,")]
+fn pass_scene_time(world: &mut LayoutWorld, seconds: f32) {
+    world.elapsed = Duration::from_secs_f32(seconds);
+}
+
+#[then(regex = r"its layout would be found at (.+)\.$")]
+fn verify_layout_path(world: &mut LayoutWorld, expected_layout_path: String) {
+    let actual_layout_path =
+        ImageCutsceneLayout::get_layout_path(&world.images_folder, &world.image_path);
+
+    assert_eq!(PathBuf::from(expected_layout_path), actual_layout_path);
+}
+
+#[then("there is no layout.")]
+fn verify_no_layout(world: &mut LayoutWorld) {
+    assert!(world.layout.is_none());
+}
+
+#[then(regex = r"the layout has ([0-9]+) layers.")]
+fn verify_number_of_layers(world: &mut LayoutWorld, expected_num_layers: usize) {
+    assert_eq!(expected_num_layers, get_layout(world).get_layers().len());
+}
+
+#[then(regex = r"layer ([0-9]+) of the layout shows (.+) with a parallax of ([0-9.]+).")]
+fn verify_layer(
+    world: &mut LayoutWorld,
+    layer_num: usize,
+    expected_image: String,
+    expected_parallax: f32,
+) {
+    let layer = &get_layout(world).get_layers()[layer_num - 1];
+
+    assert_eq!(&PathBuf::from(expected_image), layer.get_image());
+    assert_eq!(expected_parallax, layer.get_parallax());
+}
+
+#[then(regex = r"keyframe ([0-9]+) of the layout is at ([0-9.]+) seconds.")]
+fn verify_keyframe_time(world: &mut LayoutWorld, keyframe_num: usize, expected_seconds: f32) {
+    let keyframe = get_layout(world).get_keyframes()[keyframe_num - 1];
+
+    assert_eq!(expected_seconds, keyframe.get_time());
+}
+
+#[then(regex = r"the image is panned by (-?[0-9.]+), (-?[0-9.]+) with a zoom of ([0-9.]+).")]
+fn verify_pan_zoom(world: &mut LayoutWorld, pan_x: f32, pan_y: f32, expected_zoom: f32) {
+    let (actual_pan, actual_zoom) = get_layout(world).get_pan_zoom_at(world.elapsed);
+
+    assert!(Vec2::new(pan_x, pan_y).abs_diff_eq(actual_pan, 0.001));
+    assert!((expected_zoom - actual_zoom).abs() < 0.001);
+}
+
+fn main() {
+    futures::executor::block_on(LayoutWorld::run(
+        "tests/feature_files/in-theory/image_layouts.feature",
+    ));
+}
@@ -1,7 +1,11 @@
 use cucumber::{given, then, when, World};
-use helping_hand::map::{interactions::interactives::*, *};
+use helping_hand::map::{
+    interactions::{interactives::*, triggers::*},
+    *,
+};
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::time::Duration;
 use tiled::{Loader, Map};
 
 #[derive(Debug, Default, World)]
@@ -12,6 +16,7 @@ struct GameWorld {
     pub interactive_collection: InteractiveCollection,
     pub point_of_interest: PxCords,
     pub found_marker: Option<InteractiveMarker>,
+    pub found_markers: Vec<InteractiveMarker>,
 }
 
 impl GameWorld {
@@ -21,6 +26,7 @@ impl GameWorld {
         let interactive_collection = InteractiveCollection::default();
         let point_of_interest = PxCords::new(0, 0, 0);
         let found_marker = None;
+        let found_markers = Vec::new();
 
         Self {
             map_location,
@@ -28,6 +34,7 @@ impl GameWorld {
             interactive_collection,
             point_of_interest,
             found_marker,
+            found_markers,
         }
     }
 }
@@ -49,21 +56,6 @@ fn get_tiled_map_location(map_name: String) -> PathBuf {
     tiled_map_path
 }
 
-/// Converts a string into a Proximity
-fn convert_string_to_proximity(proximity_string: String) -> Proximity {
-    let proximity = match proximity_string.as_str() {
-        "lower" => Proximity::Lower,
-        "higher" => Proximity::Higher,
-        "match" => Proximity::Match,
-        _ => panic!(
-            "convert_string_to_proximity: Invalid proximity given: {}",
-            proximity_string
-        ),
-    };
-
-    proximity
-}
-
 //////////////TEST FUNCTIONS//////////////
 
 #[given(regex = r"a Tiled map called (.+\.tmx),")]
@@ -81,7 +73,18 @@ fn verify_test_map_exists(world: &mut GameWorld, map_name: String) {
 
 #[given(regex = r"a position of ([0-9]+),([0-9]+),")]
 fn set_point_of_interest(world: &mut GameWorld, x_cord: usize, y_cord: usize) {
-    world.point_of_interest = PxCords::new(x_cord, y_cord, 0);
+    // Every test map keeps its markers on its second layer, the Interaction layer.
+    world.point_of_interest = PxCords::new(x_cord, y_cord, 1);
+}
+
+#[given(regex = r"a position of ([0-9]+),([0-9]+) on layer ([0-9]+),")]
+fn set_point_of_interest_on_layer(
+    world: &mut GameWorld,
+    x_cord: usize,
+    y_cord: usize,
+    z_cord: usize,
+) {
+    world.point_of_interest = PxCords::new(x_cord, y_cord, z_cord);
 }
 
 #[when("the Tiled map is loaded,")]
@@ -93,8 +96,10 @@ fn load_test_map(world: &mut GameWorld) {
 
 #[when("an Interactive Collection is extracted from the Tiled map,")]
 fn interactive_tiles_are_collected(world: &mut GameWorld) {
-    let interactives = get_interactives_from(&world.loaded_map.as_ref().unwrap());
-    let interactive_collection = InteractiveCollection::from_markers(interactives);
+    let tiled_map = world.loaded_map.as_ref().unwrap();
+    let interactives = get_interactives_from(tiled_map);
+    let tile_dimensions = PxDimensions::new(tiled_map.tile_width, tiled_map.tile_height);
+    let interactive_collection = InteractiveCollection::from_markers(interactives, tile_dimensions);
     world.interactive_collection = interactive_collection;
 }
 
@@ -107,6 +112,17 @@ fn request_marker_from_position(world: &mut GameWorld) {
         .cloned();
 }
 
+#[when("the markers containing the position are requested,")]
+fn request_markers_containing_position(world: &mut GameWorld) {
+    let interactive_collection = &world.interactive_collection;
+    let position = world.point_of_interest;
+    world.found_markers = interactive_collection
+        .get_markers_containing(&position)
+        .into_iter()
+        .cloned()
+        .collect();
+}
+
 #[then(regex = r"there (?:is|are) ([0-9]+) interactive marker(?:s)? in the collection.")]
 fn verify_number_of_interactive_markers_in_collection(
     world: &mut GameWorld,
@@ -142,16 +158,6 @@ fn verify_marker_size(world: &mut GameWorld, index: usize, width: u32, height: u
     assert_eq!(expected_size, actual_size);
 }
 
-#[then(regex = r"the position reports ([a-zA-Z]+) on the marker.")]
-fn verify_marker_contains_position(world: &mut GameWorld, proximity: String) {
-    let position_of_interest = world.point_of_interest;
-    let marker = world.interactive_collection.get_marker_at_index(0);
-
-    let actual_proximity = marker.containing(&position_of_interest);
-    let expected_proximity = convert_string_to_proximity(proximity);
-    assert_eq!(expected_proximity, actual_proximity);
-}
-
 #[then(regex = r"the marker has a position of ([0-9]+),([0-9]+),([0-9]+).")]
 fn verify_found_marker_positon(world: &mut GameWorld, x_cord: usize, y_cord: usize, z_cord: usize) {
     let actual_position = world.found_marker.as_ref().unwrap().get_position();
@@ -181,12 +187,60 @@ fn verify_marker_interactive_type(world: &mut GameWorld, type_name: String) {
 
 #[then(regex = r"the Transition marker has a path of (.+).")]
 fn verify_interactive_type_path_value(world: &mut GameWorld, path_name: String) {
-    let marker_path = world.found_marker.as_ref().unwrap().get_path();
+    let marker_path = world.found_marker.as_ref().unwrap().get_path().unwrap();
     let actual_path = marker_path.to_str().unwrap();
     let expected_path = path_name;
     assert_eq!(expected_path, actual_path);
 }
 
+#[then(regex = r"the Inspect marker has the text '(.+)'.")]
+fn verify_interactive_type_text_value(world: &mut GameWorld, expected_text: String) {
+    let actual_text = world.found_marker.as_ref().unwrap().get_text().unwrap();
+    assert_eq!(expected_text, actual_text);
+}
+
+#[then(regex = r"the trigger changes the level to (.+).")]
+fn verify_trigger_changes_level(world: &mut GameWorld, path_name: String) {
+    let trigger = world.found_marker.as_ref().unwrap().get_trigger().unwrap();
+    let expected_action = TriggerAction::ChangeLevel(PathBuf::from(path_name));
+    assert_eq!(&expected_action, trigger.get_action());
+}
+
+#[then(regex = r"the trigger starts the act (.+).")]
+fn verify_trigger_starts_act(world: &mut GameWorld, act_path: String) {
+    let trigger = world.found_marker.as_ref().unwrap().get_trigger().unwrap();
+    let expected_action = TriggerAction::StartAct(act_path);
+    assert_eq!(&expected_action, trigger.get_action());
+}
+
+#[then(regex = r"the trigger fires on (enter|exit).")]
+fn verify_trigger_activation(world: &mut GameWorld, activation: String) {
+    let trigger = world.found_marker.as_ref().unwrap().get_trigger().unwrap();
+    let expected_activation = match activation.as_str() {
+        "enter" => TriggerActivation::OnEnter,
+        _ => TriggerActivation::OnExit,
+    };
+    assert_eq!(&expected_activation, trigger.get_activation());
+}
+
+#[then(regex = r"the trigger fires (once|repeatedly) with a cooldown of ([0-9.]+) seconds.")]
+fn verify_trigger_repetition(world: &mut GameWorld, repetition: String, cooldown: f32) {
+    let trigger = world.found_marker.as_ref().unwrap().get_trigger().unwrap();
+    assert_eq!(repetition == "once", trigger.is_once());
+    assert_eq!(&Duration::from_secs_f32(cooldown), trigger.get_cooldown());
+}
+
+#[then(regex = r"([0-9]+) markers? contains? the position.")]
+fn verify_number_of_markers_containing_position(world: &mut GameWorld, expected_amount: usize) {
+    assert_eq!(expected_amount, world.found_markers.len());
+}
+
+#[then(regex = r"the found marker has the text '(.+)'.")]
+fn verify_found_markers_text(world: &mut GameWorld, expected_text: String) {
+    let actual_text = world.found_markers[0].get_text().unwrap();
+    assert_eq!(expected_text, actual_text);
+}
+
 fn main() {
     futures::executor::block_on(GameWorld::run(
         "tests/feature_files/in-theory/interactives.feature",
@@ -0,0 +1,148 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    map::{
+        interactions::map_changing::{ChangeLevel, CurrentLevel, LoadLevel},
+        items::{Inventory, ItemPickup},
+        movement::grid_based_movement::{MovementDirection, Target},
+        player::*,
+        GridCords3D,
+    },
+    plugins::{inventory::InventoryPlugin, levels::CoreLevelsPlugin},
+    ui::menus::inventory::{InventorySlotText, InventoryUI},
+};
+
+const ITEM_MAP_FOLDER: &str = "tests/test_assets/maps/items";
+
+/// Converts a string into a MovementDirection
+fn convert_string_to_movement_direction(movement_string: String) -> MovementDirection {
+    let movement_direction_event = match movement_string.as_str() {
+        "left" => MovementDirection::Left,
+        "right" => MovementDirection::Right,
+        "up" => MovementDirection::Up,
+        "down" => MovementDirection::Down,
+        _ => panic!(
+            "convert_string_to_movement_direction: Invalid direction given: {}",
+            movement_string
+        ),
+    };
+
+    movement_direction_event
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(InventoryPlugin);
+
+    let map_path = format!("{}/{}", ITEM_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[given(regex = r"there (?:is|are) ([0-9]+) items? on the map,")]
+#[then(regex = r"there (?:is|are) ([0-9]+) items? on the map.")]
+fn verify_number_of_items(game: &mut Game, expected_item_amount: usize) {
+    let actual_item_amount = game.get_number_of::<ItemPickup>();
+
+    assert_eq!(expected_item_amount, actual_item_amount);
+}
+
+#[when("the player interacts with the tile ahead of them,")]
+fn trigger_player_interaction(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the Player moves ([a-zA-Z]+),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = convert_string_to_movement_direction(movement_direction);
+    game.write_message(movement_direction_event);
+
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+}
+
+#[when("the level is entered again,")]
+fn reenter_level(game: &mut Game) {
+    let map_path = game.get_res::<CurrentLevel>().get_level_path().clone();
+    game.write_message(ChangeLevel::new(map_path.to_str().unwrap()));
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the player presses the inventory key,")]
+fn press_inventory_key(game: &mut Game) {
+    game.press_key(KeyCode::KeyI);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[then(regex = r"the Player should be at ([0-9]+),([0-9]+).")]
+fn verify_player_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let expected_player_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, 0);
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(
+        expected_player_tile_coordinate.get_x(),
+        actual_player_tile_coordinate.get_x()
+    );
+    assert_eq!(
+        expected_player_tile_coordinate.get_y(),
+        actual_player_tile_coordinate.get_y()
+    );
+}
+
+#[then(regex = r"the Player carries ([0-9]+) ([a-zA-Z]+)[,.]")]
+fn verify_carried_items(game: &mut Game, expected_quantity: u32, item_name: String) {
+    let inventory = game.get_cloned_of::<Inventory, Player>();
+    let found_item = inventory
+        .get_items()
+        .iter()
+        .find(|item| item.get_name() == &item_name);
+
+    let actual_quantity = found_item.map_or(0, |item| item.get_quantity());
+
+    assert_eq!(expected_quantity, actual_quantity);
+}
+
+#[then(regex = r"the inventory shows '(.+)'.")]
+fn verify_inventory_shows(game: &mut Game, expected_text: String) {
+    let actual_text = game.get_cloned_of::<Text, InventorySlotText>();
+
+    assert_eq!(expected_text, actual_text.as_str());
+}
+
+#[then("the inventory is closed.")]
+fn verify_inventory_closed(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<InventoryUI>());
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/items.feature"));
+}
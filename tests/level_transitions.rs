@@ -0,0 +1,184 @@
+mod mock_game;
+
+use std::{path::PathBuf, time::Duration};
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    map::{
+        interactions::{
+            level_transitions::{LevelTransition, LevelTransitionDuration, LevelTransitionEffect},
+            map_changing::LoadLevel,
+        },
+        player::*,
+        GridCords3D,
+    },
+    plugins::{acts::CoreActsPlugin, levels::CoreLevelsPlugin, loading::CoreLoadingPlugin},
+    ui::loading_screen::LoadingScreenUI,
+    AppState,
+};
+
+const TRANSITION_MAP_FOLDER: &str = "tests/test_assets/maps/level_transitions";
+const MAP_CHANGING_MAP_FOLDER: &str = "tests/test_assets/maps/map_changing";
+const STARTING_ACT_PATH: &str = "tests/test_assets/acts/image_cutscene_act.json";
+const TIME_STEP: Duration = Duration::from_millis(5);
+const MAX_NUM_LOADING_TICKS: usize = 100_000;
+
+/// Loads some map, with level transitions short enough to wait on.
+fn load_map_from(game: &mut Game, map_path: String) {
+    game.add_plugin(CoreLevelsPlugin);
+    game.set_time_step(TIME_STEP);
+    *game.get_res_mut::<LevelTransitionDuration>() =
+        LevelTransitionDuration::new(Duration::from_secs_f32(0.2));
+
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"a Tiled map called (.+) from the map changing tests,")]
+fn given_some_map_changing_map(game: &mut Game, tiled_map_name: String) {
+    load_map_from(
+        game,
+        format!("{}/{}", MAP_CHANGING_MAP_FOLDER, tiled_map_name),
+    );
+}
+
+#[given(regex = r"^a Tiled map called ([^ ]+),$")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    load_map_from(
+        game,
+        format!("{}/{}", TRANSITION_MAP_FOLDER, tiled_map_name),
+    );
+}
+
+#[given("levels are loaded behind a loading screen,")]
+fn given_loading_screen(game: &mut Game) {
+    game.add_plugin(CoreActsPlugin::new(
+        Duration::from_secs(0),
+        PathBuf::from(TRANSITION_MAP_FOLDER),
+    ));
+    game.add_plugin(CoreLoadingPlugin::new(PathBuf::from(STARTING_ACT_PATH)));
+}
+
+#[given("everything has finished loading,")]
+#[when("everything has finished loading,")]
+fn wait_for_loading(game: &mut Game) {
+    // The game only starts loading the frame after it's asked to, so it's waited on
+    // to start loading before it's waited on to finish.
+    let mut has_started_loading = game.get_res::<State<AppState>>().get() == &AppState::Loading;
+
+    // Assets are loaded on their own threads, so this keeps the game going until they're in.
+    let has_loaded = game.tick_until(MAX_NUM_LOADING_TICKS, |game| {
+        let app_state = *game.get_res::<State<AppState>>().get();
+        has_started_loading |= app_state == AppState::Loading;
+
+        has_started_loading && app_state == AppState::InScene
+    });
+    assert!(
+        has_loaded,
+        "wait_for_loading: The assets never finished loading."
+    );
+
+    game.tick();
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[when("the player interacts with the tile ahead of them,")]
+fn trigger_player_interaction(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the player presses the key to walk down,")]
+fn press_walk_down_key(game: &mut Game) {
+    game.press_key(KeyCode::KeyS);
+}
+
+#[when("the level transition finishes,")]
+fn wait_for_level_transition(game: &mut Game) {
+    let has_finished = game.tick_until(400, |game| game.get_number_of::<LevelTransition>() == 0);
+    assert!(
+        has_finished,
+        "wait_for_level_transition: The transition never finished."
+    );
+
+    game.tick();
+}
+
+#[then(regex = r"a ([a-zA-Z]+) level transition is running.")]
+fn verify_level_transition_running(game: &mut Game, effect_name: String) {
+    let expected_effect = LevelTransitionEffect::parse_from(&effect_name)
+        .expect("verify_level_transition_running: Unknown level transition effect.");
+    let level_transitions = game.get_all_cloned_of::<LevelTransition, LevelTransition>();
+
+    assert_eq!(1, level_transitions.len());
+    assert_eq!(expected_effect, level_transitions[0].get_effect());
+}
+
+#[when("the level transition covers the screen,")]
+fn wait_for_screen_covered(game: &mut Game) {
+    let is_covered = game.tick_until(400, |game| {
+        game.get_res::<State<AppState>>().get() == &AppState::Loading
+    });
+    assert!(
+        is_covered,
+        "wait_for_screen_covered: The game never started loading the next level."
+    );
+
+    game.tick();
+}
+
+#[then("the loading screen is drawn over the level transition,")]
+fn verify_loading_screen_over_transition(game: &mut Game) {
+    let transition_z_index = game.get_of::<ZIndex, LevelTransition>();
+    let loading_screen_z_index = game.get_of::<GlobalZIndex, LoadingScreenUI>();
+
+    assert_eq!(1, game.get_number_of::<LevelTransition>());
+    assert!(loading_screen_z_index.0 > transition_z_index.0);
+}
+
+#[then("there is no level transition running,")]
+fn verify_no_level_transition_running(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<LevelTransition>());
+}
+
+#[then(regex = r"the map size should be ([0-9]+) x ([0-9]+) tiles[,.]")]
+fn verify_map_size(game: &mut Game, expected_map_width: u32, expected_map_height: u32) {
+    let map_dimensions = game.get_map_size();
+
+    assert_eq!(expected_map_height, map_dimensions.get_rows());
+    assert_eq!(expected_map_width, map_dimensions.get_columns());
+}
+
+#[then(regex = r"the Player should be at ([0-9]+),([0-9]+).")]
+fn verify_player_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let expected_player_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, 0);
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(
+        expected_player_tile_coordinate.get_x(),
+        actual_player_tile_coordinate.get_x()
+    );
+    assert_eq!(
+        expected_player_tile_coordinate.get_y(),
+        actual_player_tile_coordinate.get_y()
+    );
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/level_transitions.feature",
+    ));
+}
@@ -0,0 +1,136 @@
+mod mock_game;
+
+use std::{path::PathBuf, time::Duration};
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    loading::NeededAssets,
+    map::{interactions::map_changing::ChangeLevel, TileType},
+    plugins::{acts::CoreActsPlugin, levels::CoreLevelsPlugin, loading::CoreLoadingPlugin},
+    ui::loading_screen::LoadingScreenUI,
+    AppState,
+};
+
+const ACTS_FOLDER: &str = "tests/test_assets/acts";
+const MAPS_FOLDER: &str = "tests/test_assets/maps/scene_loading/";
+const MAX_NUM_TICKS: usize = 100_000;
+const TIME_STEP: Duration = Duration::from_millis(16);
+
+#[given("the game is capable of loading acts,")]
+fn add_loading_plugins(game: &mut Game) {
+    game.set_time_step(TIME_STEP);
+    game.add_plugin(CoreActsPlugin::new(
+        Duration::from_secs(0),
+        PathBuf::from(MAPS_FOLDER),
+    ));
+}
+
+#[given("the game is capable of loading levels,")]
+fn add_level_loading_plugins(game: &mut Game) {
+    let starting_act_path = PathBuf::from(format!("{}/image_cutscene_act.json", ACTS_FOLDER));
+
+    game.set_time_step(TIME_STEP);
+    game.add_plugin(CoreActsPlugin::new(
+        Duration::from_secs(0),
+        PathBuf::from(MAPS_FOLDER),
+    ));
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(CoreLoadingPlugin::new(starting_act_path));
+}
+
+#[when(regex = r"the level '(.+)' is changed to,")]
+fn change_level(game: &mut Game, level_name: String) {
+    let level_path = format!("{}{}", MAPS_FOLDER, level_name);
+    game.write_message(ChangeLevel::new(&level_path));
+
+    game.tick();
+}
+
+#[when(regex = r"the act called '(.+)' starts loading,")]
+fn start_loading_act(game: &mut Game, act_file_name: String) {
+    let act_file_path = PathBuf::from(format!("{}/{}", ACTS_FOLDER, act_file_name));
+    game.add_plugin(CoreLoadingPlugin::new(act_file_path));
+
+    game.get_res_mut::<NextState<AppState>>()
+        .set(AppState::Loading);
+    game.tick();
+}
+
+#[when("everything has finished loading,")]
+fn wait_for_loading(game: &mut Game) {
+    // Assets are loaded on their own threads, so this keeps the game going until they're in.
+    let has_loaded = game.tick_until(MAX_NUM_TICKS, |game| {
+        game.get_res::<State<AppState>>().get() == &AppState::InScene
+    });
+
+    assert!(
+        has_loaded,
+        "wait_for_loading: The assets never finished loading."
+    );
+}
+
+#[then("the game is loading,")]
+fn verify_loading(game: &mut Game) {
+    assert_eq!(&AppState::Loading, game.get_res::<State<AppState>>().get());
+}
+
+#[then("the game is in the scene,")]
+fn verify_in_scene(game: &mut Game) {
+    assert_eq!(&AppState::InScene, game.get_res::<State<AppState>>().get());
+}
+
+#[then(regex = r"the level is (built|not built yet).")]
+fn verify_level_built(game: &mut Game, built_or_not: String) {
+    game.tick();
+
+    let is_built = game.get_number_of::<TileType>() > 0;
+
+    assert_eq!(built_or_not == "built", is_built);
+}
+
+#[then(regex = r"the loading screen is (shown|closed).")]
+fn verify_loading_screen(game: &mut Game, shown_or_closed: String) {
+    let is_shown = game.get_number_of::<LoadingScreenUI>() > 0;
+
+    assert_eq!(shown_or_closed == "shown", is_shown);
+}
+
+#[then(regex = r"^the image '(.+)' is needed[,.]$")]
+fn verify_image_needed(game: &mut Game, image_path: String) {
+    let needed_assets = game.get_res::<NeededAssets>();
+
+    assert!(needed_assets
+        .get_image_paths()
+        .contains(&PathBuf::from(image_path)));
+}
+
+#[then(regex = r"the image '(.+)' is needed once.")]
+fn verify_image_needed_once(game: &mut Game, image_path: String) {
+    let needed_assets = game.get_res::<NeededAssets>();
+    let image_path = PathBuf::from(image_path);
+
+    let num_times_needed = needed_assets
+        .get_image_paths()
+        .iter()
+        .filter(|needed_image| **needed_image == image_path)
+        .count();
+
+    assert_eq!(1, num_times_needed);
+}
+
+#[then(regex = r"the music '(.+)' is needed.")]
+fn verify_music_needed(game: &mut Game, audio_path: String) {
+    let needed_assets = game.get_res::<NeededAssets>();
+
+    assert!(needed_assets
+        .get_audio_paths()
+        .contains(&PathBuf::from(audio_path)));
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/loading.feature"));
+}
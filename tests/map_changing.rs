@@ -3,9 +3,16 @@ mod mock_game;
 use crate::mock_game::Game;
 use cucumber::{given, then, when, World};
 
+use bevy::prelude::*;
 use helping_hand::{
-    map::{interactions::map_changing::LoadLevel, player::*, GridCords3D},
+    map::{
+        interactions::map_changing::LoadLevel,
+        movement::grid_based_movement::{MovementDirection, Target},
+        player::*,
+        GridCords3D,
+    },
     plugins::levels::CoreLevelsPlugin,
+    ui::text_box::{TextBox, TextBoxText},
 };
 
 #[given(regex = r"a Tiled map called (.+),")]
@@ -52,6 +59,24 @@ fn trigger_player_interaction(game: &mut Game) {
     }
 }
 
+#[when("the Player moves left,")]
+fn move_player_left(game: &mut Game) {
+    game.write_message(MovementDirection::Left);
+
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
 #[then(regex = r"the Player should be at ([0-9]+),([0-9]+).")]
 fn verify_player_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
     let expected_player_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, 0);
@@ -79,6 +104,27 @@ fn verify_map_size(game: &mut Game, expected_map_width: u32, expected_map_height
     assert_eq!(expected_map_width, actual_map_width);
 }
 
+#[when("the player presses the interact key,")]
+fn press_interact_key(game: &mut Game) {
+    game.press_key(KeyCode::KeyE);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[then(regex = r"a text box reads '(.+)'.")]
+fn verify_text_box_text(game: &mut Game, expected_text: String) {
+    let actual_text = game.get_cloned_of::<Text, TextBoxText>();
+
+    assert_eq!(expected_text, actual_text.as_str());
+}
+
+#[then("there is no text box.")]
+fn verify_no_text_box(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<TextBox>());
+}
+
 // This runs before everything else, so you can setup things here.
 fn main() {
     futures::executor::block_on(Game::run(
@@ -5,19 +5,27 @@ use std::{
 
 use bevy::{
     ecs::component::Mutable,
-    input::InputPlugin,
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        ButtonState, InputPlugin,
+    },
     mesh::MeshPlugin,
     prelude::*,
     render::{settings::WgpuSettings, view::screenshot::CapturedScreenshots, RenderPlugin},
     sprite::SpritePlugin,
     state::app::StatesPlugin,
     text::TextPlugin,
+    time::TimeUpdateStrategy,
     window::WindowResolution,
 };
 use cucumber::World;
 
 use helping_hand::{
-    map::{movement::grid_based_movement::*, player::*, *},
+    map::{
+        movement::{grid_based_movement::*, npc_movement::IdleTime},
+        player::*,
+        *,
+    },
     plugins::playable_character::PlayableCharacterTestingPlugin,
     AppState,
 };
@@ -58,6 +66,7 @@ impl Game {
         app.add_plugins(MeshPlugin);
         app.add_plugins(TextPlugin);
         app.insert_resource(ArrivalTime::new(Duration::from_secs_f32(0.0)));
+        app.insert_resource(IdleTime::new(Duration::from_secs_f32(0.0)));
 
         // NOTE: How dare you Bevy! We need this to ensure tests do not crash
         // starting in 0.15. Maybe we can remove these two lines in the future.
@@ -82,6 +91,30 @@ impl Game {
         self.app.update();
     }
 
+    /// Makes every tick advance time by the same step, instead of however long it really took,
+    /// so that anything playing out over time does so the same way on every run.
+    pub fn set_time_step(&mut self, time_step: Duration) {
+        self.app
+            .insert_resource(TimeUpdateStrategy::ManualDuration(time_step));
+    }
+
+    /// Advances the game one frame at a time until some condition holds, up to some number
+    /// of frames. Returns whether the condition was met.
+    pub fn tick_until<F>(&mut self, max_num_ticks: usize, mut is_met: F) -> bool
+    where
+        F: FnMut(&mut Self) -> bool,
+    {
+        for _ in 0..max_num_ticks {
+            self.tick();
+
+            if is_met(self) {
+                return true;
+            }
+        }
+
+        false
+    }
+
     /// Returns the pixel coordinates for some tile found at some grid coordinates loaded in the game.
     pub fn get_position_from_tile(&mut self, tile_grid_coordinates: &GridCords3D) -> Transform {
         let tile_position = self
@@ -155,6 +188,22 @@ impl Game {
             .expect("get_with: Cannot find a Component with some other Component.")
     }
 
+    /// Returns a copy of a Component C that has some other Component D associated with it,
+    /// or panics otherwise.
+    pub fn get_cloned_of<C, D>(&mut self) -> C
+    where
+        C: Component + Clone,
+        D: Component,
+    {
+        self.app
+            .world_mut()
+            .query_filtered::<&C, With<D>>()
+            .iter(&self.app.world())
+            .next()
+            .cloned()
+            .expect("get_cloned_of: Cannot find a Component with some other Component.")
+    }
+
     /// Returns the number of Components found in the game.
     pub fn get_number_of<C>(&mut self) -> usize
     where
@@ -208,6 +257,41 @@ impl Game {
         found_component
     }
 
+    /// Returns a clone of every Component that has some other component.
+    pub fn get_all_cloned_of<C, D>(&mut self) -> Vec<C>
+    where
+        C: Component + Clone,
+        D: Component,
+    {
+        let found_components = self
+            .app
+            .world_mut()
+            .query_filtered::<&C, With<D>>()
+            .iter(&self.app.world())
+            .cloned()
+            .collect();
+
+        found_components
+    }
+
+    /// Returns every entry of three Components found together in the game.
+    pub fn get_all_of<A, B, C>(&mut self) -> Vec<(A, B, C)>
+    where
+        A: Component + Clone,
+        B: Component + Clone,
+        C: Component + Clone,
+    {
+        let found_components = self
+            .app
+            .world_mut()
+            .query::<(&A, &B, &C)>()
+            .iter(&self.app.world())
+            .map(|(a, b, c)| (a.clone(), b.clone(), c.clone()))
+            .collect();
+
+        found_components
+    }
+
     /// Returns the grid coordinates for the player in the game.
     pub fn find_coordinates_of_player(&mut self) -> GridCords3D {
         let player_tile_coordinate = self
@@ -242,6 +326,31 @@ impl Game {
         self.tick();
     }
 
+    /// Spawns some entity straight into the game, such as a button to click, and returns it.
+    pub fn spawn<B>(&mut self, bundle: B) -> Entity
+    where
+        B: Bundle,
+    {
+        self.app.world_mut().spawn(bundle).id()
+    }
+
+    /// Presses and then releases some key, the same way a keyboard would.
+    ///
+    /// Pressing keys through the ButtonInput resource directly doesn't work for systems
+    /// checking for keys that were just pressed, since that gets cleared before they run.
+    pub fn press_key(&mut self, key_code: KeyCode) {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            self.write_message(KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+        }
+    }
+
     /// Sets the window dimensions of the game to the specified width and height.
     pub fn set_window_resolution(&mut self, window_width: u32, window_height: u32) {
         let mut window = self.get_mut::<Window>();
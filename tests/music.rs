@@ -0,0 +1,97 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::ecs::message::Messages;
+use helping_hand::{
+    audio::music::*,
+    map::interactions::map_changing::ChangeLevel,
+    narrative::act_loading::{LoadAct, LoadNextScene},
+    plugins::{acts::CoreActsPlugin, levels::CoreLevelsPlugin, music::CoreMusicPlugin},
+};
+use std::{path::PathBuf, time::Duration};
+
+const MUSIC_MAP_FOLDER: &str = "tests/test_assets/maps/music";
+
+#[given("the game can play music,")]
+fn add_music_plugins(game: &mut Game) {
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(CoreMusicPlugin::new(Duration::from_secs(0)));
+}
+
+#[given("the game is capable of handling acts,")]
+fn add_acts_plugin(game: &mut Game) {
+    let maps_folder_path = PathBuf::from(MUSIC_MAP_FOLDER);
+
+    game.add_plugin(CoreActsPlugin::new(
+        Duration::from_secs(0),
+        maps_folder_path,
+    ));
+}
+
+#[when(regex = r"the level (.+\.tmx) is loaded,")]
+fn load_level(game: &mut Game, map_file_name: String) {
+    let map_path = format!("{}/{}", MUSIC_MAP_FOLDER, map_file_name);
+    game.write_message(ChangeLevel::new(&map_path));
+
+    // Messages only last two frames, so by now the music changes of any level
+    // loaded before this one are cleared out, while this level's are still around.
+    game.tick();
+}
+
+#[when(regex = r"the act called '(.+)' is loaded,")]
+fn load_act(game: &mut Game, act_file_name: String) {
+    let act_file_path_name = format!("tests/test_assets/acts/{}", act_file_name);
+    game.write_message(LoadAct::new(&act_file_path_name));
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the game transitions to the next scene,")]
+fn transition_to_next_scene(game: &mut Game) {
+    game.write_message(LoadNextScene::new());
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[then(regex = r"the track (.+) is playing[,.]")]
+fn verify_playing_track(game: &mut Game, expected_track: String) {
+    let playing_music = game.get_res::<PlayingMusic>();
+
+    assert_eq!(
+        Some(&PathBuf::from(expected_track)),
+        playing_music.get_track()
+    );
+}
+
+#[then(regex = r"no music is playing[,.]")]
+fn verify_no_music_playing(game: &mut Game) {
+    let playing_music = game.get_res::<PlayingMusic>();
+
+    assert_eq!(None, playing_music.get_track());
+}
+
+#[then(regex = r"the music didn't change tracks[,.]")]
+fn verify_music_unchanged(game: &mut Game) {
+    let music_changes = game.get_res::<Messages<MusicChanged>>();
+    let mut music_change_cursor = music_changes.get_cursor();
+
+    assert_eq!(0, music_change_cursor.read(music_changes).count());
+}
+
+#[then(regex = r"the music changed tracks[,.]")]
+fn verify_music_changed(game: &mut Game) {
+    let music_changes = game.get_res::<Messages<MusicChanged>>();
+    let mut music_change_cursor = music_changes.get_cursor();
+
+    assert_eq!(1, music_change_cursor.read(music_changes).count());
+}
+
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/music.feature"));
+}
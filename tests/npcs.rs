@@ -0,0 +1,175 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+
+use cucumber::{given, then, when, World};
+
+use helping_hand::{
+    map::{
+        interactions::map_changing::LoadLevel,
+        movement::grid_based_movement::*,
+        npc::Npc,
+        player::{Player, PlayerInteraction},
+        GridCords3D,
+    },
+    plugins::levels::CoreLevelsPlugin,
+};
+
+/// Converts a string into a MovementDirection
+fn convert_string_to_movement_direction(movement_string: String) -> MovementDirection {
+    let movement_direction_event = match movement_string.as_str() {
+        "left" => MovementDirection::Left,
+        "right" => MovementDirection::Right,
+        "up" => MovementDirection::Up,
+        "down" => MovementDirection::Down,
+        _ => panic!(
+            "convert_string_to_movement_direction: Invalid direction given: {}",
+            movement_string
+        ),
+    };
+
+    movement_direction_event
+}
+
+/// Returns the grid coordinates and facing direction of the character with the given name.
+fn find_character(game: &mut Game, character_name: &str) -> (GridCords3D, MovementDirection) {
+    let found_character = game
+        .get_all_of::<Npc, GridCords3D, MovementDirection>()
+        .into_iter()
+        .find(|(npc, _, _)| npc.get_name() == character_name)
+        .expect("find_character: Could not find a character with the given name.");
+
+    (found_character.1, found_character.2)
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+
+    let map_path = format!("tests/test_assets/maps/npcs/{}", tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32, tile_z: usize) {
+    let expected_player_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+    assert_eq!(
+        expected_player_tile_coordinate,
+        actual_player_tile_coordinate
+    );
+}
+
+#[when(regex = r"the Player moves ([a-zA-Z]+),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = convert_string_to_movement_direction(movement_direction);
+    game.write_message(movement_direction_event);
+
+    for _i in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+}
+
+#[when("the Player interacts with the tile ahead of them,")]
+fn trigger_player_interaction(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[then(regex = r"there (?:is|are) ([0-9]+) character(?:s)? on the map.")]
+fn verify_number_of_characters(game: &mut Game, expected_num_characters: usize) {
+    let actual_num_characters = game.get_number_of::<Npc>();
+
+    assert_eq!(expected_num_characters, actual_num_characters);
+}
+
+#[then(regex = r"the character '(.+)' is at ([0-9]+),([0-9]+),([0-9]+).")]
+fn verify_character_at_tile_pos(
+    game: &mut Game,
+    character_name: String,
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: usize,
+) {
+    let expected_character_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let (actual_character_tile_coordinate, _) = find_character(game, &character_name);
+
+    assert_eq!(
+        expected_character_tile_coordinate,
+        actual_character_tile_coordinate
+    );
+}
+
+#[then(regex = r"the character '(.+)' eventually reaches ([0-9]+),([0-9]+),([0-9]+).")]
+fn verify_character_reaches_tile_pos(
+    game: &mut Game,
+    character_name: String,
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: usize,
+) {
+    let expected_character_tile_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+
+    for _i in 0..255 {
+        game.tick();
+
+        let (current_character_tile_coordinate, _) = find_character(game, &character_name);
+        if current_character_tile_coordinate == expected_character_tile_coordinate {
+            break;
+        }
+    }
+
+    let (actual_character_tile_coordinate, _) = find_character(game, &character_name);
+    assert_eq!(
+        expected_character_tile_coordinate,
+        actual_character_tile_coordinate
+    );
+}
+
+#[then(regex = r"the character '(.+)' is facing ([a-zA-Z]+).")]
+fn verify_character_facing_direction(
+    game: &mut Game,
+    character_name: String,
+    facing_direction: String,
+) {
+    let expected_facing_direction = convert_string_to_movement_direction(facing_direction);
+    let (_, actual_facing_direction) = find_character(game, &character_name);
+
+    assert_eq!(expected_facing_direction, actual_facing_direction);
+}
+
+#[then(regex = r"the Player's grid coordinates are set to tile ([0-9]+),([0-9]+),([0-9]+).")]
+fn verify_player_at_tile_grid_coordinates(
+    game: &mut Game,
+    tile_x: u32,
+    tile_y: u32,
+    tile_z: usize,
+) {
+    let expected_player_tile_grid_coordinate = GridCords3D::new_u32(tile_x, tile_y, tile_z);
+    let actual_player_tile_grid_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(
+        expected_player_tile_grid_coordinate,
+        actual_player_tile_grid_coordinate
+    );
+}
+
+#[then(regex = r"the Player is facing ([a-zA-Z]+).")]
+fn verify_player_facing_direction(game: &mut Game, facing_direction: String) {
+    let expected_facing_direction = convert_string_to_movement_direction(facing_direction);
+    let actual_facing_direction = game.get_of::<MovementDirection, Player>();
+    assert_eq!(expected_facing_direction, actual_facing_direction);
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/npcs.feature"));
+}
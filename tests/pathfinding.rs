@@ -0,0 +1,126 @@
+use cucumber::{given, then, when, World};
+use helping_hand::map::{
+    movement::{collision::CollisionCollection, pathfinding::find_path},
+    GridCords3D, GridDimensions,
+};
+use std::fmt::Debug;
+
+#[derive(Debug, Default, World)]
+#[world(init = Self::new)]
+struct GameWorld {
+    pub map_grid_dimensions: GridDimensions,
+    pub collision_collection: CollisionCollection,
+    pub occupied_tiles: Vec<GridCords3D>,
+    pub path: Vec<GridCords3D>,
+}
+
+impl GameWorld {
+    pub fn new() -> Self {
+        let map_grid_dimensions = GridDimensions::new(0, 0, 1);
+        let collision_collection = CollisionCollection::new();
+        let occupied_tiles = Vec::new();
+        let path = Vec::new();
+
+        Self {
+            map_grid_dimensions,
+            collision_collection,
+            occupied_tiles,
+            path,
+        }
+    }
+}
+
+/// Converts a string of space separated x,y pairs into grid coordinates.
+fn convert_string_to_grid_coordinates(tiles_string: String) -> Vec<GridCords3D> {
+    let grid_coordinates = tiles_string
+        .split_whitespace()
+        .map(|tile_string| {
+            let (x, y) = tile_string.split_once(',').expect(
+                "convert_string_to_grid_coordinates: Tiles need to be written as x,y pairs.",
+            );
+
+            GridCords3D::new(x.parse().unwrap(), y.parse().unwrap(), 0)
+        })
+        .collect();
+
+    grid_coordinates
+}
+
+//////////////TEST FUNCTIONS//////////////
+
+#[given(regex = r"a map that is ([0-9]+)x([0-9]+) tiles,")]
+fn create_map_of_size(world: &mut GameWorld, columns: u32, rows: u32) {
+    world.map_grid_dimensions = GridDimensions::new(columns, rows, 1);
+}
+
+#[given(regex = r"collision tiles at (.+),")]
+fn add_collision_tiles(world: &mut GameWorld, tiles_string: String) {
+    for collision_tile in convert_string_to_grid_coordinates(tiles_string) {
+        world.collision_collection.add(&collision_tile);
+    }
+}
+
+#[given(regex = r"occupied tiles at (.+),")]
+fn add_occupied_tiles(world: &mut GameWorld, tiles_string: String) {
+    world.occupied_tiles = convert_string_to_grid_coordinates(tiles_string);
+}
+
+#[when(regex = r"a path is requested from ([0-9]+),([0-9]+) to ([0-9]+),([0-9]+),")]
+fn request_path(
+    world: &mut GameWorld,
+    start_x: usize,
+    start_y: usize,
+    goal_x: usize,
+    goal_y: usize,
+) {
+    let start = GridCords3D::new(start_x, start_y, 0);
+    let goal = GridCords3D::new(goal_x, goal_y, 0);
+
+    world.path = find_path(
+        &start,
+        &goal,
+        &world.map_grid_dimensions,
+        &world.collision_collection,
+        &world.occupied_tiles,
+    );
+}
+
+#[then(regex = r"the path is ([0-9]+) tiles long.")]
+fn verify_path_length(world: &mut GameWorld, expected_path_length: usize) {
+    assert_eq!(expected_path_length, world.path.len());
+}
+
+#[then(regex = r"the path ends at ([0-9]+),([0-9]+).")]
+fn verify_path_end(world: &mut GameWorld, tile_x: usize, tile_y: usize) {
+    let expected_path_end = GridCords3D::new(tile_x, tile_y, 0);
+    let actual_path_end = *world
+        .path
+        .last()
+        .expect("verify_path_end: The path is empty.");
+
+    assert_eq!(expected_path_end, actual_path_end);
+}
+
+#[then(regex = r"the path goes through ([0-9]+),([0-9]+).")]
+fn verify_path_contains(world: &mut GameWorld, tile_x: usize, tile_y: usize) {
+    let expected_tile = GridCords3D::new(tile_x, tile_y, 0);
+
+    assert!(
+        world.path.contains(&expected_tile),
+        "The path {:?} does not go through {:?}",
+        world.path,
+        expected_tile
+    );
+}
+
+#[then("there is no path.")]
+fn verify_no_path(world: &mut GameWorld) {
+    assert!(world.path.is_empty());
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(GameWorld::run(
+        "tests/feature_files/in-theory/pathfinding.feature",
+    ));
+}
@@ -0,0 +1,188 @@
+mod mock_game;
+
+use std::path::PathBuf;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    map::{interactions::map_changing::LoadLevel, player::Player, TileType},
+    narrative::story_flags::{SetStoryFlag, StoryFlag},
+    plugins::{levels::CoreLevelsPlugin, pause_menu::CorePauseMenuPlugin},
+    saving::{load_save_from, SaveGame},
+    ui::menus::pause_menu::{PauseMenuUI, SaveStatusText},
+    AppState, PauseState,
+};
+
+const PAUSE_MAP_FOLDER: &str = "tests/test_assets/maps/pause_menu";
+
+fn get_save_file_path() -> PathBuf {
+    std::env::temp_dir().join("helping_hand_tests/pause_menu_save.json")
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(CorePauseMenuPlugin::new(get_save_file_path()));
+
+    let map_path = format!("{}/{}", PAUSE_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[given(regex = r"the story flag '(.+)' is set,")]
+fn set_story_flag(game: &mut Game, flag_name: String) {
+    game.write_message(SetStoryFlag::new(&flag_name, StoryFlag::Bool(true)));
+}
+
+#[when("the player presses Escape,")]
+fn press_escape(game: &mut Game) {
+    game.press_key(KeyCode::Escape);
+    game.tick();
+}
+
+#[when("the player holds the key to walk right,")]
+fn press_walk_right_key(game: &mut Game) {
+    game.press_key(KeyCode::KeyD);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the settings menu is opened from the pause menu,")]
+fn open_settings_menu(game: &mut Game) {
+    game.get_res_mut::<NextState<PauseState>>()
+        .set(PauseState::Settings);
+    game.tick();
+}
+
+#[when(regex = r"the (inventory|quest log) is opened,")]
+fn open_menu(game: &mut Game, menu_name: String) {
+    let pause_state = match menu_name.as_str() {
+        "inventory" => PauseState::Inventory,
+        _ => PauseState::QuestLog,
+    };
+
+    game.get_res_mut::<NextState<PauseState>>().set(pause_state);
+    game.tick();
+}
+
+#[when("the game is saved,")]
+fn save_game(game: &mut Game) {
+    game.write_message(SaveGame::new());
+}
+
+#[when("the player quits to the title screen,")]
+fn quit_to_title(game: &mut Game) {
+    game.get_res_mut::<NextState<AppState>>()
+        .set(AppState::MainMenu);
+    game.tick();
+    game.tick();
+}
+
+#[then("the game is paused,")]
+fn verify_game_paused(game: &mut Game) {
+    assert_eq!(
+        &PauseState::Paused,
+        game.get_res::<State<PauseState>>().get()
+    );
+}
+
+#[then("the game is running,")]
+fn verify_game_running(game: &mut Game) {
+    assert_eq!(
+        &PauseState::Running,
+        game.get_res::<State<PauseState>>().get()
+    );
+}
+
+#[then("the pause menu is shown,")]
+fn verify_pause_menu_shown(game: &mut Game) {
+    assert_eq!(1, game.get_number_of::<PauseMenuUI>());
+}
+
+#[then("the pause menu is closed,")]
+fn verify_pause_menu_closed(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<PauseMenuUI>());
+}
+
+#[then("time stands still.")]
+fn verify_time_paused(game: &mut Game) {
+    assert!(game.get_res::<Time<Virtual>>().is_paused());
+}
+
+#[then("time passes.")]
+fn verify_time_passes(game: &mut Game) {
+    assert!(!game.get_res::<Time<Virtual>>().is_paused());
+}
+
+#[then(regex = r"the Player is at ([0-9]+),([0-9]+).")]
+fn verify_player_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[then(regex = r"the save file has the level (.+),")]
+fn verify_saved_level(_game: &mut Game, tiled_map_name: String) {
+    let save_data = load_save_from(get_save_file_path()).expect("Unable to read the save file.");
+
+    let saved_level_path = save_data
+        .get_level_path()
+        .clone()
+        .expect("verify_saved_level: No level was saved.");
+
+    assert!(saved_level_path.ends_with(tiled_map_name));
+}
+
+#[then(regex = r"the save file has the story flag '(.+)'.")]
+fn verify_saved_story_flag(_game: &mut Game, flag_name: String) {
+    let save_data = load_save_from(get_save_file_path()).expect("Unable to read the save file.");
+
+    assert!(save_data.get_story_flags().is_set(&flag_name));
+}
+
+#[then(regex = r"the save file has the Player at ([0-9]+),([0-9]+),")]
+fn verify_saved_player_position(_game: &mut Game, tile_x: u32, tile_y: u32) {
+    let save_data = load_save_from(get_save_file_path()).expect("Unable to read the save file.");
+
+    let saved_player_position = save_data
+        .get_player_position()
+        .expect("verify_saved_player_position: No Player position was saved.");
+
+    assert_eq!(tile_x as usize, saved_player_position.get_x());
+    assert_eq!(tile_y as usize, saved_player_position.get_y());
+}
+
+#[then(regex = r"the pause menu reads '(.+)'.")]
+fn verify_save_status(game: &mut Game, expected_status: String) {
+    game.tick();
+
+    let actual_status = game.get_cloned_of::<Text, SaveStatusText>();
+
+    assert_eq!(expected_status, actual_status.as_str());
+}
+
+#[then("the level is unloaded,")]
+fn verify_level_unloaded(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<Player>());
+    assert_eq!(0, game.get_number_of::<TileType>());
+    assert_eq!(0, game.get_number_of::<PauseMenuUI>());
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/pause_menu.feature",
+    ));
+}
@@ -0,0 +1,193 @@
+mod mock_game;
+
+use std::path::PathBuf;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::prelude::*;
+use helping_hand::{
+    map::{
+        interactions::map_changing::{ChangeLevel, LoadLevel},
+        movement::grid_based_movement::{MovementDirection, Target},
+        player::*,
+    },
+    narrative::quests::{QuestEvent, QuestLog},
+    plugins::{levels::CoreLevelsPlugin, quests::QuestsPlugin},
+    ui::{
+        menus::quest_log::{QuestLogUI, QuestObjectiveText},
+        quest_toast::QuestToastText,
+    },
+};
+
+const QUEST_MAP_FOLDER: &str = "tests/test_assets/maps/quests";
+const QUEST_FILE: &str = "tests/test_assets/quests/test_quests.json";
+
+/// Converts a string into a MovementDirection
+fn convert_string_to_movement_direction(movement_string: String) -> MovementDirection {
+    let movement_direction_event = match movement_string.as_str() {
+        "left" => MovementDirection::Left,
+        "right" => MovementDirection::Right,
+        "up" => MovementDirection::Up,
+        "down" => MovementDirection::Down,
+        _ => panic!(
+            "convert_string_to_movement_direction: Invalid direction given: {}",
+            movement_string
+        ),
+    };
+
+    movement_direction_event
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+    game.add_plugin(QuestsPlugin::new(PathBuf::from(QUEST_FILE)));
+
+    let map_path = format!("{}/{}", QUEST_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[when("the player interacts with the tile ahead of them,")]
+fn trigger_player_interaction(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the Player moves ([a-zA-Z]+),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = convert_string_to_movement_direction(movement_direction);
+    game.write_message(movement_direction_event);
+
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+}
+
+#[when("the level has loaded,")]
+fn wait_for_level_to_load(game: &mut Game) {
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the level is entered again,")]
+fn reenter_level(game: &mut Game) {
+    let map_path = format!("{}/quest_test.tmx", QUEST_MAP_FOLDER);
+    game.write_message(ChangeLevel::new(&map_path));
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the scene '(.+)' is reached,")]
+fn reach_scene(game: &mut Game, scene_title: String) {
+    game.write_message(QuestEvent::ReachedScene(scene_title));
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the player presses the quest log key,")]
+fn press_quest_log_key(game: &mut Game) {
+    game.press_key(KeyCode::KeyJ);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[given(regex = r"the quest '(.+)' has ([0-9]+) of ([0-9]+) objectives completed,")]
+#[then(regex = r"the quest '(.+)' has ([0-9]+) of ([0-9]+) objectives completed[,.]")]
+fn verify_completed_objectives(
+    game: &mut Game,
+    quest_id: String,
+    expected_completed: usize,
+    expected_total: usize,
+) {
+    let quest = game
+        .get_res::<QuestLog>()
+        .get_quest(&quest_id)
+        .expect("verify_completed_objectives: Could not find the quest.")
+        .clone();
+
+    assert_eq!(expected_completed, quest.get_num_completed_objectives());
+    assert_eq!(
+        expected_total,
+        quest.get_definition().get_objectives().len()
+    );
+}
+
+#[then(regex = r"objective ([0-9]+) of the quest '(.+)' has a progress of ([0-9]+)[,.]")]
+fn verify_objective_progress(
+    game: &mut Game,
+    objective_number: usize,
+    quest_id: String,
+    expected_progress: u32,
+) {
+    let quest = game
+        .get_res::<QuestLog>()
+        .get_quest(&quest_id)
+        .expect("verify_objective_progress: Could not find the quest.")
+        .clone();
+
+    let actual_progress = quest.get_objective_progress(objective_number - 1);
+
+    assert_eq!(expected_progress, actual_progress);
+}
+
+#[then(regex = r"the quest '(.+)' is completed[,.]")]
+fn verify_quest_completed(game: &mut Game, quest_id: String) {
+    let quest = game
+        .get_res::<QuestLog>()
+        .get_quest(&quest_id)
+        .expect("verify_quest_completed: Could not find the quest.")
+        .clone();
+
+    assert!(quest.is_completed());
+}
+
+#[then(regex = r"a toast reads '(.+)'[,.]")]
+fn verify_toast_text(game: &mut Game, expected_text: String) {
+    let actual_text = game.get_cloned_of::<Text, QuestToastText>();
+
+    assert_eq!(expected_text, actual_text.as_str());
+}
+
+#[then(regex = r"the quest log shows '(.+)'[,.]")]
+fn verify_quest_log_shows(game: &mut Game, expected_text: String) {
+    let objective_texts = game.get_all_cloned_of::<Text, QuestObjectiveText>();
+    let is_shown = objective_texts
+        .iter()
+        .any(|objective_text| objective_text.as_str() == expected_text);
+
+    assert!(is_shown);
+}
+
+#[then("the quest log is closed.")]
+fn verify_quest_log_closed(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<QuestLogUI>());
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/quests.feature"));
+}
@@ -4,10 +4,13 @@ use crate::mock_game::Game;
 use bevy::color::Alpha;
 use cucumber::{given, then, when, World};
 
-use bevy::prelude::ImageNode;
+use bevy::prelude::{ImageNode, KeyCode};
+use bevy::ui::{UiTransform, Val2};
+use helping_hand::map::camera::CameraController;
 use helping_hand::map::GridCords2D;
 use helping_hand::narrative::act_loading::*;
 use helping_hand::narrative::acts::*;
+use helping_hand::narrative::image_layouts::{ImageLayer, PanZoom};
 use helping_hand::plugins::acts::CoreActsPlugin;
 use helping_hand::plugins::levels::CoreLevelsPlugin;
 use std::path::PathBuf;
@@ -122,6 +125,15 @@ fn add_test_acts_plugin(game: &mut Game) {
     game.add_plugin(CoreLevelsPlugin);
 }
 
+#[given(regex = r"the game is capable of handling acts with a ([0-9]+) second fade,")]
+fn add_test_acts_plugin_with_fade(game: &mut Game, fade_seconds: u64) {
+    let fade_duration = Duration::from_secs(fade_seconds);
+    let maps_folder_path = PathBuf::from("tests/test_assets/maps/scene_loading/");
+
+    game.add_plugin(CoreActsPlugin::new(fade_duration, maps_folder_path));
+    game.add_plugin(CoreLevelsPlugin);
+}
+
 #[when(regex = r"the act called '(.+)' is loaded,")]
 fn load_act(game: &mut Game, act_file_name: String) {
     let act_file_path_name = format!("tests/test_assets/acts/{}", act_file_name);
@@ -156,6 +168,36 @@ fn transition_to_given_scene(game: &mut Game, given_scene_num: usize) {
     }
 }
 
+#[when("the player presses a key,")]
+fn press_any_key(game: &mut Game) {
+    game.press_key(KeyCode::Space);
+}
+
+#[when("the act advances on its own,")]
+fn wait_for_auto_advance(game: &mut Game) {
+    let starting_scene_title = game.get_mut::<Act>().get_current_scene().get_title();
+
+    // Auto advancing plays out over time, so time is stepped until it has.
+    game.set_time_step(Duration::from_millis(5));
+    let has_advanced = game.tick_until(400, |game| {
+        game.get_mut::<Act>().get_current_scene().get_title() != starting_scene_title
+    });
+    assert!(
+        has_advanced,
+        "wait_for_auto_advance: The act never advanced."
+    );
+
+    game.tick();
+}
+
+#[when(regex = r"([0-9]+) seconds of the scene have passed,")]
+fn pass_scene_time(game: &mut Game, seconds: u64) {
+    game.set_time_step(Duration::from_millis(100));
+    for _ in 0..(seconds * 10) {
+        game.tick();
+    }
+}
+
 #[then(regex = r"the title of the current scene loaded is called '(.+)'.")]
 fn verify_current_scene_title(game: &mut Game, expected_scene_title: String) {
     let current_act = game.get_mut::<Act>();
@@ -182,6 +224,32 @@ fn verify_num_images_loaded(game: &mut Game) {
     assert_eq!(image_count, 1);
 }
 
+#[then(regex = r"the image is layered with ([0-9]+) images.")]
+fn verify_num_image_layers(game: &mut Game, expected_num_layers: usize) {
+    game.tick();
+
+    assert_eq!(expected_num_layers, game.get_number_of::<ImageLayer>());
+}
+
+#[then("the image has been panned and zoomed in.")]
+fn verify_image_panned_and_zoomed(game: &mut Game) {
+    let scene_transform = game.get_cloned_of::<UiTransform, PanZoom>();
+
+    assert_ne!(Val2::ZERO, scene_transform.translation);
+    assert!(scene_transform.scale.x > 1.0);
+}
+
+#[then("the layers further back have been zoomed in less than the image.")]
+fn verify_layers_follow_parallax(game: &mut Game) {
+    let layer_transforms = game.get_all_cloned_of::<UiTransform, ImageLayer>();
+
+    // Layers are scaled relative to the image they're layered on, so layers
+    // further back are scaled down to make up for its zoom.
+    assert!(layer_transforms
+        .iter()
+        .any(|layer_transform| layer_transform.scale.x < 1.0));
+}
+
 #[then("the loaded image's opacity is 100%.")]
 fn verify_image_opacity(game: &mut Game) {
     let image_node = game.get_mut::<ImageNode>();
@@ -191,6 +259,16 @@ fn verify_image_opacity(game: &mut Game) {
     assert_eq!(1.0, opacity);
 }
 
+#[then("the image is still transitioning in.")]
+fn verify_image_transitioning(game: &mut Game) {
+    assert_eq!(1, game.get_number_of::<FadeTimer>());
+}
+
+#[then("the image has finished transitioning in.")]
+fn verify_image_transitioned(game: &mut Game) {
+    assert_eq!(0, game.get_number_of::<FadeTimer>());
+}
+
 #[then(regex = r"the map size should be ([0-9]+) x ([0-9]+) tiles.")]
 fn verify_map_size(game: &mut Game, expected_map_width: u32, expected_map_height: u32) {
     game.tick();
@@ -204,6 +282,20 @@ fn verify_map_size(game: &mut Game, expected_map_width: u32, expected_map_height
     assert_eq!(expected_map_width, actual_map_width);
 }
 
+#[then("the camera pans away from the player.")]
+fn verify_camera_panning(game: &mut Game) {
+    // The camera is only spawned once the cutscene's level has loaded.
+    let is_panning = game.tick_until(10, |game| {
+        game.get_number_of::<CameraController>() > 0
+            && !game.get_mut::<CameraController>().is_following()
+    });
+
+    assert!(
+        is_panning,
+        "verify_camera_panning: The camera never panned."
+    );
+}
+
 #[then(regex = r"the character '(.+)' will be (.+) '(.+)'.")]
 fn verify_instruction_data(
     game: &mut Game,
@@ -0,0 +1,81 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::{ecs::message::Messages, prelude::*};
+use helping_hand::{
+    audio::sfx::*,
+    map::{
+        interactions::map_changing::ChangeLevel,
+        player::{PlayerInteraction, PlayerMovementActions},
+    },
+    plugins::sfx::CoreSfxPlugin,
+};
+use std::path::PathBuf;
+
+/// Returns every sound effect the game recently asked to play.
+fn get_played_sfx(game: &mut Game) -> Vec<PlaySfx> {
+    let played_sfx = game.get_res::<Messages<PlaySfx>>();
+    let mut sfx_cursor = played_sfx.get_cursor();
+
+    sfx_cursor.read(played_sfx).cloned().collect()
+}
+
+#[given(regex = r"the sound effects in (.+),")]
+fn given_sfx_manifest(game: &mut Game, manifest_file: String) {
+    game.add_plugin(CoreSfxPlugin::new(PathBuf::from(manifest_file)));
+}
+
+#[when("the Player bumps into a wall,")]
+fn bump_into_wall(game: &mut Game) {
+    game.write_message(PlayerMovementActions::Bumping);
+}
+
+#[when("the Player walks,")]
+fn walk(game: &mut Game) {
+    game.write_message(PlayerMovementActions::Walking);
+}
+
+#[when("the Player interacts,")]
+fn interact(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+}
+
+#[when(regex = r"the level changes to (.+),")]
+fn change_level(game: &mut Game, level_path: String) {
+    game.write_message(ChangeLevel::new(&level_path));
+}
+
+#[when(regex = r"the '(.+)' cue is set off,")]
+fn set_off_cue(game: &mut Game, cue_name: String) {
+    game.write_message(CueSfx::new(SfxCue::new(&cue_name)));
+}
+
+#[when("a button is clicked,")]
+fn click_button(game: &mut Game) {
+    game.spawn((Button, Interaction::Pressed));
+    game.tick();
+}
+
+#[then(regex = r"(.+) is played at a volume of ([0-9.]+)[,.]$")]
+fn verify_sfx_played(game: &mut Game, expected_sound: String, expected_volume: f32) {
+    let played_sfx = get_played_sfx(game);
+    let expected_sound = PathBuf::from(expected_sound);
+
+    let found_sfx = played_sfx
+        .iter()
+        .find(|sfx| sfx.get_sound() == &expected_sound)
+        .expect("verify_sfx_played: The sound effect was not played.");
+
+    assert_eq!(expected_volume, found_sfx.get_volume());
+}
+
+#[then(regex = r"([0-9]+) sound effects? (?:is|are) played[,.]$")]
+fn verify_number_of_sfx_played(game: &mut Game, expected_amount: usize) {
+    assert_eq!(expected_amount, get_played_sfx(game).len());
+}
+
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/sfx.feature"));
+}
@@ -0,0 +1,185 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::{ecs::message::Messages, prelude::*};
+use helping_hand::{
+    map::{
+        interactions::map_changing::{ChangeLevel, LoadLevel},
+        items::ItemPickup,
+        movement::grid_based_movement::{MovementDirection, Target},
+        npc::Npc,
+        player::*,
+    },
+    narrative::story_flags::*,
+    plugins::levels::CoreLevelsPlugin,
+};
+
+const STORY_FLAG_MAP_FOLDER: &str = "tests/test_assets/maps/story_flags";
+
+/// Converts a string into a MovementDirection
+fn convert_string_to_movement_direction(movement_string: String) -> MovementDirection {
+    let movement_direction_event = match movement_string.as_str() {
+        "left" => MovementDirection::Left,
+        "right" => MovementDirection::Right,
+        "up" => MovementDirection::Up,
+        "down" => MovementDirection::Down,
+        _ => panic!(
+            "convert_string_to_movement_direction: Invalid direction given: {}",
+            movement_string
+        ),
+    };
+
+    movement_direction_event
+}
+
+#[given(regex = r"a Tiled map called (.+),")]
+fn given_some_tiled_map(game: &mut Game, tiled_map_name: String) {
+    game.add_plugin(CoreLevelsPlugin);
+
+    let map_path = format!("{}/{}", STORY_FLAG_MAP_FOLDER, tiled_map_name);
+    game.write_message(LoadLevel::new(&map_path));
+}
+
+#[given(regex = r"the Player is at ([0-9]+),([0-9]+),")]
+fn verify_player_spawned_at_tile_pos(game: &mut Game, tile_x: u32, tile_y: u32) {
+    let actual_player_tile_coordinate = game.find_coordinates_of_player();
+
+    assert_eq!(tile_x as usize, actual_player_tile_coordinate.get_x());
+    assert_eq!(tile_y as usize, actual_player_tile_coordinate.get_y());
+}
+
+#[given(regex = r"the story flag '(.+)' is set,")]
+fn set_story_flag(game: &mut Game, flag_name: String) {
+    game.write_message(SetStoryFlag::new(&flag_name, StoryFlag::Bool(true)));
+}
+
+#[given(regex = r"the story flag '(.+)' is set to ([0-9]+),")]
+fn set_story_flag_counter(game: &mut Game, flag_name: String, count: i64) {
+    game.write_message(SetStoryFlag::new(&flag_name, StoryFlag::Counter(count)));
+}
+
+#[when("the player interacts with the tile ahead of them,")]
+fn trigger_player_interaction(game: &mut Game) {
+    game.write_message(PlayerInteraction);
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the Player moves ([a-zA-Z]+),")]
+fn move_player_in_direction(game: &mut Game, movement_direction: String) {
+    let movement_direction_event = convert_string_to_movement_direction(movement_direction);
+    game.write_message(movement_direction_event);
+
+    for _ in 0..255 {
+        game.tick();
+
+        let has_traveled = game.get_number_of::<Target>() == 0;
+        if has_traveled {
+            break;
+        }
+    }
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when("the level is entered again,")]
+fn reenter_level(game: &mut Game) {
+    let map_path = format!("{}/flag_test.tmx", STORY_FLAG_MAP_FOLDER);
+    game.write_message(ChangeLevel::new(&map_path));
+
+    for _ in 0..5 {
+        game.tick();
+    }
+}
+
+#[when(regex = r"the story flag '(.+)' is queried,")]
+fn query_story_flag(game: &mut Game, flag_name: String) {
+    game.write_message(QueryStoryFlag::new(&flag_name));
+}
+
+#[when("the story flags are saved and loaded again,")]
+fn save_and_load_story_flags(game: &mut Game) {
+    let saved_flags = serde_json::to_string(game.get_res::<StoryFlags>())
+        .expect("save_and_load_story_flags: Unable to save the story flags.");
+
+    *game.get_res_mut::<StoryFlags>() = StoryFlags::new();
+
+    let loaded_flags: StoryFlags = serde_json::from_str(&saved_flags)
+        .expect("save_and_load_story_flags: Unable to load the story flags.");
+    *game.get_res_mut::<StoryFlags>() = loaded_flags;
+}
+
+#[then(regex = r"the map size should be ([0-9]+) x ([0-9]+) tiles[,.]")]
+fn verify_map_size(game: &mut Game, expected_map_width: u32, expected_map_height: u32) {
+    let map_dimensions = game.get_map_size();
+
+    assert_eq!(expected_map_height, map_dimensions.get_rows());
+    assert_eq!(expected_map_width, map_dimensions.get_columns());
+}
+
+#[then(regex = r"the story flag '(.+)' is set[,.]")]
+fn verify_story_flag_set(game: &mut Game, flag_name: String) {
+    assert!(game.get_res::<StoryFlags>().is_set(&flag_name));
+}
+
+#[then(regex = r"the story flag '(.+)' is not set[,.]")]
+fn verify_story_flag_not_set(game: &mut Game, flag_name: String) {
+    assert!(!game.get_res::<StoryFlags>().is_set(&flag_name));
+}
+
+#[then(regex = r"the story flag '(.+)' is reported as ([0-9]+)[,.]")]
+fn verify_story_flag_report(game: &mut Game, flag_name: String, expected_count: i64) {
+    let story_flag_reports = game.get_res::<Messages<StoryFlagReport>>();
+    let mut report_cursor = story_flag_reports.get_cursor();
+
+    let found_report = report_cursor
+        .read(story_flag_reports)
+        .filter(|report| *report.get_flag_name() == flag_name)
+        .last()
+        .expect("verify_story_flag_report: The story flag was never reported.");
+
+    assert_eq!(
+        &Some(StoryFlag::Counter(expected_count)),
+        found_report.get_value()
+    );
+}
+
+#[then(regex = r"the character '(.+)' is on the map[,.]")]
+fn verify_character_present(game: &mut Game, character_name: String) {
+    let found_character = game
+        .get_all_of::<Npc, Transform, MovementDirection>()
+        .into_iter()
+        .find(|(npc, _, _)| *npc.get_name() == character_name);
+
+    assert!(found_character.is_some());
+}
+
+#[then(regex = r"the character '(.+)' is not on the map[,.]")]
+fn verify_character_absent(game: &mut Game, character_name: String) {
+    let found_character = game
+        .get_all_of::<Npc, Transform, MovementDirection>()
+        .into_iter()
+        .find(|(npc, _, _)| *npc.get_name() == character_name);
+
+    assert!(found_character.is_none());
+}
+
+#[then(regex = r"there (?:is|are) ([0-9]+) items? on the map[,.]")]
+fn verify_number_of_items(game: &mut Game, expected_item_amount: usize) {
+    let actual_item_amount = game.get_number_of::<ItemPickup>();
+
+    assert_eq!(expected_item_amount, actual_item_amount);
+}
+
+// This runs before everything else, so you can setup things here.
+fn main() {
+    futures::executor::block_on(Game::run(
+        "tests/feature_files/in-practice/story_flags.feature",
+    ));
+}
@@ -0,0 +1,241 @@
+mod mock_game;
+
+use crate::mock_game::Game;
+use cucumber::{given, then, when, World};
+
+use bevy::{ecs::message::Messages, prelude::*};
+use helping_hand::{
+    plugins::widgets::WidgetsPlugin,
+    ui::widgets::{
+        dropdown::{Dropdown, DropdownChanged},
+        focus::{Focusable, Focused},
+        slider::{Slider, SliderChanged},
+        spinner::{Spinner, SpinnerChanged},
+        tab_bar::{TabBar, TabButton, TabPage},
+        toggle::{Toggle, ToggleChanged},
+    },
+};
+
+/// Tells apart widgets of the same kind, counting up from 1 in the order they were spawned.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct WidgetNumber(usize);
+
+/// Returns every message of some type recently sent.
+fn get_messages<M: Message + Clone>(game: &mut Game) -> Vec<M> {
+    let messages = game.get_res::<Messages<M>>();
+    let mut cursor = messages.get_cursor();
+
+    cursor.read(messages).cloned().collect()
+}
+
+fn get_key_code(key_name: &str) -> KeyCode {
+    match key_name {
+        "Tab" => KeyCode::Tab,
+        "Up" => KeyCode::ArrowUp,
+        "Down" => KeyCode::ArrowDown,
+        "Left" => KeyCode::ArrowLeft,
+        "Right" => KeyCode::ArrowRight,
+        "Enter" => KeyCode::Enter,
+        _ => panic!("get_key_code: There is no key named {}.", key_name),
+    }
+}
+
+fn spawn_toggles(game: &mut Game, number_of_toggles: usize, hidden_toggle: Option<usize>) {
+    for toggle_number in 1..=number_of_toggles {
+        let display = if hidden_toggle == Some(toggle_number) {
+            Display::None
+        } else {
+            Display::Flex
+        };
+
+        game.spawn((
+            Node {
+                display,
+                ..default()
+            },
+            Button,
+            Toggle::new(false),
+            Focusable,
+            WidgetNumber(toggle_number),
+        ));
+    }
+}
+
+#[given("the menu widgets,")]
+fn given_widgets(game: &mut Game) {
+    game.add_plugin(WidgetsPlugin);
+}
+
+#[given(regex = r"^([0-9]+) toggles?(?: one after another)?,$")]
+fn given_toggles(game: &mut Game, number_of_toggles: usize) {
+    spawn_toggles(game, number_of_toggles, None);
+}
+
+#[given(regex = r"^([0-9]+) toggles one after another where toggle ([0-9]+) is hidden,$")]
+fn given_toggles_with_hidden(game: &mut Game, number_of_toggles: usize, hidden_toggle: usize) {
+    spawn_toggles(game, number_of_toggles, Some(hidden_toggle));
+}
+
+#[given(regex = r"a slider at ([0-9.]+) from ([0-9.]+) to ([0-9.]+) in steps of ([0-9.]+),")]
+fn given_slider(game: &mut Game, value: f32, min: f32, max: f32, step: f32) {
+    game.spawn((Button, Slider::new(value, min, max, step), Focusable));
+}
+
+#[given(regex = r"a spinner at ([0-9.]+) from ([0-9.]+) to ([0-9.]+) in steps of ([0-9.]+),")]
+fn given_spinner(game: &mut Game, value: f32, min: f32, max: f32, step: f32) {
+    game.spawn((
+        Node::default(),
+        Spinner::new(value, min, max, step),
+        Focusable,
+    ));
+}
+
+#[given(regex = r"a dropdown of (.+), (.+) and (.+),")]
+fn given_dropdown(game: &mut Game, first: String, second: String, third: String) {
+    game.spawn((
+        Button,
+        Dropdown::new(vec![first, second, third], 0),
+        Focusable,
+    ));
+}
+
+#[given(regex = r"a tab bar with the tabs (.+) and (.+),")]
+fn given_tab_bar(game: &mut Game, first_tab: String, second_tab: String) {
+    let tab_bar = game.spawn((Node::default(), TabBar::new(vec![first_tab, second_tab])));
+
+    for index in 0..2 {
+        game.spawn((
+            Button,
+            ImageNode::default(),
+            TabButton::new(tab_bar, index),
+            Focusable,
+            WidgetNumber(index + 1),
+        ));
+    }
+
+    for index in 0..2 {
+        game.spawn((
+            Node::default(),
+            TabPage::new(tab_bar, index),
+            WidgetNumber(index + 1),
+        ));
+    }
+}
+
+#[when(regex = r"^the (.+) key is pressed,$")]
+fn press_key(game: &mut Game, key_name: String) {
+    game.press_key(get_key_code(&key_name));
+}
+
+#[when(regex = r"^the (.+) key is pressed ([0-9]+) times,$")]
+fn press_key_repeatedly(game: &mut Game, key_name: String, times: usize) {
+    for _ in 0..times {
+        game.press_key(get_key_code(&key_name));
+    }
+}
+
+#[then(regex = r"(?:toggle|tab) ([0-9]+) is focused[,.]$")]
+fn verify_focused(game: &mut Game, expected_number: usize) {
+    let focused_widgets = game.get_all_cloned_of::<WidgetNumber, Focused>();
+
+    assert_eq!(vec![WidgetNumber(expected_number)], focused_widgets);
+}
+
+#[then(regex = r"the toggle is (on|off)[,.]$")]
+fn verify_toggle(game: &mut Game, expected_state: String) {
+    let toggle = game.get_cloned_of::<Toggle, Toggle>();
+
+    assert_eq!(expected_state == "on", toggle.is_on());
+}
+
+#[then(regex = r"the toggle was switched (on|off)[,.]$")]
+fn verify_toggle_changed(game: &mut Game, expected_state: String) {
+    let toggle_changes = get_messages::<ToggleChanged>(game);
+
+    assert_eq!(1, toggle_changes.len());
+    assert_eq!(expected_state == "on", toggle_changes[0].is_on());
+}
+
+#[then(regex = r"the slider is at ([0-9.]+)[,.]$")]
+fn verify_slider(game: &mut Game, expected_value: f32) {
+    let slider = game.get_of::<Slider, Slider>();
+
+    assert_eq!(expected_value, slider.get_value());
+}
+
+#[then(regex = r"the slider was changed to ([0-9.]+)[,.]$")]
+fn verify_slider_changed(game: &mut Game, expected_value: f32) {
+    let slider_changes = get_messages::<SliderChanged>(game);
+
+    assert_eq!(1, slider_changes.len());
+    assert_eq!(expected_value, slider_changes[0].get_value());
+}
+
+#[then("the slider was not changed.")]
+fn verify_slider_unchanged(game: &mut Game) {
+    assert!(get_messages::<SliderChanged>(game).is_empty());
+}
+
+#[then(regex = r"the spinner is at ([0-9.]+)[,.]$")]
+fn verify_spinner(game: &mut Game, expected_value: f32) {
+    let spinner = game.get_of::<Spinner, Spinner>();
+
+    assert_eq!(expected_value, spinner.get_value());
+}
+
+#[then(regex = r"the spinner was changed to ([0-9.]+)[,.]$")]
+fn verify_spinner_changed(game: &mut Game, expected_value: f32) {
+    let spinner_changes = get_messages::<SpinnerChanged>(game);
+
+    assert_eq!(1, spinner_changes.len());
+    assert_eq!(expected_value, spinner_changes[0].get_value());
+}
+
+#[then(regex = r"the dropdown shows (.+)[,.]$")]
+fn verify_dropdown_selection(game: &mut Game, expected_option: String) {
+    let dropdown = game.get_cloned_of::<Dropdown, Dropdown>();
+
+    assert_eq!(Some(&expected_option), dropdown.get_selected_option());
+}
+
+#[then(regex = r"the dropdown was changed to option ([0-9]+)[,.]$")]
+fn verify_dropdown_changed(game: &mut Game, expected_selected: usize) {
+    let dropdown_changes = get_messages::<DropdownChanged>(game);
+
+    assert_eq!(1, dropdown_changes.len());
+    assert_eq!(expected_selected, dropdown_changes[0].get_selected());
+}
+
+#[then("the dropdown is open.")]
+fn verify_dropdown_open(game: &mut Game) {
+    let dropdown = game.get_cloned_of::<Dropdown, Dropdown>();
+
+    assert!(dropdown.is_open());
+}
+
+#[then(regex = r"the (.+) tab is selected[,.]$")]
+fn verify_selected_tab(game: &mut Game, expected_tab: String) {
+    let tab_bar = game.get_cloned_of::<TabBar, TabBar>();
+
+    assert_eq!(&expected_tab, &tab_bar.get_tabs()[tab_bar.get_selected()]);
+}
+
+#[then(regex = r"only the page of the (.+) tab is shown[,.]$")]
+fn verify_shown_page(game: &mut Game, expected_tab: String) {
+    let tab_bar = game.get_cloned_of::<TabBar, TabBar>();
+    let expected_page = tab_bar
+        .get_tabs()
+        .iter()
+        .position(|tab| *tab == expected_tab)
+        .expect("verify_shown_page: There is no tab with that name.");
+
+    for (page_number, _, node) in game.get_all_of::<WidgetNumber, TabPage, Node>() {
+        let should_be_shown = page_number == WidgetNumber(expected_page + 1);
+
+        assert_eq!(should_be_shown, node.display != Display::None);
+    }
+}
+
+fn main() {
+    futures::executor::block_on(Game::run("tests/feature_files/in-practice/widgets.feature"));
+}